@@ -1,4 +1,4 @@
-use crate::storage::{Author, Storage};
+use crate::storage::{Author, Storage, StorageError};
 
 /// Stores an author in the storage.
 pub fn store_author(storage: &mut Storage, author: Author) -> Result<(), String> {
@@ -10,3 +10,148 @@ pub fn store_author(storage: &mut Storage, author: Author) -> Result<(), String>
 pub fn get_author_by_id(storage: &Storage, author_id: &str) -> Result<Option<Author>, String> {
     Ok(storage.get_author(author_id).cloned())
 }
+
+/// Name suffixes that may follow a comma without the name being in "Last, First" form.
+const NAME_SUFFIXES: [&str; 7] = ["jr", "jr.", "sr", "sr.", "ii", "iii", "iv"];
+
+/// Normalizes an author name so the same person is recognized however a lookup
+/// provider spells it: collapses whitespace and flips "Rowling, J. K." into
+/// "J. K. Rowling". Names without exactly one comma, or where the comma only
+/// separates a suffix such as "Jr.", are left in their order.
+pub fn normalize_author_name(name: &str) -> String {
+    let collapsed = name.split_whitespace().collect::<Vec<_>>().join(" ");
+    let Some((last, first)) = collapsed.split_once(',') else {
+        return collapsed;
+    };
+    let (last, first) = (last.trim(), first.trim());
+    if last.is_empty()
+        || first.is_empty()
+        || first.contains(',')
+        || NAME_SUFFIXES.contains(&first.to_lowercase().as_str())
+    {
+        return collapsed;
+    }
+    format!("{} {}", first, last)
+}
+
+/// Finds an author whose name matches `name` once both are normalized
+/// (see `normalize_author_name`), ignoring case.
+pub fn find_author_by_name<'a>(storage: &'a Storage, name: &str) -> Option<&'a Author> {
+    let wanted = normalize_author_name(name).to_lowercase();
+    storage
+        .authors
+        .values()
+        .find(|a| normalize_author_name(&a.name).to_lowercase() == wanted)
+}
+
+/// Finds an author by name (case-insensitive, treating "Last, First" and
+/// "First Last" as the same name) or creates it. Returns the author ID.
+pub fn get_or_create_author(storage: &mut Storage, name: &str) -> String {
+    if let Some(author) = find_author_by_name(storage, name) {
+        return author.id.clone();
+    }
+
+    let author = Author::new(normalize_author_name(name));
+    let id = author.id.clone();
+    storage.add_author(author);
+    id
+}
+
+/// Returns the number of single-character insertions, deletions or
+/// substitutions needed to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Finds groups of authors that are probably the same person: their normalized
+/// names (see `normalize_author_name`) match ignoring case, or differ by a single
+/// character. Groups are chained, so "Jon", "John" and "Johm" end up together.
+///
+/// Each group has at least two authors sorted by name, and groups are sorted by
+/// their first author's name.
+pub fn find_similar_authors(storage: &Storage) -> Vec<Vec<&Author>> {
+    let mut authors: Vec<&Author> = storage.authors.values().collect();
+    authors.sort_by(|a, b| {
+        a.name
+            .to_lowercase()
+            .cmp(&b.name.to_lowercase())
+            .then(a.id.cmp(&b.id))
+    });
+    let names: Vec<String> = authors
+        .iter()
+        .map(|a| normalize_author_name(&a.name).to_lowercase())
+        .collect();
+
+    // Union-find over the authors, joining every similar pair
+    let mut parent: Vec<usize> = (0..authors.len()).collect();
+    for i in 0..authors.len() {
+        for j in i + 1..authors.len() {
+            if edit_distance(&names[i], &names[j]) <= 1 {
+                let (a, b) = (root(&mut parent, i), root(&mut parent, j));
+                parent[b.max(a)] = a.min(b);
+            }
+        }
+    }
+
+    let mut groups: Vec<Vec<&Author>> = vec![Vec::new(); authors.len()];
+    for (i, author) in authors.iter().enumerate() {
+        groups[root(&mut parent, i)].push(author);
+    }
+    groups.retain(|group| group.len() > 1);
+    groups
+}
+
+/// Returns the representative of `i`'s set in a union-find `parent` table,
+/// compressing the path on the way.
+fn root(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+/// Merges the authors in `duplicate_ids` into the author `canonical_id`: their
+/// books are re-pointed to the canonical author and the duplicates are removed.
+/// Returns the number of books re-pointed.
+pub fn merge_authors(
+    storage: &mut Storage,
+    canonical_id: &str,
+    duplicate_ids: &[String],
+) -> Result<usize, String> {
+    if storage.get_author(canonical_id).is_none() {
+        return Err(StorageError::MissingAuthor(canonical_id.to_string()).into());
+    }
+    if let Some(missing) = duplicate_ids
+        .iter()
+        .find(|id| storage.get_author(id).is_none())
+    {
+        return Err(StorageError::MissingAuthor(missing.clone()).into());
+    }
+
+    let duplicates: Vec<&String> = duplicate_ids
+        .iter()
+        .filter(|id| id.as_str() != canonical_id)
+        .collect();
+    let mut moved = 0;
+    for book in storage.books.values_mut() {
+        if duplicates.contains(&&book.author_id) {
+            book.author_id = canonical_id.to_string();
+            moved += 1;
+        }
+    }
+    for id in duplicates {
+        storage.authors.remove(id);
+    }
+    Ok(moved)
+}
@@ -0,0 +1,55 @@
+use crate::storage::Storage;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where a `Storage` is persisted. `load_storage` and `write_storage` go
+/// through this trait so other formats can be added next to the JSON file.
+pub trait StorageBackend {
+    /// Reads the whole library.
+    fn load(&self) -> Result<Storage, Box<dyn Error>>;
+    /// Replaces the persisted library with `storage`.
+    fn save(&self, storage: &Storage) -> Result<(), Box<dyn Error>>;
+    /// Returns true if there is a library to load.
+    fn exists(&self) -> bool;
+}
+
+/// The default backend: a single JSON file with sorted keys (see ADR 0001).
+#[derive(Debug, Clone)]
+pub struct JsonBackend {
+    path: PathBuf,
+}
+
+impl JsonBackend {
+    /// Creates a backend for the JSON file at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl StorageBackend for JsonBackend {
+    fn load(&self) -> Result<Storage, Box<dyn Error>> {
+        let contents = fs::read_to_string(&self.path)?;
+        let storage: Storage = serde_json::from_str(&contents)?;
+        Ok(storage)
+    }
+
+    /// Writes the file, creating parent directories if they don't exist.
+    fn save(&self, storage: &Storage) -> Result<(), Box<dyn Error>> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, storage.to_sorted_json_string()?)?;
+        Ok(())
+    }
+
+    fn exists(&self) -> bool {
+        self.path.exists()
+    }
+}
+
+/// Picks the backend for a storage file. Every path uses `JsonBackend` for now;
+/// this is where other backends will be chosen by file extension.
+pub fn backend_for_path(path: &str) -> Box<dyn StorageBackend> {
+    Box::new(JsonBackend::new(Path::new(path)))
+}
@@ -1,21 +1,78 @@
-use crate::lookup::book_lookup_dto::BookLookupDTO;
-use crate::lookup::http_client::HttpClient;
+use crate::author::{find_author_by_name, normalize_author_name};
+use crate::lookup::book_lookup_dto::{parse_publish_year, BookLookupDTO};
+use crate::lookup::http_client::{HttpClient, LOOKUP_TIMED_OUT};
 use crate::series::get_or_create_series;
-use crate::storage::{Author, Book, Category, ReadingEvent, Storage};
+use crate::storage::{Author, Book, Category, ReadingEvent, Storage, StorageError};
 use indicatif::{ProgressBar, ProgressStyle};
 use inquire::{Select, Text};
 use std::io;
 use std::time::Duration;
 
-/// Interactively prompts the user for book details, performing ISBN lookup for auto-fill.
-/// Returns the constructed Book and any initial reading events (Bought, WantToRead).
-pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEvent>)> {
-    // First get ISBN
-    let isbn = Text::new("Enter ISBN:")
-        .prompt()
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+/// The status recorded for a newly added book, as offered by `add-book`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitialStatus {
+    Bought,
+    WantToRead,
+    Both,
+    Neither,
+}
+
+impl InitialStatus {
+    /// All statuses, in the order they are offered.
+    pub const ALL: [InitialStatus; 4] = [
+        InitialStatus::Bought,
+        InitialStatus::WantToRead,
+        InitialStatus::Both,
+        InitialStatus::Neither,
+    ];
+
+    /// The label shown in the status prompt.
+    pub fn label(&self) -> &'static str {
+        match self {
+            InitialStatus::Bought => "Already bought",
+            InitialStatus::WantToRead => "Want to read",
+            InitialStatus::Both => "Both",
+            InitialStatus::Neither => "Neither",
+        }
+    }
 
-    // Create a spinner for the lookup
+    /// The reading events to record for a new book with this status.
+    pub fn events(&self) -> Vec<ReadingEvent> {
+        match self {
+            InitialStatus::Bought => vec![ReadingEvent::Bought],
+            InitialStatus::WantToRead => vec![ReadingEvent::WantToRead],
+            InitialStatus::Both => vec![ReadingEvent::Bought, ReadingEvent::WantToRead],
+            InitialStatus::Neither => vec![],
+        }
+    }
+}
+
+/// Parses a book status name such as `bought`, `want-to-read`, `both` or `neither`
+/// (case-insensitive; the prompt labels like "Already bought" are accepted too).
+pub fn parse_initial_status(input: &str) -> Result<InitialStatus, String> {
+    let normalized: String = input
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .collect();
+    match normalized.as_str() {
+        "bought" | "alreadybought" => Ok(InitialStatus::Bought),
+        "wanttoread" => Ok(InitialStatus::WantToRead),
+        "both" => Ok(InitialStatus::Both),
+        "neither" => Ok(InitialStatus::Neither),
+        _ => Err(format!(
+            "Unknown book status '{}' (expected bought, want-to-read, both or neither)",
+            input
+        )),
+    }
+}
+
+/// Looks up `isbn` while showing a spinner. Prints ISBN suggestions when nothing is found.
+///
+/// The lookup gives up after the client's timeout; the spinner is cleared and
+/// `None` is returned so the details can be entered manually.
+pub fn lookup_with_spinner(client: &HttpClient, isbn: &str) -> io::Result<Option<BookLookupDTO>> {
     let spinner = ProgressBar::new_spinner();
     spinner.set_style(
         ProgressStyle::default_spinner()
@@ -25,29 +82,76 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
     );
     spinner.enable_steady_tick(Duration::from_millis(100));
 
-    // Look up book details
-    let client = HttpClient::new();
-    let book_info = match tokio::runtime::Runtime::new()
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
-        .block_on(client.get_book_by_isbn(&isbn))
-    {
-        Ok(Some(info)) => {
-            spinner.finish_and_clear();
-            info
+    let result = tokio::runtime::Runtime::new()
+        .map_err(io::Error::other)?
+        .block_on(client.get_book_by_isbn(isbn));
+    spinner.finish_and_clear();
+
+    match result {
+        Ok(Some(info)) => Ok(Some(info)),
+        Err(e) if e.to_string() == LOOKUP_TIMED_OUT => {
+            println!("Lookup timed out, enter the book details manually.");
+            Ok(None)
         }
         Ok(None) | Err(_) => {
-            spinner.finish_and_clear();
-            BookLookupDTO {
-                title: String::new(),
-                authors: vec![],
-                description: None,
-                isbn: String::new(),
-                publish_date: None,
-                cover_url: None,
-                series_name: None,
-                series_position: None,
+            if let Some(hint) = crate::isbn::format_isbn_suggestions(isbn) {
+                println!("No book found for ISBN {}. {}", isbn.trim(), hint);
             }
+            Ok(None)
         }
+    }
+}
+
+/// Interactively prompts the user for book details, performing ISBN lookup for auto-fill.
+/// Returns the constructed Book and any initial reading events (Bought, WantToRead).
+///
+/// The status prompt starts on `default_status` when given, and is skipped
+/// entirely when `status` is given. With `strict_isbn`, a missing or invalid
+/// ISBN is rejected before any lookup. With `no_isbn`, or when the ISBN is
+/// left blank, the book is stored without an ISBN and nothing is looked up.
+pub fn get_book_input(
+    storage: &mut Storage,
+    client: &HttpClient,
+    default_status: Option<InitialStatus>,
+    status: Option<InitialStatus>,
+    strict_isbn: bool,
+    no_isbn: bool,
+) -> io::Result<(Book, Vec<ReadingEvent>)> {
+    // First get ISBN
+    let isbn = if no_isbn {
+        String::new()
+    } else {
+        Text::new("Enter ISBN (leave blank if the book has none):")
+            .prompt()
+            .map_err(io::Error::other)?
+    };
+    if strict_isbn {
+        crate::isbn::validate_strict_isbn(&isbn).map_err(io::Error::other)?;
+    }
+
+    let empty_info = || BookLookupDTO {
+        title: String::new(),
+        subtitle: None,
+        authors: vec![],
+        description: None,
+        isbn: String::new(),
+        publish_date: None,
+        publisher: None,
+        cover_url: None,
+        total_pages: None,
+        series_name: None,
+        series_position: None,
+        source: None,
+    };
+
+    // Skip the lookup entirely when there is no ISBN or network access is disabled
+    let book_info = if isbn.trim().is_empty() {
+        empty_info()
+    } else if !client.is_network_enabled() {
+        println!("Network access is disabled, enter the book details manually.");
+        empty_info()
+    } else {
+        lookup_with_spinner(client, &isbn)?.unwrap_or_else(empty_info)
     };
 
     // Suggest title from lookup or prompt for new one
@@ -55,19 +159,23 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
         Text::new("Enter title:")
             .with_default(&book_info.title)
             .prompt()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+            .map_err(io::Error::other)?
     } else {
         Text::new("Enter title:")
             .prompt()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+            .map_err(io::Error::other)?
     };
 
-    let total_pages = Text::new("Enter total pages:")
-        .prompt()
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
-        .trim()
-        .parse::<i32>()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let total_pages = match book_info.total_pages {
+        Some(pages) => Text::new("Enter total pages:")
+            .with_default(&pages.to_string())
+            .prompt(),
+        None => Text::new("Enter total pages:").prompt(),
+    }
+    .map_err(io::Error::other)?
+    .trim()
+    .parse::<i32>()
+    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
     // Get list of categories with their IDs
     let categories: Vec<(String, String)> = storage
@@ -80,14 +188,13 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
         // If no categories exist, prompt for a new one
         let category_name = Text::new("Enter new category:")
             .prompt()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            .map_err(io::Error::other)?;
 
         // Create a new category
         let category = Category::new(category_name.trim().to_string(), None);
 
         // Store the category and get its ID
-        crate::category::store_category(storage, category)
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        crate::category::store_category(storage, category).map_err(io::Error::other)?;
 
         // Get the ID of the newly created category
         storage
@@ -95,7 +202,7 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
             .iter()
             .find(|(_, c)| c.name == category_name.trim())
             .map(|(id, _)| id.clone())
-            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Failed to get category ID"))?
+            .ok_or_else(|| io::Error::other("Failed to get category ID"))?
     } else {
         // Show category selection dialog with option to create new
         let mut options = categories
@@ -106,20 +213,19 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
 
         let selection = Select::new("Select category:", options)
             .prompt()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            .map_err(io::Error::other)?;
 
         if selection == "+ Create new category" {
             // Prompt for new category name
             let category_name = Text::new("Enter new category name:")
                 .prompt()
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+                .map_err(io::Error::other)?;
 
             // Create a new category
             let category = Category::new(category_name.trim().to_string(), None);
 
             // Store the category and get its ID
-            crate::category::store_category(storage, category)
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            crate::category::store_category(storage, category).map_err(io::Error::other)?;
 
             // Get the ID of the newly created category
             storage
@@ -127,16 +233,14 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
                 .iter()
                 .find(|(_, c)| c.name == category_name.trim())
                 .map(|(id, _)| id.clone())
-                .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Failed to get category ID"))?
+                .ok_or_else(|| io::Error::other("Failed to get category ID"))?
         } else {
             // Find the selected category's ID
             categories
                 .iter()
                 .find(|(name, _)| name.as_str() == selection)
                 .map(|(_, id)| id.clone())
-                .ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::Other, "Selected category not found")
-                })?
+                .ok_or_else(|| io::Error::other("Selected category not found"))?
         }
     };
 
@@ -149,21 +253,17 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
 
     let author_id = if authors.is_empty() {
         // If no authors exist, suggest the first author from lookup or prompt for new one
-        let suggested_author = book_info
-            .authors
-            .first()
-            .map(|a| a.name.clone())
-            .unwrap_or_default();
+        let suggested_author = suggested_author_name(storage, &book_info);
 
         let author_name = if !suggested_author.is_empty() {
             Text::new("Enter new author name:")
                 .with_default(&suggested_author)
                 .prompt()
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+                .map_err(io::Error::other)?
         } else {
             Text::new("Enter new author name:")
                 .prompt()
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+                .map_err(io::Error::other)?
         };
 
         // Create a new author
@@ -178,7 +278,7 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
             .iter()
             .find(|(_, a)| a.name == author_name.trim())
             .map(|(id, _)| id.clone())
-            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Failed to get author ID"))?
+            .ok_or_else(|| io::Error::other("Failed to get author ID"))?
     } else {
         // Show author selection dialog with option to create new
         let mut options = authors
@@ -189,11 +289,7 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
         options.push("+ Create new author");
 
         // Get suggested author from lookup
-        let suggested_author = book_info
-            .authors
-            .first()
-            .map(|a| a.name.clone())
-            .unwrap_or_default();
+        let suggested_author = suggested_author_name(storage, &book_info);
 
         // Track if we added the suggested author to options
         let suggested_author_added =
@@ -206,25 +302,21 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
 
         let selection = Select::new("Select author:", options)
             .prompt()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            .map_err(io::Error::other)?;
 
         if selection == "+ Create new author" {
             // Suggest the first author from lookup or prompt for new one
-            let suggested_author = book_info
-                .authors
-                .first()
-                .map(|a| a.name.clone())
-                .unwrap_or_default();
+            let suggested_author = suggested_author_name(storage, &book_info);
 
             let author_name = if !suggested_author.is_empty() {
                 Text::new("Enter new author name:")
                     .with_default(&suggested_author)
                     .prompt()
-                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+                    .map_err(io::Error::other)?
             } else {
                 Text::new("Enter new author name:")
                     .prompt()
-                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+                    .map_err(io::Error::other)?
             };
 
             // Create a new author
@@ -239,7 +331,7 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
                 .iter()
                 .find(|(_, a)| a.name == author_name.trim())
                 .map(|(id, _)| id.clone())
-                .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Failed to get author ID"))?
+                .ok_or_else(|| io::Error::other("Failed to get author ID"))?
         } else if suggested_author_added && selection == suggested_author {
             // User selected the suggested author, add it to storage
             let author = Author::new(suggested_author.trim().to_string());
@@ -251,46 +343,89 @@ pub fn get_book_input(storage: &mut Storage) -> io::Result<(Book, Vec<ReadingEve
                 .iter()
                 .find(|(_, a)| a.name == suggested_author.trim())
                 .map(|(id, _)| id.clone())
-                .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Failed to get author ID"))?
+                .ok_or_else(|| io::Error::other("Failed to get author ID"))?
         } else {
             // Find the selected author's ID from existing authors
             authors
                 .iter()
                 .find(|(name, _)| name.as_str() == selection)
                 .map(|(_, id)| id.clone())
-                .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Selected author not found"))?
+                .ok_or_else(|| io::Error::other("Selected author not found"))?
         }
     };
 
     // Series selection (optional)
     let (series_id, position_in_series) = select_series(storage, &book_info)?;
 
-    // Ask about book status
-    let options = vec!["Already bought", "Want to read", "Both", "Neither"];
-    let selection = Select::new("What is the status of this book?", options)
-        .prompt()
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    // Guard against adding a second copy of a book already in this series
+    if let Some(existing) = series_id
+        .as_deref()
+        .and_then(|id| storage.find_book_in_series_by_title(id, &title))
+        .filter(|existing| existing.author_id == author_id)
+    {
+        let isbn_note = if existing.isbn.trim().is_empty() {
+            String::new()
+        } else {
+            format!(" (ISBN {})", existing.isbn)
+        };
+        println!(
+            "Warning: \"{}\" by {} is already in this series{}.",
+            existing.title,
+            storage.author_name_for_book(existing),
+            isbn_note
+        );
+        let proceed = Select::new("Add it anyway?", vec!["No", "Yes"])
+            .prompt()
+            .map_err(io::Error::other)?;
+        if proceed == "No" {
+            return Err(io::Error::other("not adding a probable duplicate"));
+        }
+    }
 
-    let event = match selection {
-        "Already bought" => vec![ReadingEvent::Bought],
-        "Want to read" => vec![ReadingEvent::WantToRead],
-        "Both" => vec![ReadingEvent::Bought, ReadingEvent::WantToRead],
-        _ => vec![],
+    // Ask about book status, unless it was given up front
+    let status = match status {
+        Some(status) => status,
+        None => {
+            let options: Vec<&str> = InitialStatus::ALL.iter().map(|s| s.label()).collect();
+            let cursor = default_status
+                .and_then(|d| InitialStatus::ALL.iter().position(|s| *s == d))
+                .unwrap_or(0);
+            let selection = Select::new("What is the status of this book?", options)
+                .with_starting_cursor(cursor)
+                .prompt()
+                .map_err(io::Error::other)?;
+            InitialStatus::ALL
+                .into_iter()
+                .find(|s| s.label() == selection)
+                .unwrap_or(InitialStatus::Neither)
+        }
     };
+    let event = status.events();
 
-    let mut book = Book::new(
-        title.trim().to_string(),
-        isbn.trim().to_string(),
-        category_id,
-        author_id,
-        total_pages,
-    );
+    // Start from the lookup result, then apply what the user entered
+    let mut book = Book::from_lookup(&book_info, category_id, author_id);
+    book.title = title.trim().to_string();
+    book.isbn = isbn.trim().to_string();
+    book.total_pages = total_pages;
     book.series_id = series_id;
     book.position_in_series = position_in_series;
 
     Ok((book, event))
 }
 
+/// Returns the author name to suggest for a lookup result: the name of an
+/// existing author it matches (see `find_author_by_name`), otherwise the
+/// lookup's first author normalized. Empty if the lookup has no author.
+fn suggested_author_name(storage: &Storage, book_info: &BookLookupDTO) -> String {
+    let Some(author) = book_info.authors.first() else {
+        return String::new();
+    };
+    match find_author_by_name(storage, &author.name) {
+        Some(existing) => existing.name.clone(),
+        None => normalize_author_name(&author.name),
+    }
+}
+
 /// Interactively prompts the user to select or create a series for a book.
 /// Returns (series_id, position_in_series) or (None, None) if the user skips.
 fn select_series(
@@ -323,7 +458,7 @@ fn select_series(
 
     // Add existing series, sorted alphabetically
     let mut sorted_existing: Vec<&(String, String)> = existing_series.iter().collect();
-    sorted_existing.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+    sorted_existing.sort_by_key(|a| a.0.to_lowercase());
     for (name, _) in &sorted_existing {
         options.push(name.clone());
     }
@@ -333,7 +468,7 @@ fn select_series(
 
     let selection = Select::new("Series:", options.iter().map(|s| s.as_str()).collect())
         .prompt()
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        .map_err(io::Error::other)?;
 
     if selection == "No series (standalone)" {
         return Ok((None, None));
@@ -344,11 +479,11 @@ fn select_series(
             Text::new("Enter series name:")
                 .with_default(&suggested_series)
                 .prompt()
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+                .map_err(io::Error::other)?
         } else {
             Text::new("Enter series name:")
                 .prompt()
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+                .map_err(io::Error::other)?
         };
         get_or_create_series(storage, name.trim())
     } else if selection.starts_with("Use suggested: ") {
@@ -359,7 +494,7 @@ fn select_series(
             .iter()
             .find(|(name, _)| name.as_str() == selection)
             .map(|(_, id)| id.clone())
-            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Selected series not found"))?
+            .ok_or_else(|| io::Error::other("Selected series not found"))?
     };
 
     // Ask for position in series
@@ -379,23 +514,19 @@ fn select_series(
         Text::new("Book number in series (e.g. 3), or Enter for none:")
             .with_default(pos)
             .prompt()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+            .map_err(io::Error::other)?
     } else {
         Text::new("Book number in series (e.g. 3), or Enter for none:")
             .prompt()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+            .map_err(io::Error::other)?
     };
 
     let position = crate::series::parse_position_input(&position_str);
 
     // Warn if the position is already occupied by another book
     if let Some(ref pos) = position {
-        if let Some(existing_title) = crate::series::is_position_occupied(storage, &series_id, pos)
-        {
-            println!(
-                "Note: '{}' is already #{} in this series.",
-                existing_title, pos
-            );
+        if let Err(e) = crate::series::check_position_free(storage, &series_id, pos) {
+            println!("Note: {}.", e);
         }
     }
 
@@ -403,27 +534,239 @@ fn select_series(
 }
 
 /// Validates and stores a book. Returns an error if the referenced author, category, or series doesn't exist.
-pub fn store_book(storage: &mut Storage, book: Book) -> Result<(), String> {
+pub fn store_book(storage: &mut Storage, book: Book) -> Result<(), StorageError> {
+    store_book_with_isbn_check(storage, book, false)
+}
+
+/// Like `store_book`, additionally rejecting a missing or invalid ISBN when
+/// `strict_isbn` is set.
+pub fn store_book_with_isbn_check(
+    storage: &mut Storage,
+    book: Book,
+    strict_isbn: bool,
+) -> Result<(), StorageError> {
+    if strict_isbn {
+        crate::isbn::validate_strict_isbn(&book.isbn).map_err(StorageError::InvalidIsbn)?;
+    }
+
     // Validate that the category exists
     if !storage.categories.contains_key(&book.category_id) {
-        return Err(format!(
-            "Category with ID {} does not exist",
-            book.category_id
-        ));
+        return Err(StorageError::MissingCategory(book.category_id));
     }
 
     // Validate that the author exists
     if !storage.authors.contains_key(&book.author_id) {
-        return Err(format!("Author with ID {} does not exist", book.author_id));
+        return Err(StorageError::MissingAuthor(book.author_id));
     }
 
     // Validate that the series exists (if set)
     if let Some(ref series_id) = book.series_id {
         if !storage.series.contains_key(series_id) {
-            return Err(format!("Series with ID {} does not exist", series_id));
+            return Err(StorageError::MissingSeries(series_id.clone()));
         }
     }
 
     storage.books.insert(book.id.clone(), book);
     Ok(())
 }
+
+/// Fills in a stored book's blank fields from an ISBN lookup result.
+///
+/// Only fields that are currently empty are touched (title, subtitle, description, cover URL,
+/// publisher, publish year, series and position, total pages), so values the user has entered are never
+/// overwritten. Returns the names of the fields that were filled in.
+pub fn enrich_book(
+    storage: &mut Storage,
+    book_id: &str,
+    info: &BookLookupDTO,
+) -> Result<Vec<&'static str>, String> {
+    if !storage.books.contains_key(book_id) {
+        return Err(format!("Book with ID {} does not exist", book_id));
+    }
+
+    // Resolve the looked-up series before borrowing the book mutably
+    let book_has_series = storage
+        .books
+        .get(book_id)
+        .is_some_and(|b| b.series_id.is_some());
+    let lookup_series_id = match info.series_name.as_deref().map(str::trim) {
+        Some(name) if !name.is_empty() && !book_has_series => {
+            Some(get_or_create_series(storage, name))
+        }
+        _ => None,
+    };
+
+    let book = storage
+        .books
+        .get_mut(book_id)
+        .expect("book existence checked above");
+    let mut filled = Vec::new();
+
+    if book.title.trim().is_empty() && !info.title.trim().is_empty() {
+        book.title = info.title.trim().to_string();
+        filled.push("title");
+    }
+
+    if book.subtitle.is_none() {
+        if let Some(ref subtitle) = info.subtitle {
+            book.subtitle = Some(subtitle.clone());
+            filled.push("subtitle");
+        }
+    }
+
+    if book.description.is_none() {
+        if let Some(ref description) = info.description {
+            book.description = Some(description.clone());
+            filled.push("description");
+        }
+    }
+
+    if book.cover_url.is_none() {
+        if let Some(ref cover_url) = info.cover_url {
+            book.cover_url = Some(cover_url.clone());
+            filled.push("cover");
+        }
+    }
+
+    if book.publisher.is_none() {
+        if let Some(ref publisher) = info.publisher {
+            book.publisher = Some(publisher.clone());
+            filled.push("publisher");
+        }
+    }
+
+    if book.publish_year.is_none() {
+        if let Some(year) = info.publish_date.as_deref().and_then(parse_publish_year) {
+            book.publish_year = Some(year);
+            filled.push("publish year");
+        }
+    }
+
+    if let Some(series_id) = lookup_series_id {
+        book.series_id = Some(series_id);
+        filled.push("series");
+        if book.position_in_series.is_none() && info.series_position.is_some() {
+            book.position_in_series = info.series_position.clone();
+            filled.push("position in series");
+        }
+    }
+
+    if book.total_pages <= 0 {
+        if let Some(pages) = info.total_pages.filter(|p| *p > 0) {
+            book.total_pages = pages;
+            filled.push("total pages");
+        }
+    }
+
+    // Record where the new details came from, unless the book already has a source
+    if !filled.is_empty() && book.source.is_none() {
+        book.source = info.source.clone();
+    }
+
+    Ok(filled)
+}
+
+/// Formats the detail view of a book: its fields (title with subtitle, author,
+/// ISBN, category, series, pages, publisher, publish year, when it was added and
+/// the provider its details came from), followed by a timeline of its reading
+/// events, oldest first. Dates are shown in `date_format`, and fields that
+/// aren't set are left out.
+pub fn format_book_details(storage: &Storage, book: &Book, date_format: &str) -> String {
+    let mut lines = vec![format!("Title: {}", book.display_title(true))];
+    let author = storage.author_name_for_book(book);
+    if !author.is_empty() {
+        lines.push(format!("Author: {}", author));
+    }
+    if !book.isbn.trim().is_empty() {
+        lines.push(format!("ISBN: {}", book.isbn));
+    }
+    if let Some(category) = storage.get_category(&book.category_id) {
+        lines.push(format!("Category: {}", category.name));
+    }
+    if let Some(series) = book
+        .series_id
+        .as_deref()
+        .and_then(|id| storage.get_series(id))
+    {
+        lines.push(format!(
+            "Series: {}",
+            crate::series::format_series_label(series, book.position_in_series.as_deref())
+        ));
+    }
+    if book.total_pages > 0 {
+        lines.push(format!("Pages: {}", book.total_pages));
+    }
+    if let Some(publisher) = &book.publisher {
+        lines.push(format!("Publisher: {}", publisher));
+    }
+    if let Some(year) = book.publish_year {
+        lines.push(format!("Published: {}", year));
+    }
+    lines.push(format!("Added: {}", book.added_on.format(date_format)));
+    if let Some(source) = &book.source {
+        lines.push(format!("Source: {}", source));
+    }
+
+    let mut readings: Vec<_> = storage
+        .readings
+        .values()
+        .filter(|r| r.book_id == book.id)
+        .collect();
+    readings.sort_by(|a, b| a.cmp_chronological(b));
+    if !readings.is_empty() {
+        lines.push("History:".to_string());
+        for reading in readings {
+            lines.push(format!(
+                "  {} \u{2014} {}",
+                reading.created_on.format(date_format),
+                crate::reading::format_event_label(reading)
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// A book in a selection prompt: the label shown to the user and the ID of the
+/// book it stands for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookChoice {
+    pub label: String,
+    pub book_id: String,
+}
+
+impl std::fmt::Display for BookChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// Builds the options for picking one of `books` in interactive mode, labelled
+/// `[Status] "Title" by Author`. Books being read come first, then the rest,
+/// each sorted by author and title. Returns an error if a book's author is missing.
+pub fn book_choices(storage: &Storage, books: &[&Book]) -> Result<Vec<BookChoice>, String> {
+    let mut sorted: Vec<(bool, &Book)> = books
+        .iter()
+        .map(|b| (storage.is_book_started(&b.id), *b))
+        .collect();
+    sorted.sort_by(|(a_started, a), (b_started, b)| {
+        b_started
+            .cmp(a_started)
+            .then_with(|| {
+                storage
+                    .author_name_for_book(a)
+                    .cmp(storage.author_name_for_book(b))
+            })
+            .then_with(|| a.title.cmp(&b.title))
+    });
+    sorted
+        .into_iter()
+        .map(|(started, book)| {
+            let status = if started { "Started" } else { "Not Started" };
+            Ok(BookChoice {
+                label: book.to_display_string(storage, status)?,
+                book_id: book.id.clone(),
+            })
+        })
+        .collect()
+}
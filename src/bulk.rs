@@ -0,0 +1,143 @@
+use crate::author::get_or_create_author;
+use crate::lookup::book_lookup_dto::BookLookupDTO;
+use crate::storage::{Book, Reading, ReadingEvent, Storage};
+
+/// Category used for bulk-added books when none is given.
+pub const DEFAULT_BULK_CATEGORY: &str = "Uncategorized";
+
+/// Parses the contents of an ISBN list file.
+///
+/// One ISBN per line. Blank lines and lines starting with `#` are ignored,
+/// surrounding whitespace is trimmed and duplicates are dropped (first wins).
+pub fn parse_isbn_list(contents: &str) -> Vec<String> {
+    let mut isbns: Vec<String> = Vec::new();
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !isbns.iter().any(|i| i == trimmed) {
+            isbns.push(trimmed.to_string());
+        }
+    }
+    isbns
+}
+
+/// Result of a bulk add: titles that were added, and ISBNs that were skipped
+/// or failed together with the reason.
+#[derive(Debug, Default, PartialEq)]
+pub struct BulkAddSummary {
+    pub added: Vec<String>,
+    pub skipped: Vec<(String, String)>,
+    pub failed: Vec<(String, String)>,
+}
+
+impl BulkAddSummary {
+    /// Formats the summary for display, e.g. "Added 3, skipped 1, failed 0." followed
+    /// by one line per skipped or failed ISBN.
+    pub fn format(&self) -> String {
+        let mut lines = vec![format!(
+            "Added {}, skipped {}, failed {}.",
+            self.added.len(),
+            self.skipped.len(),
+            self.failed.len()
+        )];
+        for (isbn, reason) in &self.skipped {
+            lines.push(format!("  skipped {}: {}", isbn, reason));
+        }
+        for (isbn, reason) in &self.failed {
+            lines.push(format!("  failed {}: {}", isbn, reason));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Returns true if a lookup result has enough details to add a book without asking.
+fn is_complete(info: &BookLookupDTO) -> bool {
+    !info.title.trim().is_empty()
+        && info
+            .authors
+            .first()
+            .is_some_and(|a| !a.name.trim().is_empty())
+}
+
+/// Adds a book from a lookup result, creating its author and series as needed,
+/// and records it as bought. Returns the title of the added book.
+pub fn add_book_from_lookup(
+    storage: &mut Storage,
+    isbn: &str,
+    category_id: &str,
+    info: &BookLookupDTO,
+) -> Result<String, String> {
+    if !is_complete(info) {
+        return Err("lookup result is missing a title or author".to_string());
+    }
+
+    let author_id = get_or_create_author(storage, info.authors[0].name.trim());
+    let mut book = Book::from_lookup(info, category_id.to_string(), author_id);
+    book.isbn = isbn.to_string();
+    if let Some(name) = info
+        .series_name
+        .as_deref()
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+    {
+        book.series_id = Some(crate::series::get_or_create_series(storage, name));
+    }
+
+    let title = book.title.clone();
+    let book_id = book.id.clone();
+    crate::book::store_book(storage, book)?;
+    storage.add_reading(Reading::new(book_id, ReadingEvent::Bought));
+    Ok(title)
+}
+
+/// Adds every ISBN in `isbns` to the library.
+///
+/// `lookup` fetches details for an ISBN. When it finds nothing, or returns a result
+/// without a title or author, `resolve` is asked to supply the missing details
+/// (it receives the partial result, if any); returning `None` skips the ISBN.
+/// ISBNs already in the library are skipped and lookup errors count as failures.
+pub fn bulk_add<L, R>(
+    storage: &mut Storage,
+    isbns: &[String],
+    category_id: &str,
+    mut lookup: L,
+    mut resolve: R,
+) -> BulkAddSummary
+where
+    L: FnMut(&str) -> Result<Option<BookLookupDTO>, String>,
+    R: FnMut(&str, Option<&BookLookupDTO>) -> Option<BookLookupDTO>,
+{
+    let mut summary = BulkAddSummary::default();
+
+    for isbn in isbns {
+        if storage.get_book_by_isbn(isbn).is_some() {
+            summary
+                .skipped
+                .push((isbn.clone(), "already in library".to_string()));
+            continue;
+        }
+
+        let info = match lookup(isbn) {
+            Ok(Some(info)) if is_complete(&info) => Some(info),
+            Ok(partial) => resolve(isbn, partial.as_ref()),
+            Err(e) => {
+                summary.failed.push((isbn.clone(), e));
+                continue;
+            }
+        };
+
+        match info {
+            Some(info) => match add_book_from_lookup(storage, isbn, category_id, &info) {
+                Ok(title) => summary.added.push(title),
+                Err(e) => summary.failed.push((isbn.clone(), e)),
+            },
+            None => summary
+                .skipped
+                .push((isbn.clone(), "no book details found".to_string())),
+        }
+    }
+
+    summary
+}
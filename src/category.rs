@@ -5,3 +5,71 @@ pub fn store_category(storage: &mut Storage, category: Category) -> Result<(), S
     storage.categories.insert(category.id.clone(), category);
     Ok(())
 }
+
+/// Finds a category by name (case-insensitive) or creates it. Returns the category ID.
+pub fn get_or_create_category(storage: &mut Storage, name: &str) -> String {
+    if let Some((id, _)) = storage
+        .categories
+        .iter()
+        .find(|(_, c)| c.name.to_lowercase() == name.to_lowercase())
+    {
+        return id.clone();
+    }
+
+    let category = Category::new(name.to_string(), None);
+    let id = category.id.clone();
+    storage.add_category(category);
+    id
+}
+
+/// Sets or clears a category's description. Blank descriptions are stored as `None`.
+pub fn set_category_description(
+    storage: &mut Storage,
+    id: &str,
+    description: Option<String>,
+) -> Result<(), String> {
+    let category = storage
+        .categories
+        .get_mut(id)
+        .ok_or_else(|| "Category not found".to_string())?;
+    category.description = description
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty());
+    Ok(())
+}
+
+/// Formats all categories sorted by name, one per line, with their book count
+/// and description when set, e.g. "Fiction (12 books) — Novels and short stories".
+pub fn format_category_list(storage: &Storage) -> String {
+    let mut categories: Vec<&Category> = storage.categories.values().collect();
+    categories.sort_by_key(|c| c.name.to_lowercase());
+
+    categories
+        .iter()
+        .map(|category| {
+            let count = storage
+                .books
+                .values()
+                .filter(|b| b.category_id == category.id)
+                .count();
+            let books = if count == 1 { "book" } else { "books" };
+            match category.description.as_deref() {
+                Some(description) => format!(
+                    "{} ({} {}) \u{2014} {}",
+                    category.name, count, books, description
+                ),
+                None => format!("{} ({} {})", category.name, count, books),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Prints all categories with their descriptions.
+pub fn list_categories(storage: &Storage) {
+    if storage.categories.is_empty() {
+        println!("No categories found.");
+    } else {
+        println!("{}", format_category_list(storage));
+    }
+}
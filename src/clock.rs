@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+
+/// A source of the current time, so time-dependent code can be tested with a
+/// fixed "now".
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The real clock, backed by `Utc::now()`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that is stopped at a given time. Mostly useful in tests.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
@@ -1,8 +1,13 @@
+use crate::book::{parse_initial_status, InitialStatus};
+use crate::lookup::http_client::HttpClient;
+use crate::lookup::providers::KNOWN_PROVIDERS;
+use crate::time::{parse_timezone, Timezone};
+use chrono::format::{Item, StrftimeItems};
 use config::{Config, ConfigError, File, FileFormat};
 use dirs::config_dir;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // Embed the default configuration directly in the binary
 const DEFAULT_CONFIG: &str = include_str!("../config/default.yml");
@@ -35,14 +40,142 @@ fn create_config() -> Result<(), ConfigError> {
     Ok(())
 }
 
-#[derive(Debug, Deserialize)]
+fn default_providers() -> Vec<String> {
+    KNOWN_PROVIDERS.iter().map(|p| p.to_string()).collect()
+}
+
+fn default_timezone() -> String {
+    "UTC".to_string()
+}
+
+/// Date format used when the `date_format` setting is not given.
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+fn default_date_format() -> String {
+    DEFAULT_DATE_FORMAT.to_string()
+}
+
+fn default_lookup_timeout_secs() -> u64 {
+    crate::lookup::http_client::DEFAULT_LOOKUP_TIMEOUT.as_secs()
+}
+
+fn default_stale_days() -> u32 {
+    21
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Settings {
     #[allow(dead_code)]
     pub app_name: String,
     #[allow(dead_code)]
     pub debug: bool,
-    #[serde(skip)]
+    #[serde(skip_deserializing)]
     pub storage_file: String,
+    /// ISBN lookup providers, queried in this order
+    #[serde(default = "default_providers")]
+    pub providers: Vec<String>,
+    /// Timezone for dates: `UTC`, a fixed offset such as `+02:00` or an IANA
+    /// name such as `Europe/Oslo`
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
+    /// strftime-style format used when printing dates
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
+    /// Status pre-selected when adding a book: bought, want-to-read, both or neither
+    #[serde(default)]
+    pub default_book_status: Option<String>,
+    /// Seconds to wait for an ISBN lookup before entering details manually
+    #[serde(default = "default_lookup_timeout_secs")]
+    pub lookup_timeout_secs: u64,
+    /// Refuse to add books without a valid ISBN-10 or ISBN-13
+    #[serde(default)]
+    pub strict_isbn: bool,
+    /// Days without activity before a book being read is called stale; 0 turns
+    /// the warning off
+    #[serde(default = "default_stale_days")]
+    pub stale_days: u32,
+    /// Use ASCII status symbols (`x`, `>`) instead of `✓` and `▸`
+    #[serde(default)]
+    pub ascii_symbols: bool,
+    /// Symbol for finished books, overriding the default
+    #[serde(default)]
+    pub finished_symbol: Option<String>,
+    /// Symbol for books being read, overriding the default
+    #[serde(default)]
+    pub reading_symbol: Option<String>,
+    /// List every problem found in the storage file before asking how to fix
+    /// them, saving once at the end
+    #[serde(default)]
+    pub repair_summary: bool,
+}
+
+/// A problem found by `Settings::validate`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigIssue {
+    /// The setting the problem is about, e.g. "providers"
+    pub setting: &'static str,
+    pub message: String,
+}
+
+/// Settings checked by `Settings::validate`, in the order they are reported.
+pub const VALIDATED_SETTINGS: [&str; 5] = [
+    "storage_file",
+    "providers",
+    "timezone",
+    "date_format",
+    "default_book_status",
+];
+
+/// Symbols marking a book's reading status in the series and all-books views.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusSymbols {
+    pub finished: String,
+    pub reading: String,
+    pub unread: String,
+}
+
+impl Default for StatusSymbols {
+    /// `✓` finished, `▸` reading, blank for unread.
+    fn default() -> Self {
+        Self {
+            finished: "\u{2713}".to_string(),
+            reading: "\u{25b8}".to_string(),
+            unread: " ".to_string(),
+        }
+    }
+}
+
+impl StatusSymbols {
+    /// ASCII-only symbols for terminals without Unicode: `x` finished, `>` reading.
+    pub fn ascii() -> Self {
+        Self {
+            finished: "x".to_string(),
+            reading: ">".to_string(),
+            unread: " ".to_string(),
+        }
+    }
+}
+
+/// Checks that the storage file can be written, without modifying it.
+fn check_storage_writable(path: &str) -> Result<(), String> {
+    if path.is_empty() {
+        return Err("Storage path is not set".to_string());
+    }
+    let path = Path::new(path);
+    if path.exists() {
+        return fs::OpenOptions::new()
+            .append(true)
+            .open(path)
+            .map(|_| ())
+            .map_err(|e| format!("Storage file {} is not writable: {}", path.display(), e));
+    }
+    let parent = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+    tempfile::NamedTempFile::new_in(parent)
+        .map(|_| ())
+        .map_err(|e| format!("Directory {} is not writable: {}", parent.display(), e))
 }
 
 impl Settings {
@@ -83,11 +216,120 @@ impl Settings {
         Ok(settings)
     }
 
+    /// Checks the settings and returns every problem found (empty when all is well).
+    pub fn validate(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+
+        if let Err(message) = check_storage_writable(&self.storage_file) {
+            issues.push(ConfigIssue {
+                setting: "storage_file",
+                message,
+            });
+        }
+
+        if self.providers.is_empty() {
+            issues.push(ConfigIssue {
+                setting: "providers",
+                message: "No lookup providers configured".to_string(),
+            });
+        }
+        for provider in &self.providers {
+            if !KNOWN_PROVIDERS.contains(&provider.to_lowercase().as_str()) {
+                issues.push(ConfigIssue {
+                    setting: "providers",
+                    message: format!(
+                        "Unknown provider '{}' (known providers: {})",
+                        provider,
+                        KNOWN_PROVIDERS.join(", ")
+                    ),
+                });
+            }
+        }
+
+        if let Err(message) = parse_timezone(&self.timezone) {
+            issues.push(ConfigIssue {
+                setting: "timezone",
+                message,
+            });
+        }
+
+        if let Err(message) = self.date_fmt() {
+            issues.push(ConfigIssue {
+                setting: "date_format",
+                message,
+            });
+        }
+
+        if let Err(message) = self.default_book_status() {
+            issues.push(ConfigIssue {
+                setting: "default_book_status",
+                message,
+            });
+        }
+
+        issues
+    }
+
+    /// Returns the effective settings (defaults merged with the user's config file)
+    /// as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("settings always serialize")
+    }
+
+    /// Resolves the `timezone` setting. Returns an error for invalid values.
+    pub fn tz(&self) -> Result<Timezone, String> {
+        parse_timezone(&self.timezone)
+    }
+
+    /// Returns the `date_format` setting, or an error if it is not a valid
+    /// strftime-style format.
+    pub fn date_fmt(&self) -> Result<&str, String> {
+        if StrftimeItems::new(&self.date_format).any(|item| matches!(item, Item::Error)) {
+            return Err(format!("Invalid date format '{}'", self.date_format));
+        }
+        Ok(&self.date_format)
+    }
+
+    /// Resolves the `default_book_status` setting, if set. Returns an error for
+    /// unknown statuses.
+    pub fn default_book_status(&self) -> Result<Option<InitialStatus>, String> {
+        self.default_book_status
+            .as_deref()
+            .map(parse_initial_status)
+            .transpose()
+    }
+
+    /// Returns the status symbols to use: the ASCII or Unicode set, with
+    /// `finished_symbol` and `reading_symbol` overriding individual symbols.
+    pub fn status_symbols(&self) -> StatusSymbols {
+        let mut symbols = if self.ascii_symbols {
+            StatusSymbols::ascii()
+        } else {
+            StatusSymbols::default()
+        };
+        if let Some(finished) = self.finished_symbol.as_deref().filter(|s| !s.is_empty()) {
+            symbols.finished = finished.to_string();
+        }
+        if let Some(reading) = self.reading_symbol.as_deref().filter(|s| !s.is_empty()) {
+            symbols.reading = reading.to_string();
+        }
+        symbols
+    }
+
+    /// Creates an ISBN lookup client using the configured provider order.
+    pub fn http_client(&self) -> Result<HttpClient, String> {
+        Ok(HttpClient::with_providers(&self.providers)?
+            .with_timeout(std::time::Duration::from_secs(self.lookup_timeout_secs)))
+    }
+
     pub fn save(&self) -> Result<(), ConfigError> {
         let config_path = get_config_path()?;
 
-        // Create a map of values to save
-        let mut config_map = serde_yaml::Mapping::new();
+        // Keep any other keys the user has set, only updating the storage file
+        let mut config_map = fs::read_to_string(&config_path)
+            .ok()
+            .and_then(|contents| serde_yaml::from_str::<serde_yaml::Mapping>(&contents).ok())
+            .unwrap_or_default();
         config_map.insert("storage_file".into(), self.storage_file.clone().into());
 
         // Write to file
@@ -0,0 +1,93 @@
+use std::path::Path;
+
+/// Where a book's cover image can be loaded from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverSource<'a> {
+    /// An image file on disk
+    Path(&'a str),
+    /// An image to download
+    Url(&'a str),
+}
+
+/// Picks the cover to show: a local file when it exists, otherwise an
+/// `http(s)` URL. Returns `None` when neither is usable.
+pub fn select_cover_source<'a>(
+    cover_path: Option<&'a str>,
+    cover_url: Option<&'a str>,
+) -> Option<CoverSource<'a>> {
+    if let Some(path) = cover_path
+        .map(str::trim)
+        .filter(|p| !p.is_empty() && Path::new(p).is_file())
+    {
+        return Some(CoverSource::Path(path));
+    }
+    cover_url
+        .map(str::trim)
+        .filter(|u| u.starts_with("https://") || u.starts_with("http://"))
+        .map(CoverSource::Url)
+}
+
+/// Returns true if the terminal, identified by the `TERM_PROGRAM` and
+/// `LC_TERMINAL` environment variables, understands the iTerm2 inline image
+/// protocol (iTerm2 and WezTerm do).
+pub fn terminal_supports_inline_images(
+    term_program: Option<&str>,
+    lc_terminal: Option<&str>,
+) -> bool {
+    matches!(term_program, Some("iTerm.app") | Some("WezTerm")) || lc_terminal == Some("iTerm2")
+}
+
+/// Returns true if cover art was compiled in (the `cover-art` feature).
+pub fn is_enabled() -> bool {
+    cfg!(feature = "cover-art")
+}
+
+/// Builds the iTerm2 escape sequence that shows `image` inline, `width` cells wide.
+#[cfg(feature = "cover-art")]
+pub fn inline_image_sequence(image: &[u8], width: u32) -> String {
+    use base64::Engine;
+
+    format!(
+        "\x1b]1337;File=inline=1;size={};width={};preserveAspectRatio=1:{}\x07",
+        image.len(),
+        width,
+        base64::engine::general_purpose::STANDARD.encode(image)
+    )
+}
+
+/// Shows the cover inline when the terminal supports it; does nothing otherwise.
+#[cfg(feature = "cover-art")]
+pub async fn render_cover(source: CoverSource<'_>) -> Result<(), String> {
+    let term_program = std::env::var("TERM_PROGRAM").ok();
+    let lc_terminal = std::env::var("LC_TERMINAL").ok();
+    if !terminal_supports_inline_images(term_program.as_deref(), lc_terminal.as_deref()) {
+        return Ok(());
+    }
+
+    let image = match source {
+        CoverSource::Path(path) => {
+            std::fs::read(path).map_err(|e| format!("Failed to read cover {}: {}", path, e))?
+        }
+        CoverSource::Url(url) => {
+            let response = crate::lookup::providers::create_http_client()
+                .get(url)
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map_err(|e| format!("Failed to download cover: {}", e))?;
+            response
+                .bytes()
+                .await
+                .map_err(|e| format!("Failed to download cover: {}", e))?
+                .to_vec()
+        }
+    };
+    println!("{}", inline_image_sequence(&image, 20));
+    Ok(())
+}
+
+/// Without the `cover-art` feature, covers are never shown.
+#[cfg(not(feature = "cover-art"))]
+pub async fn render_cover(_source: CoverSource<'_>) -> Result<(), String> {
+    Ok(())
+}
@@ -0,0 +1,282 @@
+use crate::storage::{Book, ReadingEvent, Storage};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// A single reading log entry in the shape used by Open Library's reading log.
+#[derive(Debug, Serialize)]
+struct OpenLibraryLogEntry<'a> {
+    isbn: &'a str,
+    title: &'a str,
+    authors: Vec<&'a str>,
+    status: &'static str,
+}
+
+/// Maps a book's reading history to an Open Library shelf.
+///
+/// The most recent status-relevant event wins: `Started` is "currently-reading",
+/// `Finished` is "already-read" and `WantToRead` is "want-to-read". `Update` and
+/// `Bought` are skipped; books with no such event, or whose latest one is
+/// `UnmarkedAsWantToRead` or `DidNotFinish`, are on no shelf.
+pub fn openlibrary_shelf(storage: &Storage, book_id: &str) -> Option<&'static str> {
+    let mut readings: Vec<_> = storage
+        .readings
+        .values()
+        .filter(|r| r.book_id == book_id)
+        .collect();
+    readings.sort_by(|a, b| b.cmp_chronological(a));
+
+    for reading in readings {
+        match reading.event {
+            ReadingEvent::Started => return Some("currently-reading"),
+            ReadingEvent::Finished => return Some("already-read"),
+            ReadingEvent::WantToRead => return Some("want-to-read"),
+            ReadingEvent::UnmarkedAsWantToRead | ReadingEvent::DidNotFinish => return None,
+            ReadingEvent::Update | ReadingEvent::Bought => continue,
+        }
+    }
+    None
+}
+
+/// Exports the library as a JSON array of Open Library reading log records
+/// (`isbn`, `title`, `authors`, `status`). Books that are on no shelf are left out.
+pub fn export_openlibrary_json(storage: &Storage) -> String {
+    let mut books: Vec<&Book> = storage.books.values().collect();
+    books.sort_by(|a, b| a.title.cmp(&b.title).then_with(|| a.isbn.cmp(&b.isbn)));
+
+    let entries: Vec<OpenLibraryLogEntry> = books
+        .into_iter()
+        .filter_map(|book| {
+            let status = openlibrary_shelf(storage, &book.id)?;
+            let author = storage.author_name_for_book(book);
+            Some(OpenLibraryLogEntry {
+                isbn: &book.isbn,
+                title: &book.title,
+                authors: if author.is_empty() {
+                    vec![]
+                } else {
+                    vec![author]
+                },
+                status,
+            })
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&entries).expect("reading log entries always serialize")
+}
+
+/// Columns written by the CSV export, in order.
+pub const CSV_HEADER: &str =
+    "title,subtitle,author,category,isbn,total_pages,added_on,series,position_in_series,status";
+
+/// Quotes a CSV field when it contains a comma, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Returns the book's status as written in exports ("finished", "reading",
+/// "want to read"), or "" when it is on no shelf.
+fn status_label(storage: &Storage, book: &Book) -> &'static str {
+    match openlibrary_shelf(storage, &book.id) {
+        Some("already-read") => "finished",
+        Some("currently-reading") => "reading",
+        Some("want-to-read") => "want to read",
+        _ => "",
+    }
+}
+
+/// Category and series names of a book, or "" when unknown.
+fn category_and_series<'a>(storage: &'a Storage, book: &Book) -> (&'a str, &'a str) {
+    let category = storage
+        .categories
+        .get(&book.category_id)
+        .map(|c| c.name.as_str())
+        .unwrap_or("");
+    let series = book
+        .series_id
+        .as_deref()
+        .and_then(|id| storage.get_series(id))
+        .map(|s| s.name.as_str())
+        .unwrap_or("");
+    (category, series)
+}
+
+/// Formats one book as a CSV row (without a trailing newline).
+fn csv_row(storage: &Storage, book: &Book, date_format: &str) -> String {
+    let (category, series) = category_and_series(storage, book);
+    let status = status_label(storage, book);
+
+    [
+        book.title.as_str(),
+        book.subtitle.as_deref().unwrap_or(""),
+        storage.author_name_for_book(book),
+        category,
+        book.isbn.as_str(),
+        &book.total_pages.to_string(),
+        &book.added_on.format(date_format).to_string(),
+        series,
+        book.position_in_series.as_deref().unwrap_or(""),
+        status,
+    ]
+    .iter()
+    .map(|field| csv_field(field))
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+/// Returns the books to export, oldest added first, limited to those added at or
+/// after `since` when given.
+fn csv_books(storage: &Storage, since: Option<DateTime<Utc>>) -> Vec<&Book> {
+    let mut books: Vec<&Book> = storage
+        .books
+        .values()
+        .filter(|book| since.is_none_or(|since| book.added_on >= since))
+        .collect();
+    books.sort_by(|a, b| {
+        a.added_on
+            .cmp(&b.added_on)
+            .then_with(|| a.title.cmp(&b.title))
+    });
+    books
+}
+
+/// Exports books as CSV, oldest added first.
+///
+/// When `since` is given, only books added at or after it are included.
+/// The header line is written only when `include_header` is set, so rows can be
+/// appended to an earlier export. Dates are written in `date_format`.
+pub fn export_csv(
+    storage: &Storage,
+    since: Option<DateTime<Utc>>,
+    include_header: bool,
+    date_format: &str,
+) -> String {
+    let mut out = String::new();
+    if include_header {
+        out.push_str(CSV_HEADER);
+        out.push('\n');
+    }
+    for book in csv_books(storage, since) {
+        out.push_str(&csv_row(storage, book, date_format));
+        out.push('\n');
+    }
+    out
+}
+
+/// Writes a CSV export to `path` and returns the number of book rows written.
+///
+/// With `append`, rows are added to the end of an existing, non-empty file and the
+/// header is not repeated; otherwise the file is replaced.
+pub fn write_csv_export(
+    storage: &Storage,
+    path: &Path,
+    since: Option<DateTime<Utc>>,
+    append: bool,
+    date_format: &str,
+) -> Result<usize, String> {
+    let appending = append && path.metadata().is_ok_and(|m| m.len() > 0);
+    let rows = csv_books(storage, since).len();
+    let csv = export_csv(storage, since, !appending, date_format);
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(appending)
+        .truncate(!appending)
+        .open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    file.write_all(csv.as_bytes())
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(rows)
+}
+
+/// Columns written by the review CSV export, in order.
+pub const REVIEWS_CSV_HEADER: &str = "title,author,date,rating,review";
+
+/// Exports all reviews as CSV, oldest first, with a header line and dates in
+/// `date_format`.
+///
+/// Review text is kept as-is, so multi-line reviews become a single quoted field
+/// with the line breaks inside. Reviews of books that no longer exist are exported
+/// with an empty title and author.
+pub fn export_reviews_csv(storage: &Storage, date_format: &str) -> String {
+    let mut reviews: Vec<_> = storage.reviews.values().collect();
+    reviews.sort_by(|a, b| {
+        a.created_on
+            .cmp(&b.created_on)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let mut out = String::from(REVIEWS_CSV_HEADER);
+    out.push('\n');
+    for review in reviews {
+        let book = storage.get_book(&review.book_id);
+        let rating = review.rating.map(|r| r.to_string()).unwrap_or_default();
+        let row = [
+            book.map(|b| b.title.as_str()).unwrap_or(""),
+            book.map(|b| storage.author_name_for_book(b)).unwrap_or(""),
+            &review.created_on.format(date_format).to_string(),
+            &rating,
+            review.text.as_str(),
+        ]
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",");
+        out.push_str(&row);
+        out.push('\n');
+    }
+    out
+}
+
+/// One book as written by the NDJSON export.
+#[derive(Debug, Serialize)]
+struct NdjsonRecord<'a> {
+    id: &'a str,
+    title: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subtitle: Option<&'a str>,
+    author: &'a str,
+    category: &'a str,
+    isbn: &'a str,
+    total_pages: i32,
+    added_on: DateTime<Utc>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    series: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position_in_series: Option<&'a str>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    status: &'static str,
+}
+
+/// Writes every book as one JSON object per line (NDJSON), oldest added first.
+///
+/// Records are written to `w` one at a time, so the export never holds more than
+/// a single line in memory. Wrap `w` in a `BufWriter` for files and stdout.
+pub fn export_ndjson_to_writer<W: Write>(storage: &Storage, mut w: W) -> io::Result<()> {
+    for book in csv_books(storage, None) {
+        let (category, series) = category_and_series(storage, book);
+        let record = NdjsonRecord {
+            id: &book.id,
+            title: &book.title,
+            subtitle: book.subtitle.as_deref(),
+            author: storage.author_name_for_book(book),
+            category,
+            isbn: &book.isbn,
+            total_pages: book.total_pages,
+            added_on: book.added_on,
+            series,
+            position_in_series: book.position_in_series.as_deref(),
+            status: status_label(storage, book),
+        };
+        serde_json::to_writer(&mut w, &record)?;
+        w.write_all(b"\n")?;
+    }
+    w.flush()
+}
@@ -1,4 +1,7 @@
-use chrono::{DateTime, Datelike, Utc};
+use crate::storage::Storage;
+use crate::table::{print_table, Alignment};
+use crate::time::Timezone;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 
 /// Generates motivational text about the reading pace needed to reach a yearly goal.
 ///
@@ -83,11 +86,178 @@ pub fn motivational_pace_text(
     }
 }
 
+/// Formats the one-line goal footer shown by the default (no-command) run, e.g.
+/// "Reading goal 2026: 12/24 books — That's about 2 books per month — right on track!".
+///
+/// Returns `None` when no goal is set for the current year (local to `tz`).
+pub fn format_goal_footer(storage: &Storage, tz: &Timezone, now: DateTime<Utc>) -> Option<String> {
+    let year = tz.year_of(&now);
+    let target = storage.get_goal(year)?;
+    let finished = storage.get_books_finished_in_year(year, tz).len() as u32;
+
+    let progress = format!("Reading goal {}: {}/{} books", year, finished, target);
+    Some(match motivational_pace_text(finished, target, year, now) {
+        Some(pace) => format!("{} \u{2014} {}", progress, pace),
+        None => progress,
+    })
+}
+
+/// Width, in characters, of the goal progress bar.
+pub const PROGRESS_BAR_WIDTH: usize = 20;
+
+/// Renders a progress bar of `finished` out of `target` using Unicode block
+/// characters, `width` characters wide. The bar is full once the goal is reached,
+/// and for a target of 0.
+pub fn render_progress_bar(finished: u32, target: u32, width: usize) -> String {
+    let filled = if target > 0 {
+        ((finished as f64 / target as f64) * width as f64)
+            .round()
+            .min(width as f64) as usize
+    } else {
+        width
+    };
+    "\u{2588}".repeat(filled) + &"\u{2591}".repeat(width - filled)
+}
+
+/// Builds the rows of the goal history table from `(year, target, finished)`
+/// entries (see `Storage::goal_vs_actual`): year, goal, finished count, a
+/// hit/miss marker and, when there is one, the pace text for that year.
+///
+/// Goals not yet reached in the current or a future year are "in progress"
+/// rather than missed. Includes the header row.
+pub fn goal_history_rows(history: &[(i32, u32, usize)], now: DateTime<Utc>) -> Vec<Vec<String>> {
+    let mut rows = vec![vec![
+        "Year".to_string(),
+        "Goal".to_string(),
+        "Finished".to_string(),
+        "Result".to_string(),
+        "".to_string(),
+    ]];
+    for &(year, target, finished) in history {
+        let finished_u32 = u32::try_from(finished).unwrap_or(u32::MAX);
+        let result = if finished_u32 >= target {
+            "\u{2713} hit"
+        } else if year >= now.year() {
+            "in progress"
+        } else {
+            "\u{2717} missed"
+        };
+        rows.push(vec![
+            year.to_string(),
+            target.to_string(),
+            finished.to_string(),
+            result.to_string(),
+            motivational_pace_text(finished_u32, target, year, now).unwrap_or_default(),
+        ]);
+    }
+    rows
+}
+
+/// Prints the goal history table, or a hint when no goals are set.
+pub fn show_goal_history(storage: &Storage, tz: &Timezone, now: DateTime<Utc>) {
+    let history = storage.goal_vs_actual(tz);
+    if history.is_empty() {
+        println!("No reading goals set. Use `set-goal` to set one.");
+        return;
+    }
+    let alignments = [
+        Alignment::Left,  // Year
+        Alignment::Right, // Goal
+        Alignment::Right, // Finished
+        Alignment::Left,  // Result
+        Alignment::Left,  // Pace text
+    ];
+    print_table(&goal_history_rows(&history, now), &alignments);
+}
+
+/// Formats the per-category challenge from `(category, target, finished)`
+/// entries (see `Storage::challenge_status`), one line per category, e.g.
+/// "  \u{2713} Fantasy: 3/3 books" or "  \u{2717} Poetry: 1/3 books".
+pub fn format_challenge_status(status: &[(String, u32, usize)]) -> Vec<String> {
+    status
+        .iter()
+        .map(|(category, target, finished)| {
+            let marker = if *finished >= *target as usize {
+                "\u{2713}"
+            } else {
+                "\u{2717}"
+            };
+            format!(
+                "  {} {}: {}/{} {}",
+                marker,
+                category,
+                finished,
+                target,
+                pluralize_book(*target)
+            )
+        })
+        .collect()
+}
+
 /// Returns "book" or "books" depending on the count.
-fn pluralize_book(count: u32) -> &'static str {
+pub fn pluralize_book(count: u32) -> &'static str {
     if count == 1 {
         "book"
     } else {
         "books"
     }
 }
+
+/// Formats how many pages a goal of `target` books amounts to at an average of
+/// `average_pages` per book, e.g. "That's about 7200 pages at your average of 300
+/// pages per book.". Without an average there is nothing to estimate from.
+pub fn format_implied_pages(target: u32, average_pages: Option<f64>) -> String {
+    match average_pages {
+        Some(average) => format!(
+            "That's about {:.0} pages at your average of {:.0} pages per book.",
+            f64::from(target) * average,
+            average
+        ),
+        None => {
+            "Finish a book with a page count to estimate how many pages your goal is.".to_string()
+        }
+    }
+}
+
+/// Returns the day of `year` that falls on the same calendar date as `date`.
+///
+/// Feb 29 maps to Feb 28 in non-leap years, so "this point in the year" means
+/// the same month and day rather than the same ordinal.
+pub fn same_day_of_year(date: NaiveDate, year: i32) -> u32 {
+    NaiveDate::from_ymd_opt(year, date.month(), date.day())
+        .or_else(|| NaiveDate::from_ymd_opt(year, date.month(), date.day() - 1))
+        .expect("Feb 28 exists in every year")
+        .ordinal()
+}
+
+/// Formats this year's finished count against earlier years at the same point
+/// in the year, e.g. "So far in 2025: 12 books — ahead of 2024 (9), behind 2023 (14)".
+/// `previous` holds `(year, count)` pairs, newest first. Returns `None` when there
+/// is nothing to compare with.
+pub fn format_pace_comparison(
+    year: i32,
+    count: usize,
+    previous: &[(i32, usize)],
+) -> Option<String> {
+    if previous.is_empty() {
+        return None;
+    }
+    let comparisons: Vec<String> = previous
+        .iter()
+        .map(|(prev_year, prev_count)| {
+            let relation = match count.cmp(prev_count) {
+                std::cmp::Ordering::Greater => "ahead of",
+                std::cmp::Ordering::Less => "behind",
+                std::cmp::Ordering::Equal => "level with",
+            };
+            format!("{} {} ({})", relation, prev_year, prev_count)
+        })
+        .collect();
+    Some(format!(
+        "So far in {}: {} {} \u{2014} {}",
+        year,
+        count,
+        pluralize_book(count as u32),
+        comparisons.join(", ")
+    ))
+}
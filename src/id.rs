@@ -0,0 +1,44 @@
+use std::cell::Cell;
+use uuid::Uuid;
+
+/// A source of IDs for new records, so tests and imports can get predictable
+/// IDs instead of random UUIDs.
+pub trait IdGen {
+    fn new_id(&self) -> String;
+}
+
+/// The default generator, producing random v4 UUIDs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidGen;
+
+impl IdGen for UuidGen {
+    fn new_id(&self) -> String {
+        Uuid::new_v4().to_string()
+    }
+}
+
+/// A generator that counts up from 1, producing `<prefix>1`, `<prefix>2`, and
+/// so on. Mostly useful in tests and for reproducible imports.
+#[derive(Debug, Default)]
+pub struct SequentialIdGen {
+    prefix: String,
+    next: Cell<u64>,
+}
+
+impl SequentialIdGen {
+    /// Creates a generator whose IDs start with `prefix`.
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            next: Cell::new(1),
+        }
+    }
+}
+
+impl IdGen for SequentialIdGen {
+    fn new_id(&self) -> String {
+        let n = self.next.get().max(1);
+        self.next.set(n + 1);
+        format!("{}{}", self.prefix, n)
+    }
+}
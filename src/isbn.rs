@@ -0,0 +1,157 @@
+/// Returns the ISBN-13 check digit for the first 12 digits.
+fn isbn13_check_digit(digits: &[u32]) -> u32 {
+    let sum: u32 = digits
+        .iter()
+        .take(12)
+        .enumerate()
+        .map(|(i, d)| if i % 2 == 0 { *d } else { d * 3 })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+/// Parses an ISBN into its digits, ignoring hyphens and whitespace.
+/// Returns `None` if any other character is present.
+fn isbn_digits(isbn: &str) -> Option<Vec<u32>> {
+    isbn.chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .map(|c| c.to_digit(10))
+        .collect()
+}
+
+fn digits_to_string(digits: &[u32]) -> String {
+    digits
+        .iter()
+        .map(|d| char::from_digit(*d, 10).expect("digits are always 0-9"))
+        .collect()
+}
+
+/// Returns true if `isbn` is a 13-digit ISBN with a correct check digit.
+/// Hyphens and whitespace are ignored.
+pub fn is_valid_isbn13(isbn: &str) -> bool {
+    match isbn_digits(isbn) {
+        Some(digits) if digits.len() == 13 => isbn13_check_digit(&digits) == digits[12],
+        _ => false,
+    }
+}
+
+/// Returns the ISBN-10 check character for the first 9 digits ('X' stands for 10).
+fn isbn10_check_char(digits: &[u32]) -> char {
+    let sum: u32 = digits
+        .iter()
+        .take(9)
+        .enumerate()
+        .map(|(i, d)| (10 - i as u32) * d)
+        .sum();
+    match (11 - sum % 11) % 11 {
+        10 => 'X',
+        d => char::from_digit(d, 10).expect("check digit is 0-9"),
+    }
+}
+
+/// Returns true if `isbn` is a 10-character ISBN with a correct check
+/// character (a digit or 'X'). Hyphens and whitespace are ignored.
+pub fn is_valid_isbn10(isbn: &str) -> bool {
+    let cleaned: Vec<char> = isbn
+        .chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .collect();
+    if cleaned.len() != 10 {
+        return false;
+    }
+    match isbn_digits(&cleaned[..9].iter().collect::<String>()) {
+        Some(digits) => isbn10_check_char(&digits) == cleaned[9].to_ascii_uppercase(),
+        None => false,
+    }
+}
+
+/// Normalizes a valid ISBN-10 or ISBN-13 to the bare 13-digit form.
+/// Returns `None` for anything that isn't a valid ISBN.
+pub fn to_isbn13(isbn: &str) -> Option<String> {
+    if is_valid_isbn13(isbn) {
+        return isbn_digits(isbn).map(|digits| digits_to_string(&digits));
+    }
+    if !is_valid_isbn10(isbn) {
+        return None;
+    }
+    let cleaned: String = isbn
+        .chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .take(9)
+        .collect();
+    let mut digits = vec![9, 7, 8];
+    digits.extend(isbn_digits(&cleaned)?);
+    digits.push(isbn13_check_digit(&digits));
+    Some(digits_to_string(&digits))
+}
+
+/// Converts a valid ISBN to its ISBN-10 form. Only 978-prefixed ISBN-13s
+/// have one; returns `None` for 979 ISBNs and invalid input.
+pub fn to_isbn10(isbn: &str) -> Option<String> {
+    let isbn13 = to_isbn13(isbn)?;
+    let body = isbn13.strip_prefix("978")?;
+    let digits = isbn_digits(&body[..9])?;
+    Some(format!("{}{}", &body[..9], isbn10_check_char(&digits)))
+}
+
+/// Checks an ISBN for strict mode: it must be present and be a valid ISBN-10
+/// or ISBN-13. Returns an error describing the problem otherwise.
+pub fn validate_strict_isbn(isbn: &str) -> Result<(), String> {
+    if isbn.trim().is_empty() {
+        return Err("An ISBN is required (strict_isbn is enabled)".to_string());
+    }
+    if is_valid_isbn13(isbn) || is_valid_isbn10(isbn) {
+        Ok(())
+    } else {
+        Err(format!(
+            "'{}' is not a valid ISBN-10 or ISBN-13 (strict_isbn is enabled)",
+            isbn.trim()
+        ))
+    }
+}
+
+/// Suggests likely intended ISBNs for a mistyped ISBN-13.
+///
+/// When the check digit doesn't match, returns the ISBN with the check digit
+/// corrected, followed by every ISBN obtained by swapping two adjacent digits
+/// that has a valid check digit. Returns an empty list for valid ISBNs and for
+/// input that isn't 13 digits.
+pub fn suggest_isbn_corrections(isbn: &str) -> Vec<String> {
+    let digits = match isbn_digits(isbn) {
+        Some(digits) if digits.len() == 13 => digits,
+        _ => return Vec::new(),
+    };
+    if isbn13_check_digit(&digits) == digits[12] {
+        return Vec::new();
+    }
+
+    let mut corrected = digits.clone();
+    corrected[12] = isbn13_check_digit(&digits);
+    let mut suggestions = vec![digits_to_string(&corrected)];
+
+    for i in 0..12 {
+        if digits[i] == digits[i + 1] {
+            continue;
+        }
+        let mut swapped = digits.clone();
+        swapped.swap(i, i + 1);
+        if isbn13_check_digit(&swapped) == swapped[12] {
+            let candidate = digits_to_string(&swapped);
+            if !suggestions.contains(&candidate) {
+                suggestions.push(candidate);
+            }
+        }
+    }
+
+    suggestions
+}
+
+/// Formats ISBN suggestions as a hint for a failed lookup, e.g.
+/// "Did you mean 9780142410349?". Returns `None` when there are no suggestions.
+pub fn format_isbn_suggestions(isbn: &str) -> Option<String> {
+    let suggestions = suggest_isbn_corrections(isbn);
+    if suggestions.is_empty() {
+        None
+    } else {
+        Some(format!("Did you mean {}?", suggestions.join(" or ")))
+    }
+}
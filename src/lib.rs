@@ -1,13 +1,25 @@
 pub mod author;
+pub mod backend;
 pub mod book;
+pub mod bulk;
 pub mod category;
+pub mod clock;
 pub mod config;
+pub mod cover;
+pub mod export;
 pub mod goal;
+pub mod id;
+pub mod isbn;
+pub mod merge;
+pub mod quote;
 pub mod reading;
+pub mod report;
 pub mod review;
 pub mod series;
 pub mod storage;
+pub mod summary;
 pub mod table;
+pub mod time;
 pub mod lookup {
     pub mod book_lookup_dto;
     pub mod http_client;
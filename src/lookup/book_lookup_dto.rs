@@ -10,13 +10,31 @@ pub struct AuthorDTO {
 #[derive(Debug, Clone)]
 pub struct BookLookupDTO {
     pub title: String,
+    /// Subtitle, when the provider keeps it separate from the title
+    pub subtitle: Option<String>,
     pub authors: Vec<AuthorDTO>,
     pub description: Option<String>,
     pub isbn: String,
     pub publish_date: Option<String>,
+    /// Publisher of the looked-up edition (if known)
+    pub publisher: Option<String>,
     pub cover_url: Option<String>,
+    /// Number of pages in the looked-up edition (if known)
+    pub total_pages: Option<i32>,
     /// Series name from ISBN lookup (e.g. "Harry Potter")
     pub series_name: Option<String>,
     /// Position within the series (e.g. "1", "2.5" for novellas)
     pub series_position: Option<String>,
+    /// Name of the provider that returned this result (set by `ProviderManager`)
+    pub source: Option<String>,
+}
+
+/// Extracts the year from a publish date such as "2001", "2001-05-03" or
+/// "January 1, 2001": the first run of exactly four digits. Returns `None` when
+/// there is no such run.
+pub fn parse_publish_year(publish_date: &str) -> Option<i32> {
+    publish_date
+        .split(|c: char| !c.is_ascii_digit())
+        .find(|digits| digits.len() == 4)
+        .and_then(|digits| digits.parse().ok())
 }
@@ -1,9 +1,21 @@
 use crate::lookup::book_lookup_dto::BookLookupDTO;
-use crate::lookup::providers::ProviderManager;
+use crate::lookup::providers::{BookProvider, ProviderManager};
 use std::error::Error;
+use std::time::Duration;
+
+/// Error message returned by lookups when network access is disabled.
+pub const NETWORK_DISABLED: &str = "Network access is disabled (--no-network)";
+
+/// Error message returned by lookups that take longer than the client's timeout.
+pub const LOOKUP_TIMED_OUT: &str = "Lookup timed out";
+
+/// How long a lookup may take before giving up, unless configured otherwise.
+pub const DEFAULT_LOOKUP_TIMEOUT: Duration = Duration::from_secs(15);
 
 pub struct HttpClient {
     provider_manager: ProviderManager,
+    network_enabled: bool,
+    timeout: Duration,
 }
 
 impl Default for HttpClient {
@@ -16,13 +28,59 @@ impl HttpClient {
     pub fn new() -> Self {
         Self {
             provider_manager: ProviderManager::new(),
+            network_enabled: true,
+            timeout: DEFAULT_LOOKUP_TIMEOUT,
         }
     }
 
+    /// Creates a client that queries the given providers in order.
+    pub fn from_providers(providers: Vec<Box<dyn BookProvider>>) -> Self {
+        Self {
+            provider_manager: ProviderManager::from_providers(providers),
+            network_enabled: true,
+            timeout: DEFAULT_LOOKUP_TIMEOUT,
+        }
+    }
+
+    /// Creates a client that queries the named providers in the given order
+    /// (see `KNOWN_PROVIDERS`).
+    pub fn with_providers(names: &[String]) -> Result<Self, String> {
+        Ok(Self {
+            provider_manager: ProviderManager::with_order(names)?,
+            network_enabled: true,
+            timeout: DEFAULT_LOOKUP_TIMEOUT,
+        })
+    }
+
+    /// Enables or disables network access. When disabled, lookups fail with
+    /// `NETWORK_DISABLED` without contacting any provider.
+    pub fn with_network(mut self, enabled: bool) -> Self {
+        self.network_enabled = enabled;
+        self
+    }
+
+    pub fn is_network_enabled(&self) -> bool {
+        self.network_enabled
+    }
+
+    /// Sets how long a lookup (across all providers) may take. Slower lookups
+    /// fail with `LOOKUP_TIMED_OUT`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     pub async fn get_book_by_isbn(
         &self,
         isbn: &str,
     ) -> Result<Option<BookLookupDTO>, Box<dyn Error>> {
-        self.provider_manager.get_book_by_isbn(isbn).await
+        if !self.network_enabled {
+            return Err(NETWORK_DISABLED.into());
+        }
+        match tokio::time::timeout(self.timeout, self.provider_manager.get_book_by_isbn(isbn)).await
+        {
+            Ok(result) => result,
+            Err(_) => Err(LOOKUP_TIMED_OUT.into()),
+        }
     }
 }
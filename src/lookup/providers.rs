@@ -33,19 +33,48 @@ impl Default for ProviderManager {
     }
 }
 
+/// Provider names accepted in the `providers` setting, in the default lookup order.
+pub const KNOWN_PROVIDERS: [&str; 2] = ["openlibrary", "bibsok"];
+
+/// Creates the provider with the given (case-insensitive) name, if it is known.
+fn provider_by_name(name: &str) -> Option<Box<dyn BookProvider>> {
+    match name.to_lowercase().as_str() {
+        "openlibrary" => Some(Box::new(OpenLibraryProvider {
+            client: create_http_client(),
+        })),
+        "bibsok" => Some(Box::new(BibsokProvider {
+            client: create_http_client(),
+        })),
+        _ => None,
+    }
+}
+
 impl ProviderManager {
     pub fn new() -> Self {
-        let providers: Vec<Box<dyn BookProvider>> = vec![
-            Box::new(OpenLibraryProvider {
-                client: create_http_client(),
-            }),
-            Box::new(BibsokProvider {
-                client: create_http_client(),
-            }),
-        ];
+        let providers = KNOWN_PROVIDERS
+            .iter()
+            .filter_map(|name| provider_by_name(name))
+            .collect();
         Self { providers }
     }
 
+    /// Creates a manager that queries the given providers in order.
+    pub fn from_providers(providers: Vec<Box<dyn BookProvider>>) -> Self {
+        Self { providers }
+    }
+
+    /// Creates a manager that queries the named providers in the given order.
+    /// Returns an error naming the first unknown provider.
+    pub fn with_order(names: &[String]) -> Result<Self, String> {
+        let providers = names
+            .iter()
+            .map(|name| {
+                provider_by_name(name).ok_or_else(|| format!("Unknown provider '{}'", name))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { providers })
+    }
+
     pub async fn get_book_by_isbn(
         &self,
         isbn: &str,
@@ -54,7 +83,10 @@ impl ProviderManager {
 
         for provider in &self.providers {
             match provider.get_book_by_isbn(isbn).await {
-                Ok(Some(book)) => return Ok(Some(book)),
+                Ok(Some(mut book)) => {
+                    book.source = Some(provider.name().to_string());
+                    return Ok(Some(book));
+                }
                 Ok(None) => continue,
                 Err(e) => errors.push((provider.name(), e)),
             }
@@ -90,13 +90,17 @@ impl BibsokProvider {
 
         Ok(BookLookupDTO {
             title,
+            subtitle: None,
             authors,
             description: None, // Bibsok doesn't provide descriptions in the search results
             isbn: String::new(), // Will be set by the caller
             publish_date: year,
+            publisher: None,
             cover_url,
+            total_pages: None, // Bibsok doesn't provide page counts in search results
             series_name: None, // Bibsok doesn't provide series info in search results
             series_position: None,
+            source: None,
         })
     }
 }
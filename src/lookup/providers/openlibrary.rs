@@ -33,14 +33,31 @@ pub fn parse_series_string(s: &str) -> (String, Option<String>) {
 /// Edition data from OpenLibrary's ISBN API.
 #[derive(Debug, Serialize, Deserialize)]
 struct OpenLibraryEdition {
+    #[serde(default)]
+    subtitle: Option<String>,
     #[serde(default)]
     series: Option<Vec<String>>,
+    #[serde(default)]
+    number_of_pages: Option<i32>,
+    #[serde(default)]
+    publishers: Option<Vec<String>>,
+}
+
+/// Edition-level details (subtitle, series, page count and publisher) extracted from OpenLibrary's ISBN API.
+#[derive(Debug, Default)]
+struct EditionDetails {
+    subtitle: Option<String>,
+    series: Option<(String, Option<String>)>,
+    number_of_pages: Option<i32>,
+    publisher: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OpenLibraryBook {
     title: String,
     #[serde(default)]
+    subtitle: Option<String>,
+    #[serde(default)]
     authors: Vec<Author>,
     #[serde(default)]
     #[serde(deserialize_with = "deserialize_description")]
@@ -51,13 +68,6 @@ pub struct OpenLibraryBook {
     covers: Option<Vec<i64>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct TextValue {
-    #[serde(rename = "type")]
-    type_: String,
-    value: String,
-}
-
 fn deserialize_description<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -240,32 +250,35 @@ impl OpenLibraryProvider {
         Ok(authors)
     }
 
-    /// Fetches edition data by ISBN to get series information.
-    /// Returns None if the edition is not found or has no series data.
-    async fn fetch_edition_series(
-        &self,
-        isbn: &str,
-    ) -> Result<Option<(String, Option<String>)>, Box<dyn Error>> {
+    /// Fetches edition data by ISBN to get the subtitle, series information, page count and publisher.
+    /// Returns empty details if the edition is not found.
+    async fn fetch_edition_details(&self, isbn: &str) -> Result<EditionDetails, Box<dyn Error>> {
         let url = format!("{}/isbn/{}.json", HOSTNAME, isbn);
         let response = self.client.get(&url).send().await?;
 
         if !response.status().is_success() {
-            return Ok(None);
+            return Ok(EditionDetails::default());
         }
 
         let response_text = response.text().await?;
         let edition: OpenLibraryEdition = serde_json::from_str(&response_text)?;
 
-        if let Some(series_list) = edition.series {
-            if let Some(first_series) = series_list.first() {
-                let (name, position) = parse_series_string(first_series);
-                if !name.is_empty() {
-                    return Ok(Some((name, position)));
-                }
-            }
-        }
-
-        Ok(None)
+        let series = edition
+            .series
+            .as_ref()
+            .and_then(|series_list| series_list.first())
+            .map(|first_series| parse_series_string(first_series))
+            .filter(|(name, _)| !name.is_empty());
+
+        Ok(EditionDetails {
+            subtitle: edition.subtitle.filter(|s| !s.trim().is_empty()),
+            series,
+            number_of_pages: edition.number_of_pages.filter(|pages| *pages > 0),
+            publisher: edition
+                .publishers
+                .and_then(|publishers| publishers.into_iter().next())
+                .filter(|p| !p.trim().is_empty()),
+        })
     }
 
     fn convert_to_dto(
@@ -273,15 +286,19 @@ impl OpenLibraryProvider {
         book: OpenLibraryBook,
         authors: Vec<Author>,
         isbn: &str,
-        series_info: Option<(String, Option<String>)>,
+        edition: EditionDetails,
     ) -> BookLookupDTO {
-        let (series_name, series_position) = match series_info {
+        let (series_name, series_position) = match edition.series {
             Some((name, pos)) => (Some(name), pos),
             None => (None, None),
         };
 
         BookLookupDTO {
             title: book.title,
+            // Prefer the edition's subtitle, which matches the looked-up ISBN
+            subtitle: edition
+                .subtitle
+                .or(book.subtitle.filter(|s| !s.trim().is_empty())),
             authors: authors
                 .into_iter()
                 .map(|a| AuthorDTO {
@@ -295,12 +312,15 @@ impl OpenLibraryProvider {
             description: book.description,
             isbn: isbn.to_string(),
             publish_date: book.first_publish_date,
+            publisher: edition.publisher,
             cover_url: book.covers.and_then(|c| {
                 c.first()
                     .map(|id| format!("https://covers.openlibrary.org/b/id/{}-L.jpg", id))
             }),
+            total_pages: edition.number_of_pages,
             series_name,
             series_position,
+            source: None,
         }
     }
 }
@@ -333,11 +353,11 @@ impl BookProvider for OpenLibraryProvider {
             obj.remove("authors");
         }
 
-        // Fetch edition data for series info (best-effort, don't fail on error)
-        let series_info = self.fetch_edition_series(isbn).await.unwrap_or(None);
+        // Fetch edition data for series info and page count (best-effort, don't fail on error)
+        let edition = self.fetch_edition_details(isbn).await.unwrap_or_default();
 
         // Parse book data and convert to DTO
         let book: OpenLibraryBook = serde_json::from_value(work_response)?;
-        Ok(Some(self.convert_to_dto(book, authors, isbn, series_info)))
+        Ok(Some(self.convert_to_dto(book, authors, isbn, edition)))
     }
 }
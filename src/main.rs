@@ -1,17 +1,32 @@
 use bookmon::{
-    book, config, goal,
-    lookup::http_client,
+    book,
+    clock::{Clock, SystemClock},
+    config, goal,
+    lookup::{
+        book_lookup_dto::{AuthorDTO, BookLookupDTO},
+        http_client::NETWORK_DISABLED,
+    },
     reading, review,
-    storage::{self, Book, BookRepairInput, RepairPrompter, Storage},
+    storage::{self, Book, BookRepairInput, MissingField, RepairMode, RepairPrompter, Storage},
+    table::{TableOutput, TableStyle},
+    time::Timezone,
 };
 use chrono::Datelike;
-use clap::{Parser, Subcommand};
-use inquire::{Select, Text};
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use inquire::{MultiSelect, Select, Text};
+use std::io::Write;
 
 /// Interactive prompter that uses `inquire` for user input during storage repair
 struct InquirePrompter;
 
 impl RepairPrompter for InquirePrompter {
+    fn show_summary(&self, issues: &[MissingField]) {
+        println!("Found {} problem(s) in the storage file:", issues.len());
+        for issue in issues {
+            println!("  - {}", issue.describe());
+        }
+    }
+
     fn prompt_author_name(&self, book_title: &str) -> Result<String, Box<dyn std::error::Error>> {
         println!(
             "Book '{}' references a missing author. Please provide the author name:",
@@ -96,32 +111,256 @@ struct Cli {
     /// Run in interactive mode
     #[arg(short, long, global = true)]
     interactive: bool,
+    /// Include subtitles in book titles
+    #[arg(long, global = true)]
+    full_titles: bool,
+    /// Never look up books online
+    #[arg(long, global = true)]
+    no_network: bool,
+    /// Leave out the goal footer and stale-book reminders when run without a command
+    #[arg(short, long, global = true)]
+    quiet: bool,
+    /// Print book tables as plain aligned columns without borders
+    #[arg(long, global = true, conflicts_with = "markdown")]
+    plain: bool,
+    /// Print book tables as markdown tables
+    #[arg(long, global = true)]
+    markdown: bool,
+    /// Reverse the order of book listings
+    #[arg(long, global = true)]
+    reverse: bool,
+    /// Write book listings to this file instead of the terminal
+    #[arg(long, global = true, value_name = "PATH")]
+    output: Option<String>,
+    /// Redraw the currently-reading view every SECONDS seconds (default 60), reloading the library each time
+    #[arg(long, value_name = "SECONDS", num_args = 0..=1, default_missing_value = "60")]
+    watch: Option<u64>,
+}
+
+impl Cli {
+    /// The table style picked with `--plain` or `--markdown` (boxed otherwise).
+    fn table_style(&self) -> TableStyle {
+        if self.plain {
+            TableStyle::Plain
+        } else if self.markdown {
+            TableStyle::Markdown
+        } else {
+            TableStyle::Boxed
+        }
+    }
+}
+
+/// Formats supported by the `export` command.
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    /// One JSON object per line
+    Ndjson,
+    /// Comma-separated values with a header line
+    Csv,
+}
+
+/// Periods `print-finished --group-by` can group books by.
+#[derive(Clone, Copy, ValueEnum)]
+enum GroupBy {
+    /// One group per year and month, e.g. 2025-04
+    Month,
+}
+
+/// Arguments of `add-book`, also available as `book add`.
+#[derive(Args)]
+struct AddBookArgs {
+    /// Status to record without asking: bought, want-to-read, both or neither
+    #[arg(long)]
+    status: Option<String>,
+    /// Refuse books without a valid ISBN (same as the strict_isbn setting)
+    #[arg(long)]
+    strict_isbn: bool,
+    /// Skip the ISBN and enter the details by hand, for books that have none
+    #[arg(long, conflicts_with = "strict_isbn")]
+    no_isbn: bool,
+}
+
+/// Arguments of `show-book`, also available as `book show`.
+#[derive(Args)]
+struct ShowBookArgs {
+    /// Book to show: ISBN, title, or (a prefix of) its ID
+    book: String,
+}
+
+/// Arguments of `enrich`, also available as `book enrich`.
+#[derive(Args)]
+struct EnrichArgs {
+    /// Book to enrich: ISBN, title, or (a prefix of) its ID
+    book: String,
+}
+
+/// Arguments of `get-isbn`, also available as `book isbn`.
+#[derive(Args)]
+struct GetIsbnArgs {
+    /// The ISBN to look up
+    isbn: String,
+    /// Show the cover inline (needs the cover-art feature and a supporting terminal)
+    #[arg(long)]
+    cover: bool,
+    /// Shorten the description to this many characters
+    #[arg(long, default_value_t = 500)]
+    max_desc: usize,
+    /// Show the full description
+    #[arg(long, conflicts_with = "max_desc")]
+    full: bool,
+}
+
+/// Arguments of `update-progress`, also available as `book progress`.
+#[derive(Args)]
+struct UpdateProgressArgs {
+    /// Book to update: ISBN, title, or (a prefix of) its ID
+    book: String,
+    /// The page you are currently on
+    page: i32,
+}
+
+/// Arguments of `recommend`, also available as `book recommend`.
+#[derive(Args)]
+struct RecommendArgs {
+    /// Book to base the suggestions on: ISBN, title, or (a prefix of) its ID
+    book: String,
+    /// Maximum number of suggestions
+    #[arg(short, long, default_value_t = 5)]
+    limit: usize,
+}
+
+/// Arguments of `print-series`, also available as `series list`.
+#[derive(Args)]
+struct PrintSeriesArgs {
+    /// Show one summary line per series instead of listing its books
+    #[arg(long)]
+    compact: bool,
+    /// List at most this many books per series
+    #[arg(long)]
+    limit: Option<usize>,
+}
+
+/// Arguments of `set-goal`, also available as `goal set`.
+#[derive(Args)]
+struct SetGoalArgs {
+    /// Number of books to read (asks, suggesting one based on previous years, when left out)
+    target: Option<u32>,
+    /// Year to set the goal for (defaults to current year)
+    #[arg(short, long)]
+    year: Option<i32>,
+    /// Set a reading challenge quota for this category instead of the yearly goal
+    #[arg(short, long)]
+    category: Option<String>,
+}
+
+/// Arguments of `print-goal`, also available as `goal show`.
+#[derive(Args)]
+struct PrintGoalArgs {
+    /// Year to check (defaults to current year)
+    #[arg(short, long)]
+    year: Option<i32>,
+    /// Estimate how many pages the goal is from the average length of the books you've finished
+    #[arg(long)]
+    pages_goal_from_finished: bool,
+}
+
+/// Arguments of `challenge-status`, also available as `goal challenge`.
+#[derive(Args)]
+struct ChallengeStatusArgs {
+    /// Year to check (defaults to current year)
+    #[arg(short, long)]
+    year: Option<i32>,
+}
+
+/// Arguments of `print-statistics`, also available as `stats`.
+#[derive(Args)]
+struct PrintStatisticsArgs {
+    /// Show how the page counts of finished books are distributed instead
+    #[arg(long)]
+    page_distribution: bool,
+    /// Also list the books abandoned each year
+    #[arg(long)]
+    include_dnf: bool,
 }
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Add, look up and update single books
+    Book {
+        #[command(subcommand)]
+        command: BookCommand,
+    },
+    /// Show and manage book series
+    Series {
+        #[command(subcommand)]
+        command: SeriesCommand,
+    },
+    /// Set and follow yearly reading goals
+    Goal {
+        #[command(subcommand)]
+        command: GoalCommand,
+    },
+    /// Show reading statistics by year
+    Stats(PrintStatisticsArgs),
     /// Add a new book to the collection
-    AddBook,
+    #[command(hide = true)]
+    AddBook(AddBookArgs),
+    /// List every book in the library
+    Books {
+        /// Only list books without a page count
+        #[arg(long)]
+        missing_pages: bool,
+    },
+    /// Show books that don't belong to any series
+    Standalone,
     /// Show books that have been finished
     PrintFinished {
         /// Filter by series name (case-insensitive substring match)
         #[arg(short, long)]
         series: Option<String>,
+        /// Print only the number of matching books
+        #[arg(long)]
+        count: bool,
+        /// Show at most this many books
+        #[arg(short, long)]
+        limit: Option<usize>,
+        /// Skip this many books before showing any
+        #[arg(long, default_value_t = 0)]
+        offset: usize,
+        /// Sort by finish date, earliest first
+        #[arg(long, conflicts_with = "newest_first")]
+        oldest_first: bool,
+        /// Sort by finish date, latest first
+        #[arg(long)]
+        newest_first: bool,
+        /// Group the books under a header per period, in finish-date order
+        #[arg(long, value_enum)]
+        group_by: Option<GroupBy>,
+        /// Show only books with an average rating of at least this many stars
+        #[arg(long)]
+        min_rating: Option<f64>,
     },
     /// Show books that have not been started yet
     PrintBacklog {
         /// Filter by series name (case-insensitive substring match)
         #[arg(short, long)]
         series: Option<String>,
+        /// Print only the number of matching books
+        #[arg(long)]
+        count: bool,
     },
     /// Show books that are in the want to read list
     PrintWantToRead {
         /// Filter by series name (case-insensitive substring match)
         #[arg(short, long)]
         series: Option<String>,
+        /// Print only the number of matching books
+        #[arg(long)]
+        count: bool,
     },
     /// Show reading statistics by year
-    PrintStatistics,
+    #[command(hide = true)]
+    PrintStatistics(PrintStatisticsArgs),
     /// Change the storage file path
     ChangeStoragePath {
         /// The new path for the storage file
@@ -129,40 +368,274 @@ enum Commands {
     },
     /// Print the path to the config file
     GetConfigPath,
-    /// Get book information by ISBN
-    GetIsbn {
-        /// The ISBN to look up
-        isbn: String,
+    /// Check the config file for problems
+    ValidateConfig,
+    /// Print the effective settings as JSON
+    PrintConfig,
+    /// Copy the storage file to a backup, by default a timestamped file next to it
+    Backup {
+        /// Where to write the backup (defaults to e.g. storage-20250301-091500.json)
+        path: Option<String>,
     },
+    /// Check the library for inconsistencies, such as series with more books than their total
+    Check {
+        /// Print the issues as JSON
+        #[arg(long)]
+        json: bool,
+        /// Re-key records stored under the wrong ID before checking
+        #[arg(long)]
+        fix: bool,
+    },
+    /// Get book information by ISBN
+    #[command(hide = true)]
+    GetIsbn(GetIsbnArgs),
     /// Write a review for a book (opens $EDITOR)
+    #[command(hide = true)]
     ReviewBook,
     /// Show all book reviews
     PrintReviews,
     /// Set a yearly reading goal (number of books to finish)
-    SetGoal {
-        /// Number of books to read
-        target: u32,
-        /// Year to set the goal for (defaults to current year)
-        #[arg(short, long)]
-        year: Option<i32>,
-    },
+    #[command(hide = true)]
+    SetGoal(SetGoalArgs),
     /// Show progress toward your reading goal
-    PrintGoal {
-        /// Year to check (defaults to current year)
+    #[command(hide = true)]
+    PrintGoal(PrintGoalArgs),
+    /// Print a markdown report of the books finished in a year
+    Report {
+        /// Year to report on (defaults to current year)
         #[arg(short, long)]
         year: Option<i32>,
+        /// Print a reading journal with each finished book's reviews instead
+        #[arg(long)]
+        journal: bool,
     },
+    /// Show every year with a reading goal and whether it was reached
+    #[command(hide = true)]
+    GoalHistory,
+    /// Show finished books per category against this year's category quotas
+    #[command(hide = true)]
+    ChallengeStatus(ChallengeStatusArgs),
     /// Show all book series and their books
-    PrintSeries,
+    #[command(hide = true)]
+    PrintSeries(PrintSeriesArgs),
+    /// List all categories with their descriptions
+    Categories,
+    /// Set or clear the description of a category
+    EditCategory,
+    /// Find authors that are probably the same person and merge them
+    DedupeAuthors,
+    /// Move every book in one category to another, e.g. "Sci-Fi" to "Science Fiction"
+    Recategorize {
+        /// Category to move books out of
+        from: String,
+        /// Category to move books into (created if it doesn't exist)
+        to: String,
+        /// Delete the source category once it's empty
+        #[arg(long)]
+        delete_empty: bool,
+    },
+    /// Pick several books you're reading and mark them all as finished
+    MarkFinished,
     /// Delete a series (books are kept but unlinked)
+    #[command(hide = true)]
     DeleteSeries,
     /// Rename an existing series
+    #[command(hide = true)]
     RenameSeries,
+    /// Look up a book again by ISBN and fill in any missing details
+    #[command(hide = true)]
+    Enrich(EnrichArgs),
+    /// Show all details of a book and its reading history
+    #[command(hide = true)]
+    ShowBook(ShowBookArgs),
+    /// Suggest unread books by the same author or in the same category
+    #[command(hide = true)]
+    Recommend(RecommendArgs),
+    /// List the quotes collected from a book, or add one
+    Quotes {
+        /// Book the quotes are from: ISBN, title, or (a prefix of) its ID
+        book: String,
+        /// Add this quote instead of listing them
+        #[arg(long)]
+        add: Option<String>,
+        /// Page the added quote is on
+        #[arg(long, requires = "add")]
+        page: Option<i32>,
+    },
+    /// Suggest what to read next
+    NextUp {
+        /// Maximum number of suggestions
+        #[arg(short, long, default_value_t = 5)]
+        limit: usize,
+    },
+    /// Record the current page of a book without entering interactive mode
+    #[command(hide = true)]
+    UpdateProgress(UpdateProgressArgs),
+    /// Show books you finished on this day in earlier years
+    OnThisDay,
+    /// Remove a single reading event, e.g. one recorded by mistake
+    RemoveEvent {
+        /// ID of the event, or a unique prefix of it (see `recent --ids`)
+        id: String,
+    },
+    /// Show the most recent reading events
+    Recent {
+        /// Number of events to show
+        #[arg(short, long, default_value_t = 10)]
+        limit: usize,
+        /// Skip this many events before showing any
+        #[arg(long, default_value_t = 0)]
+        offset: usize,
+        /// Only show events on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        after: Option<String>,
+        /// Only show events before this date (YYYY-MM-DD)
+        #[arg(long)]
+        before: Option<String>,
+        /// Only show events of this type (e.g. started, finished, want-to-read)
+        #[arg(short, long)]
+        event: Option<String>,
+        /// Prefix each event with its short ID (for `remove-event`)
+        #[arg(long)]
+        ids: bool,
+    },
+    /// Show a summary of the whole library
+    Summary {
+        /// Print stable key=value lines for scripts instead of the pretty format
+        #[arg(long)]
+        porcelain: bool,
+    },
+    /// Export the library as Open Library reading log JSON to stdout
+    ExportOpenLibrary,
+    /// Export every book to stdout, one record at a time
+    Export {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ExportFormat::Ndjson)]
+        format: ExportFormat,
+    },
+    /// Export all reviews as CSV to stdout
+    ExportReviews,
+    /// Export the library as CSV
+    ExportCsv {
+        /// File to write the CSV to
+        path: String,
+        /// Only export books added on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+        /// Append rows to an existing file instead of replacing it (the header is only written once)
+        #[arg(long)]
+        append: bool,
+    },
+    /// Merge another bookmon storage file into this library
+    Merge {
+        /// Path to the storage file to merge in
+        path: String,
+    },
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate completions for
+        shell: clap_complete::Shell,
+    },
+    /// Add books in bulk from a text file of ISBNs (one per line)
+    BulkAdd {
+        /// Path to the file with ISBNs
+        path: String,
+        /// Category for the added books (created if it doesn't exist)
+        #[arg(short, long, default_value = bookmon::bulk::DEFAULT_BULK_CATEGORY)]
+        category: String,
+        /// Skip ISBNs that can't be looked up instead of asking for details
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+/// Subcommands of `book`.
+#[derive(Subcommand)]
+enum BookCommand {
+    /// Add a new book to the collection
+    Add(AddBookArgs),
+    /// Show all details of a book and its reading history
+    Show(ShowBookArgs),
+    /// Look up a book again by ISBN and fill in any missing details
+    Enrich(EnrichArgs),
+    /// Get book information by ISBN
+    Isbn(GetIsbnArgs),
+    /// Record the current page of a book without entering interactive mode
+    Progress(UpdateProgressArgs),
+    /// Suggest unread books by the same author or in the same category
+    Recommend(RecommendArgs),
+    /// Write a review for a book (opens $EDITOR)
+    Review,
+}
+
+/// Subcommands of `series`.
+#[derive(Subcommand)]
+enum SeriesCommand {
+    /// Show all book series and their books
+    List(PrintSeriesArgs),
+    /// Rename an existing series
+    Rename,
+    /// Delete a series (books are kept but unlinked)
+    Delete,
+}
+
+/// Subcommands of `goal`.
+#[derive(Subcommand)]
+enum GoalCommand {
+    /// Set a yearly reading goal (number of books to finish)
+    Set(SetGoalArgs),
+    /// Show progress toward your reading goal
+    Show(PrintGoalArgs),
+    /// Show every year with a reading goal and whether it was reached
+    History,
+    /// Show finished books per category against this year's category quotas
+    Challenge(ChallengeStatusArgs),
+}
+
+impl Commands {
+    /// Turns a grouped command such as `book add` into the top-level command it
+    /// replaces (`add-book`), so both spellings are handled the same way.
+    fn ungroup(self) -> Commands {
+        match self {
+            Commands::Book { command } => match command {
+                BookCommand::Add(args) => Commands::AddBook(args),
+                BookCommand::Show(args) => Commands::ShowBook(args),
+                BookCommand::Enrich(args) => Commands::Enrich(args),
+                BookCommand::Isbn(args) => Commands::GetIsbn(args),
+                BookCommand::Progress(args) => Commands::UpdateProgress(args),
+                BookCommand::Recommend(args) => Commands::Recommend(args),
+                BookCommand::Review => Commands::ReviewBook,
+            },
+            Commands::Series { command } => match command {
+                SeriesCommand::List(args) => Commands::PrintSeries(args),
+                SeriesCommand::Rename => Commands::RenameSeries,
+                SeriesCommand::Delete => Commands::DeleteSeries,
+            },
+            Commands::Goal { command } => match command {
+                GoalCommand::Set(args) => Commands::SetGoal(args),
+                GoalCommand::Show(args) => Commands::PrintGoal(args),
+                GoalCommand::History => Commands::GoalHistory,
+                GoalCommand::Challenge(args) => Commands::ChallengeStatus(args),
+            },
+            Commands::Stats(args) => Commands::PrintStatistics(args),
+            command => command,
+        }
+    }
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cli = Cli::parse();
+    cli.command = cli.command.map(Commands::ungroup);
+
+    // Completions don't need settings or storage
+    if let Some(Commands::Completions { shell }) = cli.command {
+        let mut command = Cli::command();
+        let name = command.get_name().to_string();
+        clap_complete::generate(shell, &mut command, name, &mut std::io::stdout());
+        return Ok(());
+    }
+
     let mut settings = config::Settings::load()?;
-    let cli = Cli::parse();
 
     match cli.command {
         Some(Commands::ChangeStoragePath { path }) => {
@@ -171,6 +644,27 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Storage path updated successfully!");
             return Ok(());
         }
+        Some(Commands::PrintConfig) => {
+            println!("{}", settings.to_json());
+            return Ok(());
+        }
+        Some(Commands::ValidateConfig) => {
+            let issues = settings.validate();
+            for setting in config::VALIDATED_SETTINGS {
+                let setting_issues: Vec<_> =
+                    issues.iter().filter(|i| i.setting == setting).collect();
+                if setting_issues.is_empty() {
+                    println!("PASS  {}", setting);
+                }
+                for issue in setting_issues {
+                    println!("FAIL  {}: {}", setting, issue.message);
+                }
+            }
+            if !issues.is_empty() {
+                return Err(format!("{} configuration problem(s) found", issues.len()).into());
+            }
+            return Ok(());
+        }
         _ => {
             if settings.storage_file.is_empty() {
                 return Err(
@@ -181,18 +675,66 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    let tz = settings.tz()?;
+    let date_format = settings.date_fmt()?;
+    let clock = SystemClock;
+    let default_status = settings.default_book_status()?;
+
     // Initialize storage file if it doesn't exist
     storage::initialize_storage_file(&settings.storage_file)?;
 
-    let mut storage = storage::load_and_repair_storage(&settings.storage_file, &InquirePrompter)?;
+    let repair_mode = if settings.repair_summary {
+        RepairMode::SummaryFirst
+    } else {
+        RepairMode::Incremental
+    };
+    let mut storage = storage::load_and_repair_storage_with_mode(
+        &settings.storage_file,
+        &InquirePrompter,
+        repair_mode,
+    )?;
+
+    // Book listings go to --output when given, otherwise to the terminal
+    let mut writer: Box<dyn Write> = match &cli.output {
+        Some(path) => Box::new(std::fs::File::create(path)?),
+        None => Box::new(std::io::stdout()),
+    };
+    let max_width = if cli.output.is_some() {
+        None
+    } else {
+        bookmon::table::terminal_width()
+    };
+    let mut out = TableOutput::new(writer.as_mut(), cli.table_style())
+        .with_max_width(max_width)
+        .with_date_format(date_format);
 
     // Handle commands (or default to showing currently-reading)
     if let Some(ref command) = cli.command {
         match command {
-            Commands::AddBook => {
-                match book::get_book_input(&mut storage) {
+            Commands::AddBook(AddBookArgs {
+                status,
+                strict_isbn,
+                no_isbn,
+            }) => {
+                let status = status
+                    .as_deref()
+                    .map(book::parse_initial_status)
+                    .transpose()?;
+                let strict_isbn = *strict_isbn || settings.strict_isbn;
+                match book::get_book_input(
+                    &mut storage,
+                    &settings.http_client()?.with_network(!cli.no_network),
+                    default_status,
+                    status,
+                    strict_isbn,
+                    *no_isbn,
+                ) {
                     Ok((book, event)) => {
-                        match book::store_book(&mut storage, book.clone()) {
+                        match book::store_book_with_isbn_check(
+                            &mut storage,
+                            book.clone(),
+                            strict_isbn,
+                        ) {
                             Ok(_) => {
                                 // Store all reading events
                                 for event_type in event {
@@ -212,74 +754,235 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     Err(e) => eprintln!("Failed to get book input: {}", e),
                 }
             }
-            Commands::SetGoal { target, year } => {
-                let year = year.unwrap_or_else(|| chrono::Utc::now().year());
-                storage.set_goal(year, *target);
-                storage::write_storage(&settings.storage_file, &storage)?;
-                println!("Reading goal for {}: {} books", year, target);
+            Commands::SetGoal(SetGoalArgs {
+                target,
+                year,
+                category,
+            }) => {
+                let year = year.unwrap_or_else(|| tz.year_of(&clock.now()));
+                let target = match target {
+                    Some(target) => *target,
+                    None => {
+                        let suggestion = storage.suggested_goal(year, &tz);
+                        Text::new(&format!("How many books do you want to read in {}?", year))
+                            .with_default(&suggestion.to_string())
+                            .prompt()
+                            .map_err(std::io::Error::other)?
+                            .trim()
+                            .parse::<u32>()
+                            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?
+                    }
+                };
+                match category {
+                    Some(category) => {
+                        if let Err(e) = storage.set_category_goal(year, category, target) {
+                            eprintln!("Failed to set category goal: {}", e);
+                            return Ok(());
+                        }
+                        storage::write_storage(&settings.storage_file, &storage)?;
+                        println!(
+                            "Reading challenge for {}: {} {} in {}",
+                            year,
+                            target,
+                            goal::pluralize_book(target),
+                            category.trim()
+                        );
+                    }
+                    None => {
+                        storage.set_goal(year, target);
+                        storage::write_storage(&settings.storage_file, &storage)?;
+                        println!("Reading goal for {}: {} books", year, target);
+                    }
+                }
             }
-            Commands::PrintGoal { year } => {
-                let year = year.unwrap_or_else(|| chrono::Utc::now().year());
-                print_goal_status(&storage, year);
+            Commands::PrintGoal(PrintGoalArgs {
+                year,
+                pages_goal_from_finished,
+            }) => {
+                let year = year.unwrap_or_else(|| tz.year_of(&clock.now()));
+                print_goal_status(&storage, year, &tz, &clock, *pages_goal_from_finished);
+            }
+            Commands::Report { year, journal } => {
+                let year = year.unwrap_or_else(|| tz.year_of(&clock.now()));
+                let report = if *journal {
+                    bookmon::report::reading_journal_markdown(&storage, year, &tz, date_format)
+                } else {
+                    bookmon::report::year_report_markdown(&storage, year, &tz, date_format)
+                };
+                println!("{}", report);
+            }
+            Commands::GoalHistory => {
+                goal::show_goal_history(&storage, &tz, clock.now());
+            }
+            Commands::ChallengeStatus(ChallengeStatusArgs { year }) => {
+                let year = year.unwrap_or_else(|| tz.year_of(&clock.now()));
+                let status = storage.challenge_status(year, &tz);
+                if status.is_empty() {
+                    println!(
+                        "No reading challenge set for {}. Use `set-goal <n> --category <name>` to add one.",
+                        year
+                    );
+                } else {
+                    println!("Reading challenge {}:", year);
+                    for line in goal::format_challenge_status(&status) {
+                        println!("{}", line);
+                    }
+                }
             }
             Commands::ReviewBook => {
                 review_book_flow(&mut storage, &settings.storage_file)?;
             }
             Commands::PrintReviews => {
                 if cli.interactive {
-                    review_interactive_mode(&storage)?;
+                    review_interactive_mode(&storage, date_format)?;
                 } else {
-                    match review::show_reviews(&storage) {
+                    match review::show_reviews(&storage, date_format) {
                         Ok(_) => {}
                         Err(e) => eprintln!("Failed to show reviews: {}", e),
                     }
                 }
             }
-            Commands::PrintFinished { ref series } => {
+            Commands::PrintFinished {
+                ref series,
+                count,
+                limit,
+                offset,
+                oldest_first,
+                newest_first,
+                group_by,
+                min_rating,
+            } => {
                 if cli.interactive {
-                    interactive_mode(&storage, &settings.storage_file, Some(command))?;
+                    interactive_mode(&mut storage, &settings.storage_file, Some(command))?;
                 } else {
-                    let books = storage.get_finished_books();
-                    if let Some(filter) = series {
-                        let filtered =
-                            bookmon::series::filter_books_by_series(&storage, &books, filter);
-                        let empty_msg =
-                            bookmon::series::format_series_filter_empty_message(&storage, filter);
-                        match reading::show_finished_books_list(&storage, filtered, &empty_msg) {
-                            Ok(_) => {}
-                            Err(e) => eprintln!("Failed to show finished books: {}", e),
-                        }
+                    let mut books = storage.get_finished_books();
+                    if let Some(min_rating) = min_rating {
+                        let rated: Vec<&str> = storage
+                            .books_with_min_rating(*min_rating)
+                            .iter()
+                            .map(|b| b.id.as_str())
+                            .collect();
+                        books.retain(|b| rated.contains(&b.id.as_str()));
+                    }
+                    if *count {
+                        println!(
+                            "{}",
+                            bookmon::series::count_books_matching_series(
+                                &storage,
+                                &books,
+                                series.as_deref()
+                            )
+                        );
                     } else {
-                        match reading::show_finished_books_list(
-                            &storage,
-                            books,
-                            "No finished books found.",
-                        ) {
-                            Ok(_) => {}
-                            Err(e) => eprintln!("Failed to show finished books: {}", e),
+                        let (books, empty_msg) = match series {
+                            Some(filter) => (
+                                bookmon::series::filter_books_by_series(&storage, &books, filter),
+                                bookmon::series::format_series_filter_empty_message(
+                                    &storage, filter,
+                                ),
+                            ),
+                            None => match min_rating {
+                                Some(min_rating) => (
+                                    books,
+                                    format!("No finished books rated {} or higher.", min_rating),
+                                ),
+                                None => (books, "No finished books found.".to_string()),
+                            },
+                        };
+                        let order = if *oldest_first {
+                            reading::FinishedOrder::OldestFirst
+                        } else if *newest_first {
+                            reading::FinishedOrder::NewestFirst
+                        } else if group_by.is_some() {
+                            reading::FinishedOrder::OldestFirst
+                        } else {
+                            reading::FinishedOrder::AuthorTitle
+                        };
+                        // Month groups follow the finish-date order, so reversing
+                        // them is the same as flipping that order
+                        let month_order = match (order, cli.reverse) {
+                            (reading::FinishedOrder::NewestFirst, true) => {
+                                reading::FinishedOrder::OldestFirst
+                            }
+                            (_, true) => reading::FinishedOrder::NewestFirst,
+                            (order, false) => order,
+                        };
+                        let total = books.len();
+                        let page = reading::paginate(
+                            reading::sort_finished_books_by(&storage, books, order),
+                            *offset,
+                            *limit,
+                        );
+                        let empty_msg = if total > 0 {
+                            format!(
+                                "No finished books at offset {} ({} in total).",
+                                offset, total
+                            )
+                        } else {
+                            empty_msg
+                        };
+                        match group_by {
+                            Some(GroupBy::Month) => reading::show_finished_books_by_month(
+                                &mut out,
+                                &storage,
+                                page,
+                                &empty_msg,
+                                cli.full_titles,
+                                month_order,
+                                &tz,
+                            ),
+                            None => reading::show_finished_books_list(
+                                &mut out,
+                                &storage,
+                                page,
+                                &empty_msg,
+                                cli.full_titles,
+                                order,
+                                cli.reverse,
+                            ),
                         }
+                        .unwrap_or_else(|e| eprintln!("Failed to show finished books: {}", e));
                     }
                 }
             }
-            Commands::PrintBacklog { ref series } => {
+            Commands::PrintBacklog { ref series, count } => {
                 if cli.interactive {
-                    interactive_mode(&storage, &settings.storage_file, Some(command))?;
+                    interactive_mode(&mut storage, &settings.storage_file, Some(command))?;
                 } else {
                     let books = storage.get_unstarted_books();
-                    if let Some(filter) = series {
+                    if *count {
+                        println!(
+                            "{}",
+                            bookmon::series::count_books_matching_series(
+                                &storage,
+                                &books,
+                                series.as_deref()
+                            )
+                        );
+                    } else if let Some(filter) = series {
                         let filtered =
                             bookmon::series::filter_books_by_series(&storage, &books, filter);
                         let empty_msg =
                             bookmon::series::format_series_filter_empty_message(&storage, filter);
-                        match reading::print_book_list_table(&storage, filtered, &empty_msg) {
+                        match reading::print_book_list_table(
+                            &mut out,
+                            &storage,
+                            filtered,
+                            &empty_msg,
+                            cli.full_titles,
+                            cli.reverse,
+                        ) {
                             Ok(_) => {}
                             Err(e) => eprintln!("Failed to show unstarted books: {}", e),
                         }
                     } else {
                         match reading::print_book_list_table(
+                            &mut out,
                             &storage,
                             books,
                             "No unstarted books found.",
+                            cli.full_titles,
+                            cli.reverse,
                         ) {
                             Ok(_) => {}
                             Err(e) => eprintln!("Failed to show unstarted books: {}", e),
@@ -287,25 +990,44 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
             }
-            Commands::PrintWantToRead { ref series } => {
+            Commands::PrintWantToRead { ref series, count } => {
                 if cli.interactive {
-                    interactive_mode(&storage, &settings.storage_file, Some(command))?;
+                    interactive_mode(&mut storage, &settings.storage_file, Some(command))?;
                 } else {
                     let books = storage.get_want_to_read_books();
-                    if let Some(filter) = series {
+                    if *count {
+                        println!(
+                            "{}",
+                            bookmon::series::count_books_matching_series(
+                                &storage,
+                                &books,
+                                series.as_deref()
+                            )
+                        );
+                    } else if let Some(filter) = series {
                         let filtered =
                             bookmon::series::filter_books_by_series(&storage, &books, filter);
                         let empty_msg =
                             bookmon::series::format_series_filter_empty_message(&storage, filter);
-                        match reading::print_book_list_table(&storage, filtered, &empty_msg) {
+                        match reading::print_book_list_table(
+                            &mut out,
+                            &storage,
+                            filtered,
+                            &empty_msg,
+                            cli.full_titles,
+                            cli.reverse,
+                        ) {
                             Ok(_) => {}
                             Err(e) => eprintln!("Failed to show want to read books: {}", e),
                         }
                     } else {
                         match reading::print_book_list_table(
+                            &mut out,
                             &storage,
                             books,
                             "No books in want to read list.",
+                            cli.full_titles,
+                            cli.reverse,
                         ) {
                             Ok(_) => {}
                             Err(e) => eprintln!("Failed to show want to read books: {}", e),
@@ -313,67 +1035,96 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
             }
-            Commands::PrintStatistics => {
-                if cli.interactive {
-                    interactive_mode(&storage, &settings.storage_file, Some(command))?;
-                } else if let Some(earliest_year) = storage.get_earliest_finished_year() {
-                    let current_year = chrono::Utc::now().year();
-                    println!("\nReading Statistics by Year:");
-                    println!("------------------------");
-
-                    for year in earliest_year..=current_year {
-                        let books = storage.get_books_finished_in_year(year);
-                        if !books.is_empty() {
-                            // Show goal progress if a goal is set for this year
-                            if let Some(target) = storage.get_goal(year) {
-                                let finished = books.len() as u32;
-                                let pct = goal_percentage(finished, target);
-                                let remaining = target.saturating_sub(finished);
-                                if year == current_year && remaining > 0 {
-                                    println!(
-                                            "\n{}: {} books (Goal: {} \u{2014} {:.0}% complete, {} remaining)",
-                                            year,
-                                            books.len(),
-                                            target,
-                                            pct,
-                                            remaining
-                                        );
-                                } else {
-                                    println!(
-                                        "\n{}: {} books (Goal: {} \u{2014} {:.0}% complete)",
-                                        year,
-                                        books.len(),
-                                        target,
-                                        pct
-                                    );
-                                }
-                            } else {
-                                println!("\n{}: {} books", year, books.len());
-                            }
-                            for book in books {
-                                let author_name = storage.author_name_for_book(book);
-                                let author_name = if author_name.is_empty() {
-                                    "Unknown Author"
-                                } else {
-                                    author_name
-                                };
-                                println!("  - \"{}\" by {}", book.title, author_name);
-                            }
+            Commands::Standalone => {
+                if let Err(e) = reading::print_all_books_table(
+                    &mut out,
+                    &storage,
+                    storage.standalone_books(),
+                    "No standalone books found.",
+                    cli.full_titles,
+                    &settings.status_symbols(),
+                    cli.reverse,
+                ) {
+                    eprintln!("Failed to show books: {}", e);
+                }
+            }
+            Commands::Books { missing_pages } => {
+                if *missing_pages {
+                    print_books_missing_pages(&storage, cli.full_titles);
+                } else {
+                    let mut books: Vec<&Book> = storage.books.values().collect();
+                    books.sort_by(|a, b| a.title.cmp(&b.title));
+                    let footer = reading::format_library_counts(&storage, &books);
+                    let result = reading::print_all_books_table(
+                        &mut out,
+                        &storage,
+                        books,
+                        "No books in your library yet.",
+                        cli.full_titles,
+                        &settings.status_symbols(),
+                        cli.reverse,
+                    )
+                    .and_then(|_| {
+                        if cli.quiet || storage.books.is_empty() {
+                            Ok(())
+                        } else {
+                            out.write_line(&footer)
                         }
+                    });
+                    if let Err(e) = result {
+                        eprintln!("Failed to show books: {}", e);
                     }
+                }
+            }
+            Commands::PrintStatistics(PrintStatisticsArgs {
+                page_distribution,
+                include_dnf,
+            }) => {
+                if cli.interactive {
+                    interactive_mode(&mut storage, &settings.storage_file, Some(command))?;
+                } else if *page_distribution {
+                    print_page_distribution(&storage);
                 } else {
-                    println!("No finished books found in your reading history.");
+                    print_statistics(&storage, &tz, &clock, *include_dnf);
                 }
             }
             Commands::GetConfigPath => {
                 println!("Config file path: {}", config::get_config_path()?.display());
             }
-            Commands::GetIsbn { isbn } => {
-                let client = http_client::HttpClient::new();
-                let book =
-                    tokio::runtime::Runtime::new()?.block_on(client.get_book_by_isbn(isbn))?;
+            Commands::GetIsbn(GetIsbnArgs {
+                isbn,
+                cover,
+                max_desc,
+                full,
+            }) => {
+                let client = settings.http_client()?.with_network(!cli.no_network);
+                if !client.is_network_enabled() {
+                    eprintln!("{}; cannot look up ISBN {}", NETWORK_DISABLED, isbn);
+                    return Ok(());
+                }
+                let runtime = tokio::runtime::Runtime::new()?;
+                let book = runtime.block_on(client.get_book_by_isbn(isbn))?;
                 if let Some(book) = book {
+                    if *cover {
+                        if !bookmon::cover::is_enabled() {
+                            eprintln!(
+                                "Cover art is not available; rebuild with --features cover-art"
+                            );
+                        } else if let Some(source) =
+                            bookmon::cover::select_cover_source(None, book.cover_url.as_deref())
+                        {
+                            if let Err(e) = runtime.block_on(bookmon::cover::render_cover(source)) {
+                                eprintln!("Failed to show cover: {}", e);
+                            }
+                        }
+                    }
                     println!("Title: {}", book.title);
+                    if let Some(isbn13) = bookmon::isbn::to_isbn13(isbn) {
+                        println!("ISBN-13: {}", isbn13);
+                    }
+                    if let Some(isbn10) = bookmon::isbn::to_isbn10(isbn) {
+                        println!("ISBN-10: {}", isbn10);
+                    }
                     println!("Authors:");
                     for author in book.authors {
                         println!("  - {}", author.name);
@@ -381,8 +1132,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     if let Some(publish_date) = book.publish_date {
                         println!("Published: {}", publish_date);
                     }
+                    if let Some(publisher) = book.publisher {
+                        println!("Publisher: {}", publisher);
+                    }
                     if let Some(description) = book.description {
-                        println!("Description: {}", description);
+                        if *full {
+                            println!("Description: {}", description);
+                        } else {
+                            println!(
+                                "Description: {}",
+                                review::truncate_text(&description, *max_desc)
+                            );
+                        }
                     }
                     if let Some(cover_url) = book.cover_url {
                         println!("Cover URL: {}", cover_url);
@@ -396,10 +1157,43 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 } else {
                     println!("No book found for ISBN {}", isbn);
+                    if let Some(hint) = bookmon::isbn::format_isbn_suggestions(isbn) {
+                        println!("{}", hint);
+                    }
                 }
             }
-            Commands::PrintSeries => {
-                print_series(&storage);
+            Commands::PrintSeries(PrintSeriesArgs { compact, limit }) => {
+                print_series(&storage, &settings.status_symbols(), *compact, *limit);
+            }
+            Commands::Categories => {
+                bookmon::category::list_categories(&storage);
+            }
+            Commands::EditCategory => {
+                edit_category_flow(&mut storage, &settings.storage_file)?;
+            }
+            Commands::DedupeAuthors => {
+                dedupe_authors_flow(&mut storage, &settings.storage_file)?;
+            }
+            Commands::Recategorize {
+                from,
+                to,
+                delete_empty,
+            } => match storage.recategorize(from, to, *delete_empty) {
+                Ok(moved) => {
+                    storage::write_storage(&settings.storage_file, &storage)?;
+                    let books = if moved == 1 { "book" } else { "books" };
+                    println!(
+                        "Moved {} {} from \"{}\" to \"{}\".",
+                        moved,
+                        books,
+                        from.trim(),
+                        to.trim()
+                    );
+                }
+                Err(e) => eprintln!("Failed to recategorize books: {}", e),
+            },
+            Commands::MarkFinished => {
+                mark_finished_flow(&mut storage, &settings.storage_file)?;
             }
             Commands::DeleteSeries => {
                 delete_series_flow(&mut storage, &settings.storage_file)?;
@@ -407,16 +1201,386 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             Commands::RenameSeries => {
                 rename_series_flow(&mut storage, &settings.storage_file)?;
             }
+            Commands::ShowBook(ShowBookArgs { book: reference }) => {
+                match storage.resolve_book_ref(reference) {
+                    Ok(book) => {
+                        println!("{}", book::format_book_details(&storage, book, date_format))
+                    }
+                    Err(e) => eprintln!("{}", e),
+                }
+            }
+            Commands::Recommend(RecommendArgs {
+                book: reference,
+                limit,
+            }) => {
+                let book = match storage.resolve_book_ref(reference) {
+                    Ok(book) => book,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return Ok(());
+                    }
+                };
+                let suggestions = storage.recommendations_for(&book.id, *limit);
+                if suggestions.is_empty() {
+                    println!(
+                        "No unread books by the same author or in the same category as \"{}\".",
+                        book.title
+                    );
+                } else {
+                    println!("Because you read \"{}\":", book.title);
+                    for suggestion in suggestions {
+                        let reason = if suggestion.author_id == book.author_id {
+                            "same author"
+                        } else {
+                            "same category"
+                        };
+                        println!(
+                            "  {} by {} ({})",
+                            suggestion.title,
+                            storage.author_name_for_book(suggestion),
+                            reason
+                        );
+                    }
+                }
+            }
+            Commands::Quotes { book, add, page } => {
+                let book = match storage.resolve_book_ref(book) {
+                    Ok(book) => book,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return Ok(());
+                    }
+                };
+                match add.as_deref().map(str::trim) {
+                    Some("") => eprintln!("The quote is empty."),
+                    Some(text) => {
+                        let quote = storage::Quote::new(book.id.clone(), text.to_string(), *page);
+                        match bookmon::quote::store_quote(&mut storage, quote) {
+                            Ok(()) => {
+                                storage::write_storage(&settings.storage_file, &storage)?;
+                                println!("Quote saved.");
+                            }
+                            Err(e) => eprintln!("Failed to store quote: {}", e),
+                        }
+                    }
+                    None => {
+                        let quotes = bookmon::quote::format_quotes(&storage, &book.id);
+                        if quotes.is_empty() {
+                            println!("No quotes from \"{}\" yet.", book.title);
+                        } else {
+                            println!("Quotes from \"{}\":", book.title);
+                            for quote in quotes {
+                                println!("  {}", quote);
+                            }
+                        }
+                    }
+                }
+            }
+            Commands::NextUp { limit } => {
+                let books = storage.next_up(*limit);
+                if books.is_empty() {
+                    println!(
+                        "Nothing to suggest. Mark some books as want to read, or start a series."
+                    );
+                }
+                for (i, book) in books.iter().enumerate() {
+                    let author_name = storage.author_name_for_book(book);
+                    let author_name = if author_name.is_empty() {
+                        "Unknown Author"
+                    } else {
+                        author_name
+                    };
+                    println!("{}. \"{}\" by {}", i + 1, book.title, author_name);
+                }
+            }
+            Commands::Enrich(EnrichArgs { book: reference }) => {
+                let (book_id, isbn) = match storage.resolve_book_ref(reference) {
+                    Ok(book) => (book.id.clone(), book.isbn.clone()),
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return Ok(());
+                    }
+                };
+                let client = settings.http_client()?.with_network(!cli.no_network);
+                if !client.is_network_enabled() {
+                    eprintln!("{}; cannot enrich ISBN {}", NETWORK_DISABLED, isbn);
+                    return Ok(());
+                }
+                let info =
+                    tokio::runtime::Runtime::new()?.block_on(client.get_book_by_isbn(&isbn))?;
+                match info {
+                    Some(info) => match book::enrich_book(&mut storage, &book_id, &info) {
+                        Ok(filled) if filled.is_empty() => {
+                            println!("Nothing to enrich \u{2014} no missing details were found.");
+                        }
+                        Ok(filled) => {
+                            storage::write_storage(&settings.storage_file, &storage)?;
+                            println!("Filled in: {}.", filled.join(", "));
+                        }
+                        Err(e) => eprintln!("Failed to enrich book: {}", e),
+                    },
+                    None => println!("No book found for ISBN {}", isbn),
+                }
+            }
+            Commands::Recent {
+                limit,
+                offset,
+                after,
+                before,
+                event,
+                ids,
+            } => match reading::filter_readings(
+                &storage,
+                after.as_deref(),
+                before.as_deref(),
+                event.as_deref(),
+                *offset,
+                *limit,
+            ) {
+                Ok(readings) if readings.is_empty() => println!("No reading events found."),
+                Ok(readings) => {
+                    for reading in readings {
+                        let line =
+                            reading::format_reading_event_line(&storage, reading, date_format);
+                        if *ids {
+                            println!("{}  {}", storage::short_id(&reading.id), line);
+                        } else {
+                            println!("{}", line);
+                        }
+                    }
+                }
+                Err(e) => eprintln!("Failed to show recent events: {}", e),
+            },
+            Commands::ExportOpenLibrary => {
+                println!("{}", bookmon::export::export_openlibrary_json(&storage));
+            }
+            Commands::Export { format } => {
+                let mut out = std::io::BufWriter::new(std::io::stdout().lock());
+                let result = match format {
+                    ExportFormat::Ndjson => bookmon::export::export_ndjson_to_writer(&storage, out),
+                    ExportFormat::Csv => out
+                        .write_all(
+                            bookmon::export::export_csv(&storage, None, true, date_format)
+                                .as_bytes(),
+                        )
+                        .and_then(|_| out.flush()),
+                };
+                if let Err(e) = result {
+                    eprintln!("Failed to export: {}", e);
+                }
+            }
+            Commands::ExportReviews => {
+                print!(
+                    "{}",
+                    bookmon::export::export_reviews_csv(&storage, date_format)
+                );
+            }
+            Commands::ExportCsv {
+                path,
+                since,
+                append,
+            } => {
+                let since = match since.as_deref().map(reading::parse_date_bound).transpose() {
+                    Ok(since) => since,
+                    Err(e) => {
+                        eprintln!("Failed to export CSV: {}", e);
+                        return Ok(());
+                    }
+                };
+                match bookmon::export::write_csv_export(
+                    &storage,
+                    std::path::Path::new(path),
+                    since,
+                    *append,
+                    date_format,
+                ) {
+                    Ok(rows) => println!(
+                        "Exported {} {} to {}",
+                        rows,
+                        goal::pluralize_book(rows as u32),
+                        path
+                    ),
+                    Err(e) => eprintln!("Failed to export CSV: {}", e),
+                }
+            }
+            Commands::Merge { path } => {
+                let other = match storage::load_storage(path) {
+                    Ok(other) => other,
+                    Err(e) => {
+                        eprintln!("Failed to load {}: {}", path, e);
+                        return Ok(());
+                    }
+                };
+                let summary = bookmon::merge::merge_storage(&mut storage, &other);
+                storage::write_storage(&settings.storage_file, &storage)?;
+                println!("Merged {} into your library.", path);
+                println!("{}", summary.format());
+            }
+            Commands::Completions { .. } => unreachable!("handled before loading settings"),
+            Commands::Book { .. }
+            | Commands::Series { .. }
+            | Commands::Goal { .. }
+            | Commands::Stats(_) => unreachable!("grouped commands are ungrouped after parsing"),
+            Commands::ValidateConfig | Commands::PrintConfig => {
+                unreachable!("handled before loading storage")
+            }
+            Commands::Backup { path } => {
+                let destination = match path {
+                    Some(path) => std::path::PathBuf::from(path),
+                    None => storage::default_backup_path(
+                        &settings.storage_file,
+                        tz.local_datetime(&clock.now()),
+                    ),
+                };
+                storage::backup_storage(&settings.storage_file, &destination)?;
+                println!("Backed up library to {}", destination.display());
+            }
+            Commands::Check { json, fix } => {
+                if *fix {
+                    let repaired = storage.repair_keys();
+                    if repaired > 0 {
+                        storage::write_storage(&settings.storage_file, &storage)?;
+                        if !*json {
+                            println!(
+                                "Repaired {} record(s) stored under the wrong key.",
+                                repaired
+                            );
+                        }
+                    }
+                }
+                let issues = storage.integrity_issues();
+                if *json {
+                    println!("{}", serde_json::to_string_pretty(&issues)?);
+                } else if issues.is_empty() {
+                    println!("No problems found.");
+                } else {
+                    for issue in &issues {
+                        println!("WARN  {}", issue.detail);
+                    }
+                }
+                if !issues.is_empty() {
+                    return Err(format!("{} problem(s) found", issues.len()).into());
+                }
+            }
+            Commands::Summary { porcelain } => {
+                let summary = bookmon::summary::LibrarySummary::from_storage(&storage);
+                if *porcelain {
+                    println!("{}", summary.format_porcelain());
+                } else {
+                    println!("{}", summary.format_pretty());
+                }
+            }
+            Commands::BulkAdd {
+                path,
+                category,
+                yes,
+            } => {
+                let contents = std::fs::read_to_string(path)?;
+                let isbns = bookmon::bulk::parse_isbn_list(&contents);
+                if isbns.is_empty() {
+                    println!("No ISBNs found in {}", path);
+                    return Ok(());
+                }
+
+                let category_id = bookmon::category::get_or_create_category(&mut storage, category);
+                let client = settings.http_client()?.with_network(!cli.no_network);
+                let runtime = tokio::runtime::Runtime::new()?;
+                let summary = bookmon::bulk::bulk_add(
+                    &mut storage,
+                    &isbns,
+                    &category_id,
+                    |isbn| {
+                        // Without network, every ISBN goes straight to manual entry
+                        if !client.is_network_enabled() {
+                            return Ok(None);
+                        }
+                        println!("Looking up {}...", isbn);
+                        runtime
+                            .block_on(client.get_book_by_isbn(isbn))
+                            .map_err(|e| e.to_string())
+                    },
+                    |isbn, partial| {
+                        if *yes {
+                            None
+                        } else {
+                            prompt_missing_book_details(isbn, partial)
+                        }
+                    },
+                );
+
+                if !summary.added.is_empty() {
+                    storage::write_storage(&settings.storage_file, &storage)?;
+                }
+                println!("{}", summary.format());
+            }
+            Commands::OnThisDay => {
+                let today = tz.local_date(&clock.now());
+                let earlier: Vec<_> = storage
+                    .finished_on_day(today.month(), today.day(), &tz)
+                    .into_iter()
+                    .filter(|(_, year)| *year < today.year())
+                    .collect();
+                if earlier.is_empty() {
+                    println!("No books finished on this day in earlier years.");
+                } else {
+                    println!("On this day ({}):", today.format("%B %-d"));
+                    for (book, year) in earlier {
+                        let author = storage.author_name_for_book(book);
+                        if author.is_empty() {
+                            println!("  {}: {}", year, book.title);
+                        } else {
+                            println!("  {}: {} by {}", year, book.title, author);
+                        }
+                    }
+                }
+            }
+            Commands::RemoveEvent { id } => {
+                let reading_id = match storage.resolve_reading_ref(id) {
+                    Ok(reading) => reading.id.clone(),
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return Ok(());
+                    }
+                };
+                if let Some(reading) = storage.remove_reading(&reading_id) {
+                    storage::write_storage(&settings.storage_file, &storage)?;
+                    println!(
+                        "Removed: {}",
+                        reading::format_reading_event_line(&storage, &reading, date_format)
+                    );
+                }
+            }
+            Commands::UpdateProgress(UpdateProgressArgs {
+                book: reference,
+                page,
+            }) => match reading::update_progress_by_ref(&mut storage, reference, *page) {
+                Ok(_) => {
+                    storage::write_storage(&settings.storage_file, &storage)?;
+                    println!("Progress updated to page {}.", page);
+                }
+                Err(e) => eprintln!("Failed to update progress: {}", e),
+            },
             Commands::ChangeStoragePath { .. } => unreachable!(),
         }
     } else {
-        // Default case (no command) - show goal status + currently-reading
-        show_goal_status_if_set(&storage);
+        // Default case (no command) - show currently-reading with a goal footer,
+        // or the full goal status before the interactive menu
         if cli.interactive {
-            interactive_mode(&storage, &settings.storage_file, None)?;
+            show_goal_status_if_set(&storage, &tz, &clock);
+            interactive_mode(&mut storage, &settings.storage_file, None)?;
+        } else if let Some(seconds) = cli.watch {
+            watch_default_view(&settings, &tz, cli.full_titles, cli.quiet, seconds)?;
         } else {
-            match reading::show_started_books(&storage) {
-                Ok(_) => {}
+            match reading::format_default_view(
+                &storage,
+                &tz,
+                &clock,
+                date_format,
+                cli.full_titles,
+                settings.stale_days,
+                cli.quiet,
+            ) {
+                Ok(view) => write!(out.writer, "{}", view)?,
                 Err(e) => eprintln!("Failed to show started books: {}", e),
             }
         }
@@ -425,6 +1589,178 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Redraws the default view every `seconds` seconds until Ctrl-C, reloading
+/// the storage file each time so changes made elsewhere show up.
+fn watch_default_view(
+    settings: &config::Settings,
+    tz: &Timezone,
+    full_titles: bool,
+    quiet: bool,
+    seconds: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let interval = std::time::Duration::from_secs(seconds.max(1));
+    tokio::runtime::Runtime::new()?.block_on(async {
+        loop {
+            // Clear the screen and move the cursor to the top left
+            print!("\x1B[2J\x1B[H");
+            match reading::reload_and_render(
+                &settings.storage_file,
+                tz,
+                &SystemClock,
+                settings.date_fmt()?,
+                full_titles,
+                settings.stale_days,
+                quiet,
+            ) {
+                Ok(view) => print!("{}", view),
+                Err(e) => eprintln!("{}", e),
+            }
+            std::io::stdout().flush()?;
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => break,
+                _ = tokio::time::sleep(interval) => {}
+            }
+        }
+        println!();
+        Ok(())
+    })
+}
+
+/// Prints reading statistics by year: finished books (with goal progress when a goal
+/// is set) and how many books were added to the library that year.
+fn print_statistics(storage: &Storage, tz: &Timezone, clock: &dyn Clock, include_dnf: bool) {
+    let earliest_year = match storage.get_earliest_finished_year(tz) {
+        Some(year) => year,
+        None => {
+            println!("No finished books found in your reading history.");
+            return;
+        }
+    };
+    let earliest_year = storage
+        .get_earliest_added_year(tz)
+        .map_or(earliest_year, |added| added.min(earliest_year));
+
+    let today = tz.local_date(&clock.now());
+    let current_year = today.year();
+    println!("\nReading Statistics by Year:");
+    println!("------------------------");
+
+    for year in earliest_year..=current_year {
+        let books = storage.get_books_finished_in_year(year, tz);
+        let added = storage.books_added_in_year(year, tz).len();
+        let abandoned = if include_dnf {
+            storage.get_dnf_books_in_year(year, tz)
+        } else {
+            Vec::new()
+        };
+        if books.is_empty() && added == 0 && abandoned.is_empty() {
+            continue;
+        }
+
+        // Show goal progress if a goal is set for this year
+        if let Some(target) = storage.get_goal(year) {
+            let finished = books.len() as u32;
+            let pct = goal_percentage(finished, target);
+            let remaining = target.saturating_sub(finished);
+            if year == current_year && remaining > 0 {
+                println!(
+                    "\n{}: {} books (Goal: {} \u{2014} {:.0}% complete, {} remaining)",
+                    year,
+                    books.len(),
+                    target,
+                    pct,
+                    remaining
+                );
+            } else {
+                println!(
+                    "\n{}: {} books (Goal: {} \u{2014} {:.0}% complete)",
+                    year,
+                    books.len(),
+                    target,
+                    pct
+                );
+            }
+        } else {
+            println!("\n{}: {} books", year, books.len());
+        }
+        for book in books {
+            let author_name = storage.author_name_for_book(book);
+            let author_name = if author_name.is_empty() {
+                "Unknown Author"
+            } else {
+                author_name
+            };
+            println!("  - \"{}\" by {}", book.title, author_name);
+        }
+        let by_category = storage.finished_counts_by_category(Some(year), tz);
+        if !by_category.is_empty() {
+            let parts: Vec<String> = by_category
+                .iter()
+                .map(|(name, count)| format!("{} {}", name, count))
+                .collect();
+            println!("  By category: {}", parts.join(", "));
+        }
+        if !abandoned.is_empty() {
+            let titles: Vec<String> = abandoned
+                .iter()
+                .map(|book| format!("\"{}\"", book.title))
+                .collect();
+            println!(
+                "  Did not finish: {} {} ({})",
+                abandoned.len(),
+                goal::pluralize_book(abandoned.len() as u32),
+                titles.join(", ")
+            );
+        }
+        if added > 0 {
+            println!(
+                "  Added to library: {} {}",
+                added,
+                goal::pluralize_book(added as u32)
+            );
+        }
+        let spent = storage.spending_by_currency(Some(year), tz);
+        if !spent.is_empty() {
+            println!("  Spent on books: {}", reading::format_spending(&spent));
+        }
+    }
+
+    let spent = storage.spending_by_currency(None, tz);
+    if !spent.is_empty() {
+        println!(
+            "\nTotal spent on books: {}",
+            reading::format_spending(&spent)
+        );
+    }
+
+    // Compare this year's pace against earlier years at the same date
+    let count = storage.finished_count_through_day_of_year(current_year, today.ordinal(), tz);
+    let previous: Vec<(i32, usize)> = (earliest_year..current_year)
+        .rev()
+        .map(|year| {
+            let day = goal::same_day_of_year(today, year);
+            (
+                year,
+                storage.finished_count_through_day_of_year(year, day, tz),
+            )
+        })
+        .collect();
+    if let Some(line) = goal::format_pace_comparison(current_year, count, &previous) {
+        println!("\n{}", line);
+    }
+}
+
+/// Prints a bar chart of how many finished books fall in each page-count range.
+fn print_page_distribution(storage: &Storage) {
+    let buckets = storage.finished_page_buckets();
+    if buckets.iter().all(|(_, count)| *count == 0) {
+        println!("No finished books with a page count found.");
+        return;
+    }
+    println!("\nFinished books by length (pages):");
+    println!("{}", bookmon::table::format_bar_chart(&buckets, 30));
+}
+
 /// Calculates the percentage of a reading goal completed.
 fn goal_percentage(finished: u32, target: u32) -> f64 {
     if target > 0 {
@@ -434,28 +1770,51 @@ fn goal_percentage(finished: u32, target: u32) -> f64 {
     }
 }
 
-/// Prints a progress bar using Unicode block characters.
-fn print_progress_bar(finished: u32, target: u32) {
-    let bar_width = 20;
-    let filled = if target > 0 {
-        ((finished as f64 / target as f64) * bar_width as f64)
-            .round()
-            .min(bar_width as f64) as usize
-    } else {
-        bar_width
-    };
-    let empty = bar_width - filled;
+/// Lists books without a page count, with their ISBN so they can be fixed.
+fn print_books_missing_pages(storage: &Storage, full_titles: bool) {
+    let books = storage.books_missing_pages();
+    if books.is_empty() {
+        println!("All books have a page count.");
+        return;
+    }
 
-    let bar: String = "\u{2588}".repeat(filled) + &"\u{2591}".repeat(empty);
-    print!("{}", bar);
+    println!(
+        "{} {} missing a page count:",
+        books.len(),
+        if books.len() == 1 {
+            "book is"
+        } else {
+            "books are"
+        }
+    );
+    for book in books {
+        let author_name = storage.author_name_for_book(book);
+        let author_name = if author_name.is_empty() {
+            "Unknown Author"
+        } else {
+            author_name
+        };
+        println!(
+            "  - \"{}\" by {} (ISBN {})",
+            book.display_title(full_titles),
+            author_name,
+            book.isbn
+        );
+    }
 }
 
 /// Prints the reading goal status for a given year.
 /// Shows book count, percentage, progress bar, remaining count, and motivational pace text.
-fn print_goal_status(storage: &Storage, year: i32) {
+fn print_goal_status(
+    storage: &Storage,
+    year: i32,
+    tz: &Timezone,
+    clock: &dyn Clock,
+    show_pages: bool,
+) {
     match storage.get_goal(year) {
         Some(target) => {
-            let finished = storage.get_books_finished_in_year(year).len() as u32;
+            let finished = storage.get_books_finished_in_year(year, tz).len() as u32;
             let pct = goal_percentage(finished, target);
             let remaining = target.saturating_sub(finished);
 
@@ -463,17 +1822,26 @@ fn print_goal_status(storage: &Storage, year: i32) {
                 "\nReading goal {}: {}/{} books ({:.0}%)\n",
                 year, finished, target, pct
             );
-            print_progress_bar(finished, target);
+            print!(
+                "{}",
+                goal::render_progress_bar(finished, target, goal::PROGRESS_BAR_WIDTH)
+            );
             if remaining > 0 {
                 println!(" {} remaining", remaining);
             } else {
                 println!(" Goal reached!");
             }
             if let Some(motivation) =
-                goal::motivational_pace_text(finished, target, year, chrono::Utc::now())
+                goal::motivational_pace_text(finished, target, year, clock.now())
             {
                 println!("{}", motivation);
             }
+            if show_pages {
+                println!(
+                    "{}",
+                    goal::format_implied_pages(target, storage.average_finished_pages())
+                );
+            }
             println!();
         }
         None => {
@@ -485,17 +1853,23 @@ fn print_goal_status(storage: &Storage, year: i32) {
     }
 }
 
-/// Prints the current year's goal status if one is set. Used by the default command.
-fn show_goal_status_if_set(storage: &Storage) {
-    let year = chrono::Utc::now().year();
+/// Prints the current year's goal status if one is set. Used by the default
+/// command in interactive mode.
+fn show_goal_status_if_set(storage: &Storage, tz: &Timezone, clock: &dyn Clock) {
+    let year = tz.year_of(&clock.now());
     if storage.get_goal(year).is_some() {
-        print_goal_status(storage, year);
+        print_goal_status(storage, year, tz, clock, false);
     }
 }
 
 /// Prints all series and their books, sorted by series name then position.
 /// Shows reading status indicators and progress for each series.
-fn print_series(storage: &Storage) {
+fn print_series(
+    storage: &Storage,
+    symbols: &config::StatusSymbols,
+    compact: bool,
+    limit: Option<usize>,
+) {
     if storage.series.is_empty() {
         println!("No series found.");
         return;
@@ -503,17 +1877,74 @@ fn print_series(storage: &Storage) {
 
     // Sort series by name
     let mut all_series: Vec<&storage::Series> = storage.series.values().collect();
-    all_series.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    all_series.sort_by_key(|a| a.name.to_lowercase());
+
+    if compact {
+        for s in all_series {
+            println!("{}", bookmon::series::format_series_summary(storage, &s.id));
+        }
+        return;
+    }
 
     for s in all_series {
         println!(
             "\n{}",
-            bookmon::series::format_series_display(storage, &s.id)
+            bookmon::series::format_series_display_with_limit(storage, &s.id, symbols, limit)
         );
     }
     println!();
 }
 
+/// Flow for the `mark-finished` command: pick any number of started books and
+/// mark them all as finished, writing storage once.
+fn mark_finished_flow(
+    storage: &mut Storage,
+    storage_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut started: Vec<(String, String)> = storage
+        .get_started_books()
+        .into_iter()
+        .map(|book| {
+            let author_name = storage.author_name_for_book(book);
+            (
+                format!("\"{}\" by {}", book.title, author_name),
+                book.id.clone(),
+            )
+        })
+        .collect();
+    started.sort();
+    if started.is_empty() {
+        println!("No books are currently being read.");
+        return Ok(());
+    }
+
+    let display_options: Vec<&str> = started.iter().map(|(d, _)| d.as_str()).collect();
+    let selection =
+        match MultiSelect::new("Select books to mark as finished:", display_options).raw_prompt() {
+            Ok(s) => s,
+            Err(_) => {
+                println!("Operation cancelled.");
+                return Ok(());
+            }
+        };
+    if selection.is_empty() {
+        println!("No books selected.");
+        return Ok(());
+    }
+
+    let ids: Vec<String> = selection
+        .iter()
+        .map(|option| started[option.index].1.clone())
+        .collect();
+    let marked = storage.mark_books_finished(&ids);
+    storage::write_storage(storage_file, storage)?;
+    match marked {
+        1 => println!("Marked 1 book as finished."),
+        n => println!("Marked {} books as finished.", n),
+    }
+    Ok(())
+}
+
 /// Interactive flow to delete a series. Prompts the user to select which series to delete.
 fn delete_series_flow(
     storage: &mut Storage,
@@ -525,7 +1956,7 @@ fn delete_series_flow(
     }
 
     let mut series_list: Vec<(&String, &storage::Series)> = storage.series.iter().collect();
-    series_list.sort_by(|a, b| a.1.name.to_lowercase().cmp(&b.1.name.to_lowercase()));
+    series_list.sort_by_key(|a| a.1.name.to_lowercase());
 
     let display_names: Vec<String> = series_list
         .iter()
@@ -605,7 +2036,7 @@ fn rename_series_flow(
     }
 
     let mut series_list: Vec<(&String, &storage::Series)> = storage.series.iter().collect();
-    series_list.sort_by(|a, b| a.1.name.to_lowercase().cmp(&b.1.name.to_lowercase()));
+    series_list.sort_by_key(|a| a.1.name.to_lowercase());
 
     let names: Vec<&str> = series_list.iter().map(|(_, s)| s.name.as_str()).collect();
 
@@ -651,9 +2082,182 @@ fn rename_series_flow(
     Ok(())
 }
 
+/// Walks through each group of similar authors (see `author::find_similar_authors`),
+/// asking which name to keep and merging the others into it.
+fn dedupe_authors_flow(
+    storage: &mut Storage,
+    storage_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let groups: Vec<Vec<(String, String)>> = bookmon::author::find_similar_authors(storage)
+        .into_iter()
+        .map(|group| {
+            group
+                .into_iter()
+                .map(|a| (a.id.clone(), a.name.clone()))
+                .collect()
+        })
+        .collect();
+    if groups.is_empty() {
+        println!("No duplicate authors found.");
+        return Ok(());
+    }
+
+    let mut merged = 0;
+    for group in groups {
+        let mut options: Vec<String> = group
+            .iter()
+            .map(|(id, name)| {
+                let count = storage
+                    .books
+                    .values()
+                    .filter(|b| &b.author_id == id)
+                    .count();
+                let books = if count == 1 { "book" } else { "books" };
+                format!("{} ({} {})", name, count, books)
+            })
+            .collect();
+        options.push("Skip \u{2014} these are different people".to_string());
+
+        let selection = match Select::new("Keep which name?", options).raw_prompt() {
+            Ok(s) => s,
+            Err(_) => {
+                println!("Operation cancelled.");
+                break;
+            }
+        };
+        let Some((canonical_id, canonical_name)) = group.get(selection.index) else {
+            continue;
+        };
+        let duplicate_ids: Vec<String> = group.iter().map(|(id, _)| id.clone()).collect();
+        match bookmon::author::merge_authors(storage, canonical_id, &duplicate_ids) {
+            Ok(moved) => {
+                merged += 1;
+                println!(
+                    "Merged into '{}', moving {} book(s).",
+                    canonical_name, moved
+                );
+            }
+            Err(e) => eprintln!("Failed to merge authors: {}", e),
+        }
+    }
+
+    if merged > 0 {
+        storage::write_storage(storage_file, storage)?;
+    }
+    Ok(())
+}
+
+/// Flow for the `edit-category` command: pick a category and set its description.
+/// An empty description clears it.
+fn edit_category_flow(
+    storage: &mut Storage,
+    storage_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if storage.categories.is_empty() {
+        println!("No categories to edit.");
+        return Ok(());
+    }
+
+    let mut categories: Vec<&storage::Category> = storage.categories.values().collect();
+    categories.sort_by_key(|c| c.name.to_lowercase());
+    let names: Vec<&str> = categories.iter().map(|c| c.name.as_str()).collect();
+
+    let selection = match Select::new("Select category:", names).raw_prompt() {
+        Ok(s) => s,
+        Err(_) => {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    };
+    let category = categories[selection.index];
+    let category_id = category.id.clone();
+    let category_name = category.name.clone();
+
+    let description = match Text::new("Description (empty to clear):")
+        .with_initial_value(category.description.as_deref().unwrap_or(""))
+        .prompt()
+    {
+        Ok(d) => d,
+        Err(_) => {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    };
+
+    match bookmon::category::set_category_description(storage, &category_id, Some(description)) {
+        Ok(_) => {
+            storage::write_storage(storage_file, storage)?;
+            match storage
+                .get_category(&category_id)
+                .and_then(|c| c.description.as_deref())
+            {
+                Some(_) => println!("Updated the description of '{}'.", category_name),
+                None => println!("Cleared the description of '{}'.", category_name),
+            }
+        }
+        Err(e) => eprintln!("Failed to update category: {}", e),
+    }
+
+    Ok(())
+}
+
+/// Asks the user for the title and author of a book whose ISBN lookup came up
+/// empty or incomplete. Returns `None` if the user leaves the title blank.
+fn prompt_missing_book_details(
+    isbn: &str,
+    partial: Option<&BookLookupDTO>,
+) -> Option<BookLookupDTO> {
+    println!("Couldn't find complete details for ISBN {}.", isbn);
+    let mut info = partial.cloned().unwrap_or(BookLookupDTO {
+        title: String::new(),
+        subtitle: None,
+        authors: vec![],
+        description: None,
+        isbn: isbn.to_string(),
+        publish_date: None,
+        publisher: None,
+        cover_url: None,
+        total_pages: None,
+        series_name: None,
+        series_position: None,
+        source: None,
+    });
+
+    let title = Text::new("Enter title (leave blank to skip):")
+        .with_default(&info.title)
+        .prompt()
+        .ok()?;
+    if title.trim().is_empty() {
+        return None;
+    }
+    info.title = title.trim().to_string();
+
+    let suggested_author = info
+        .authors
+        .first()
+        .map(|a| a.name.clone())
+        .unwrap_or_default();
+    let author = Text::new("Enter author name:")
+        .with_default(&suggested_author)
+        .prompt()
+        .ok()?;
+    if author.trim().is_empty() {
+        return None;
+    }
+    info.authors = vec![AuthorDTO {
+        name: author.trim().to_string(),
+        personal_name: None,
+        birth_date: None,
+        death_date: None,
+        bio: None,
+    }];
+
+    Some(info)
+}
+
 // Helper function for interactive mode
 fn interactive_mode(
-    storage: &Storage,
+    storage: &mut Storage,
     storage_file: &str,
     command: Option<&Commands>,
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -664,7 +2268,7 @@ fn interactive_mode(
             Commands::PrintFinished { .. } => storage.get_finished_books(),
             Commands::PrintBacklog { .. } => storage.get_unstarted_books(),
             Commands::PrintWantToRead { .. } => storage.get_want_to_read_books(),
-            Commands::PrintStatistics => storage.get_finished_books(),
+            Commands::PrintStatistics { .. } => storage.get_finished_books(),
             _ => storage.get_started_books(), // Fallback to currently reading
         },
     };
@@ -674,59 +2278,11 @@ fn interactive_mode(
         return Ok(());
     }
 
-    // Create options for book selection with status, keeping book IDs
-    let mut options: Vec<(String, String)> = Vec::new();
-    for b in &filtered_books {
-        let status = if storage.is_book_started(&b.id) {
-            "Started"
-        } else {
-            "Not Started"
-        };
-        let display = b.to_display_string(storage, status)?;
-        options.push((display, b.id.clone()));
-    }
-
-    // Sort options by:
-    // 1. Reading status (Started first)
-    // 2. Author name (from the book's author_id, not from parsing display string)
-    // 3. Book title
-    options.sort_by(|a, b| {
-        let a_started = a.0.starts_with("[Started]");
-        let b_started = b.0.starts_with("[Started]");
-
-        if a_started != b_started {
-            b_started.cmp(&a_started)
-        } else {
-            // Look up author names by book ID for robust sorting
-            let a_book = storage.books.get(&a.1);
-            let b_book = storage.books.get(&b.1);
-            let a_author = a_book
-                .map(|book| storage.author_name_for_book(book))
-                .unwrap_or("");
-            let b_author = b_book
-                .map(|book| storage.author_name_for_book(book))
-                .unwrap_or("");
-
-            if a_author != b_author {
-                a_author.cmp(b_author)
-            } else {
-                let a_title = a_book.map(|b| b.title.as_str()).unwrap_or("");
-                let b_title = b_book.map(|b| b.title.as_str()).unwrap_or("");
-                a_title.cmp(b_title)
-            }
-        }
-    });
-
-    // Build a mapping from display string → book ID
-    let display_to_id: std::collections::HashMap<String, String> = options
-        .iter()
-        .map(|(display, id)| (display.clone(), id.clone()))
-        .collect();
-
-    let display_options: Vec<String> = options.into_iter().map(|(display, _)| display).collect();
+    // Each option carries its book ID, so the selection never has to be parsed
+    let choices = book::book_choices(storage, &filtered_books)?;
 
     // Let user select a book
-    let book_selection = match Select::new("Select a book to update:", display_options).prompt() {
+    let book_selection = match Select::new("Select a book to update:", choices).prompt() {
         Ok(selection) => selection,
         Err(_) => {
             println!("Operation cancelled");
@@ -734,14 +2290,11 @@ fn interactive_mode(
         }
     };
 
-    // Find the selected book by ID (not by fragile title parsing)
-    let selected_book_id = display_to_id
-        .get(&book_selection)
-        .ok_or("Selected book not found in display mapping")?;
-
+    let selected_book_id = &book_selection.book_id;
     let selected_book = storage
         .books
         .get(selected_book_id)
+        .cloned()
         .ok_or_else(|| format!("Book with ID {} not found in storage", selected_book_id))?;
 
     // Determine available actions based on book status
@@ -775,6 +2328,7 @@ fn interactive_mode(
     if is_started && !is_finished {
         actions.push("Update progress");
         actions.push("Mark as finished");
+        actions.push("Mark as did not finish");
     }
 
     if !is_bought {
@@ -784,6 +2338,8 @@ fn interactive_mode(
     // Series assignment is always available
     if selected_book.series_id.is_some() {
         actions.push("Change series");
+        actions.push("Mark all in series as want to read");
+        actions.push("Suggest next in series");
     } else {
         actions.push("Assign to series");
     }
@@ -807,8 +2363,6 @@ fn interactive_mode(
 
     // Handle "Assign to series" / "Change series" action
     if action_selection == "Assign to series" || action_selection == "Change series" {
-        let mut storage = storage.clone();
-
         // Build series options
         let existing_series: Vec<(String, String)> = storage
             .series
@@ -823,7 +2377,7 @@ fn interactive_mode(
 
         let mut options: Vec<String> = Vec::new();
         let mut sorted_existing: Vec<&(String, String)> = existing_series.iter().collect();
-        sorted_existing.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        sorted_existing.sort_by_key(|a| a.0.to_lowercase());
         for (name, id) in &sorted_existing {
             if current_series_id.as_deref() == Some(id.as_str()) {
                 options.push(format!("{} (current)", name));
@@ -868,7 +2422,7 @@ fn interactive_mode(
                 book.series_id = None;
                 book.position_in_series = None;
             }
-            storage::write_storage(storage_file, &storage)?;
+            storage::write_storage(storage_file, storage)?;
             println!(
                 "Removed '{}' from series '{}'.",
                 book_title, old_series_name
@@ -885,7 +2439,7 @@ fn interactive_mode(
                         return Ok(());
                     }
                 };
-                bookmon::series::get_or_create_series(&mut storage, name.trim())
+                bookmon::series::get_or_create_series(storage, name.trim())
             } else {
                 existing_series
                     .iter()
@@ -907,7 +2461,7 @@ fn interactive_mode(
             // Warn if the position is already occupied by another book
             if let Some(ref pos) = position {
                 if let Some(existing_title) =
-                    bookmon::series::is_position_occupied(&storage, &series_id, pos)
+                    bookmon::series::is_position_occupied(storage, &series_id, pos)
                 {
                     // Don't warn if the book being assigned is the one already at that position
                     if storage
@@ -938,7 +2492,7 @@ fn interactive_mode(
                 book.series_id = Some(series_id);
                 book.position_in_series = position;
             }
-            storage::write_storage(storage_file, &storage)?;
+            storage::write_storage(storage_file, storage)?;
             println!(
                 "Assigned '{}' to series '{}'{}.",
                 book_title, series_name, pos_label
@@ -948,9 +2502,55 @@ fn interactive_mode(
         return Ok(());
     }
 
+    if action_selection == "Suggest next in series" {
+        let series_id = selected_book
+            .series_id
+            .clone()
+            .ok_or("Selected book has no series")?;
+        let series_name = storage
+            .get_series(&series_id)
+            .map(|s| s.name.clone())
+            .unwrap_or_default();
+        match storage.next_in_series(&series_id) {
+            Some(next) => println!(
+                "Next in '{}': {}\"{}\"",
+                series_name,
+                bookmon::series::format_position_prefix(next.position_in_series.as_deref()),
+                next.title
+            ),
+            None => println!(
+                "Nothing left to read in '{}' \u{2014} every book has been started or finished.",
+                series_name
+            ),
+        }
+        return Ok(());
+    }
+
+    if action_selection == "Mark all in series as want to read" {
+        let series_id = selected_book
+            .series_id
+            .clone()
+            .ok_or("Selected book has no series")?;
+        let series_name = storage
+            .get_series(&series_id)
+            .map(|s| s.name.clone())
+            .unwrap_or_default();
+        let marked = storage.mark_series_want_to_read(&series_id);
+        if marked > 0 {
+            storage::write_storage(storage_file, storage)?;
+        }
+        println!(
+            "Marked {} {} in '{}' as want to read.",
+            marked,
+            goal::pluralize_book(marked as u32),
+            series_name
+        );
+        return Ok(());
+    }
+
     // Handle "Write review" action separately from reading events
     if action_selection == "Write review" {
-        let author_name = storage.author_name_for_book(selected_book);
+        let author_name = storage.author_name_for_book(&selected_book);
         let author_name = if author_name.is_empty() {
             "Unknown Author"
         } else {
@@ -959,11 +2559,11 @@ fn interactive_mode(
 
         match review::get_review_text_from_editor(&selected_book.title, author_name) {
             Ok(Some(text)) => {
-                let review_obj = storage::Review::new(selected_book.id.clone(), text);
-                let mut storage = storage.clone();
-                match review::store_review(&mut storage, review_obj) {
+                let mut review_obj = storage::Review::new(selected_book.id.clone(), text);
+                review_obj.rating = prompt_rating()?;
+                match review::store_review(storage, review_obj) {
                     Ok(_) => {
-                        storage::write_storage(storage_file, &storage)?;
+                        storage::write_storage(storage_file, storage)?;
                         println!("Review saved successfully!");
                     }
                     Err(e) => eprintln!("Failed to store review: {}", e),
@@ -981,6 +2581,7 @@ fn interactive_mode(
     let event = match action_selection {
         "Start reading" => storage::ReadingEvent::Started,
         "Mark as finished" => storage::ReadingEvent::Finished,
+        "Mark as did not finish" => storage::ReadingEvent::DidNotFinish,
         "Update progress" => storage::ReadingEvent::Update,
         "Mark as bought" => storage::ReadingEvent::Bought,
         "Mark as want to read" => storage::ReadingEvent::WantToRead,
@@ -988,24 +2589,38 @@ fn interactive_mode(
         _ => unreachable!(),
     };
 
-    let reading = if event == storage::ReadingEvent::Update {
+    let current_page = if event == storage::ReadingEvent::Update {
         let current_page = Text::new("Enter current page:")
             .prompt()
             .map_err(|e| format!("Failed to get current page: {}", e))?
             .trim()
             .parse::<i32>()
             .map_err(|e| format!("Invalid page number: {}", e))?;
-
-        storage::Reading::with_metadata(selected_book.id.clone(), event, current_page)
+        Some(current_page)
     } else {
-        storage::Reading::new(selected_book.id.clone(), event)
+        None
     };
 
-    let mut storage = storage.clone();
+    if event == storage::ReadingEvent::Bought {
+        let price_input = Text::new("Price, e.g. 249 NOK (Enter to skip):")
+            .prompt()
+            .map_err(|e| format!("Failed to get price: {}", e))?;
+        if let Some((price, currency)) = reading::parse_price_input(&price_input)? {
+            let reading = storage::Reading::with_price(selected_book.id.clone(), price, currency);
+            match reading::store_reading(storage, reading) {
+                Ok(_) => {
+                    storage::write_storage(storage_file, storage)?;
+                    println!("Reading event added successfully!");
+                }
+                Err(e) => eprintln!("Failed to add reading event: {}", e),
+            }
+            return Ok(());
+        }
+    }
 
-    match reading::store_reading(&mut storage, reading) {
+    match reading::record_reading_event(storage, &selected_book.id, event, current_page) {
         Ok(_) => {
-            storage::write_storage(storage_file, &storage)?;
+            storage::write_storage(storage_file, storage)?;
             println!("Reading event added successfully!");
         }
         Err(e) => eprintln!("Failed to add reading event: {}", e),
@@ -1065,7 +2680,8 @@ fn review_book_flow(
 
     match review::get_review_text_from_editor(&book_title, author_name) {
         Ok(Some(text)) => {
-            let review_obj = storage::Review::new(book_id, text);
+            let mut review_obj = storage::Review::new(book_id, text);
+            review_obj.rating = prompt_rating()?;
             match review::store_review(storage, review_obj) {
                 Ok(_) => {
                     storage::write_storage(storage_file, storage)?;
@@ -1083,8 +2699,22 @@ fn review_book_flow(
     Ok(())
 }
 
+/// Asks for an optional 1-5 rating, re-prompting until the input is valid.
+fn prompt_rating() -> Result<Option<u8>, Box<dyn std::error::Error>> {
+    loop {
+        let input = Text::new("Rating (1-5, Enter to skip):").prompt()?;
+        match review::parse_rating_input(&input) {
+            Ok(rating) => return Ok(rating),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+}
+
 /// Interactive mode for browsing reviews: select a review to view full text, loop.
-fn review_interactive_mode(storage: &Storage) -> Result<(), Box<dyn std::error::Error>> {
+fn review_interactive_mode(
+    storage: &Storage,
+    date_format: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
     loop {
         let mut reviews: Vec<&storage::Review> = storage.reviews.values().collect();
 
@@ -1094,7 +2724,7 @@ fn review_interactive_mode(storage: &Storage) -> Result<(), Box<dyn std::error::
         }
 
         // Sort by date, newest first
-        reviews.sort_by(|a, b| b.created_on.cmp(&a.created_on));
+        reviews.sort_by_key(|r| std::cmp::Reverse(r.created_on));
 
         let mut options: Vec<(String, String)> = Vec::new();
         for r in &reviews {
@@ -1103,7 +2733,7 @@ fn review_interactive_mode(storage: &Storage) -> Result<(), Box<dyn std::error::
             let author = book
                 .map(|b| storage.author_name_for_book(b))
                 .unwrap_or("Unknown Author");
-            let date = r.created_on.format("%Y-%m-%d").to_string();
+            let date = r.created_on.format(date_format).to_string();
             let preview: String = r.text.replace('\n', " ");
             let preview = if preview.chars().count() > 40 {
                 let truncated: String = preview.chars().take(37).collect();
@@ -1133,6 +2763,6 @@ fn review_interactive_mode(storage: &Storage) -> Result<(), Box<dyn std::error::
             .get(&selection)
             .ok_or("Selected review not found")?;
 
-        review::show_review_detail(storage, review_id)?;
+        review::show_review_detail(storage, review_id, date_format)?;
     }
 }
@@ -0,0 +1,185 @@
+use crate::storage::{strip_isbn_separators, Storage};
+use std::collections::HashMap;
+
+/// Counts of what `merge_storage` added to the target library.
+#[derive(Debug, Default, PartialEq)]
+pub struct MergeSummary {
+    pub books_added: usize,
+    /// Books that matched an existing book by ISBN and were merged into it
+    pub books_merged: usize,
+    pub authors_added: usize,
+    pub categories_added: usize,
+    pub series_added: usize,
+    pub readings_added: usize,
+    pub reviews_added: usize,
+    pub quotes_added: usize,
+}
+
+impl MergeSummary {
+    /// Formats the summary for display, one count per line.
+    pub fn format(&self) -> String {
+        [
+            format!("Books added: {}", self.books_added),
+            format!("Books merged by ISBN: {}", self.books_merged),
+            format!("Authors added: {}", self.authors_added),
+            format!("Categories added: {}", self.categories_added),
+            format!("Series added: {}", self.series_added),
+            format!("Reading events added: {}", self.readings_added),
+            format!("Reviews added: {}", self.reviews_added),
+            format!("Quotes added: {}", self.quotes_added),
+        ]
+        .join("\n")
+    }
+}
+
+/// Returns the ID of the entry in `existing` whose name matches `name` case-insensitively.
+fn find_by_name<'a, I>(existing: I, name: &str) -> Option<String>
+where
+    I: IntoIterator<Item = (&'a String, &'a str)>,
+{
+    let wanted = name.trim().to_lowercase();
+    existing
+        .into_iter()
+        .find(|(_, n)| n.trim().to_lowercase() == wanted)
+        .map(|(id, _)| id.clone())
+}
+
+/// Merges `other` into `into`.
+///
+/// Authors, categories and series are matched by case-insensitive name and books
+/// by ISBN (ignoring hyphens and spaces); matches are merged into the existing
+/// entry, everything else is copied over. Readings, reviews and quotes are all kept and
+/// re-pointed to the merged book. Entries whose ID already exists in `into`
+/// (e.g. from an earlier copy of the same file) are not duplicated. Yearly and
+/// category goals are only copied where `into` has none set.
+pub fn merge_storage(into: &mut Storage, other: &Storage) -> MergeSummary {
+    let mut summary = MergeSummary::default();
+
+    let mut author_ids: HashMap<String, String> = HashMap::new();
+    for (id, author) in &other.authors {
+        let existing = find_by_name(
+            into.authors.iter().map(|(id, a)| (id, a.name.as_str())),
+            &author.name,
+        )
+        .or_else(|| into.authors.contains_key(id).then(|| id.clone()));
+        let target = existing.unwrap_or_else(|| {
+            summary.authors_added += 1;
+            into.add_author(author.clone());
+            id.clone()
+        });
+        author_ids.insert(id.clone(), target);
+    }
+
+    let mut category_ids: HashMap<String, String> = HashMap::new();
+    for (id, category) in &other.categories {
+        let existing = find_by_name(
+            into.categories.iter().map(|(id, c)| (id, c.name.as_str())),
+            &category.name,
+        )
+        .or_else(|| into.categories.contains_key(id).then(|| id.clone()));
+        let target = existing.unwrap_or_else(|| {
+            summary.categories_added += 1;
+            into.add_category(category.clone());
+            id.clone()
+        });
+        category_ids.insert(id.clone(), target);
+    }
+
+    let mut series_ids: HashMap<String, String> = HashMap::new();
+    for (id, series) in &other.series {
+        let existing = find_by_name(
+            into.series.iter().map(|(id, s)| (id, s.name.as_str())),
+            &series.name,
+        )
+        .or_else(|| into.series.contains_key(id).then(|| id.clone()));
+        let target = existing.unwrap_or_else(|| {
+            summary.series_added += 1;
+            into.add_series(series.clone());
+            id.clone()
+        });
+        series_ids.insert(id.clone(), target);
+    }
+
+    let mut book_ids: HashMap<String, String> = HashMap::new();
+    for (id, book) in &other.books {
+        let isbn = strip_isbn_separators(&book.isbn);
+        let existing = into
+            .books
+            .values()
+            .find(|b| !isbn.is_empty() && strip_isbn_separators(&b.isbn) == isbn)
+            .map(|b| b.id.clone())
+            .or_else(|| into.books.contains_key(id).then(|| id.clone()));
+
+        let target = match existing {
+            Some(existing_id) => {
+                summary.books_merged += 1;
+                existing_id
+            }
+            None => {
+                let mut book = book.clone();
+                if let Some(author_id) = author_ids.get(&book.author_id) {
+                    book.author_id = author_id.clone();
+                }
+                if let Some(category_id) = category_ids.get(&book.category_id) {
+                    book.category_id = category_id.clone();
+                }
+                book.series_id = book
+                    .series_id
+                    .map(|sid| series_ids.get(&sid).cloned().unwrap_or(sid));
+                summary.books_added += 1;
+                into.add_book(book);
+                id.clone()
+            }
+        };
+        book_ids.insert(id.clone(), target);
+    }
+
+    for (id, reading) in &other.readings {
+        if into.readings.contains_key(id) {
+            continue;
+        }
+        let mut reading = reading.clone();
+        if let Some(book_id) = book_ids.get(&reading.book_id) {
+            reading.book_id = book_id.clone();
+        }
+        summary.readings_added += 1;
+        into.add_reading(reading);
+    }
+
+    for (id, review) in &other.reviews {
+        if into.reviews.contains_key(id) {
+            continue;
+        }
+        let mut review = review.clone();
+        if let Some(book_id) = book_ids.get(&review.book_id) {
+            review.book_id = book_id.clone();
+        }
+        summary.reviews_added += 1;
+        into.add_review(review);
+    }
+
+    for (id, quote) in &other.quotes {
+        if into.quotes.contains_key(id) {
+            continue;
+        }
+        let mut quote = quote.clone();
+        if let Some(book_id) = book_ids.get(&quote.book_id) {
+            quote.book_id = book_id.clone();
+        }
+        summary.quotes_added += 1;
+        into.add_quote(quote);
+    }
+
+    for (year, target) in &other.goals {
+        into.goals.entry(*year).or_insert(*target);
+    }
+
+    for (year, targets) in &other.category_goals {
+        let into_targets = into.category_goals.entry(*year).or_default();
+        for (category, target) in targets {
+            into_targets.entry(category.clone()).or_insert(*target);
+        }
+    }
+
+    summary
+}
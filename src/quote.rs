@@ -0,0 +1,25 @@
+use crate::storage::{Quote, Storage, StorageError};
+
+/// Validates and stores a quote. Returns an error if the referenced book doesn't exist.
+pub fn store_quote(storage: &mut Storage, quote: Quote) -> Result<(), StorageError> {
+    if !storage.books.contains_key(&quote.book_id) {
+        return Err(StorageError::MissingBook(quote.book_id));
+    }
+
+    storage.add_quote(quote);
+    Ok(())
+}
+
+/// Formats the quotes from a book for display, one per line in page order, e.g.
+/// `p. 42: "All we have to decide is what to do with the time that is given us."`.
+/// Quotes without a page are shown without the page prefix.
+pub fn format_quotes(storage: &Storage, book_id: &str) -> Vec<String> {
+    storage
+        .get_quotes_for_book(book_id)
+        .into_iter()
+        .map(|quote| match quote.page {
+            Some(page) => format!("p. {}: \"{}\"", page, quote.text),
+            None => format!("\"{}\"", quote.text),
+        })
+        .collect()
+}
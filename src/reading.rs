@@ -1,7 +1,14 @@
-use crate::series::format_position_prefix;
-use crate::storage::{compare_positions, Book, Reading, ReadingEvent, Storage};
-use crate::table::{print_structured_table, print_table, Alignment, TableRow};
-use chrono::Utc;
+use crate::clock::{Clock, SystemClock};
+use crate::config::{StatusSymbols, DEFAULT_DATE_FORMAT};
+use crate::series::{format_position_prefix, status_symbol};
+use crate::storage::{compare_positions, Book, Reading, ReadingEvent, Storage, StorageError};
+use crate::table::{
+    format_structured_table_fitted, reverse_table_rows, terminal_width, Alignment, TableOutput,
+    TableRow, TableStyle,
+};
+use crate::time::Timezone;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::BTreeMap;
 use std::io;
 
 /// A book entry in a sorted, grouped list — either a standalone book
@@ -91,22 +98,282 @@ pub fn group_books_by_series<'a>(storage: &'a Storage, books: &[&'a Book]) -> Ve
     entries.into_iter().map(|(_, _, _, entry)| entry).collect()
 }
 
+/// Returns the page of `items` that starts at `offset` and holds at most `limit`
+/// items (all remaining items when `limit` is `None`). Empty when `offset` is past the end.
+pub fn paginate<T>(items: Vec<T>, offset: usize, limit: Option<usize>) -> Vec<T> {
+    items
+        .into_iter()
+        .skip(offset)
+        .take(limit.unwrap_or(usize::MAX))
+        .collect()
+}
+
+/// How the finished view orders its books.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FinishedOrder {
+    /// Grouped by series, otherwise by author then title
+    #[default]
+    AuthorTitle,
+    /// By finish date, earliest first
+    OldestFirst,
+    /// By finish date, latest first
+    NewestFirst,
+}
+
+/// Returns when a book was last finished, if ever.
+fn last_finished_on(storage: &Storage, book: &Book) -> Option<DateTime<Utc>> {
+    storage
+        .readings
+        .values()
+        .filter(|r| r.book_id == book.id && r.event == ReadingEvent::Finished)
+        .map(|r| r.created_on)
+        .max()
+}
+
+/// Sorts finished books into the order the finished view displays them: grouped by
+/// series (see `group_books_by_series`) when any book has one, otherwise by author
+/// then title. Used to paginate before building the table.
+pub fn sort_finished_books<'a>(storage: &'a Storage, books: Vec<&'a Book>) -> Vec<&'a Book> {
+    sort_finished_books_by(storage, books, FinishedOrder::AuthorTitle)
+}
+
+/// Like `sort_finished_books`, but in the given order. The chronological orders
+/// sort by each book's most recent `Finished` event, ties broken by title, and
+/// don't group series.
+pub fn sort_finished_books_by<'a>(
+    storage: &'a Storage,
+    books: Vec<&'a Book>,
+    order: FinishedOrder,
+) -> Vec<&'a Book> {
+    if order != FinishedOrder::AuthorTitle {
+        let mut sorted_books = books;
+        sorted_books.sort_by(|a, b| {
+            let by_date = last_finished_on(storage, a).cmp(&last_finished_on(storage, b));
+            let by_date = if order == FinishedOrder::NewestFirst {
+                by_date.reverse()
+            } else {
+                by_date
+            };
+            by_date.then_with(|| a.title.cmp(&b.title))
+        });
+        sorted_books
+    } else if books.iter().any(|b| b.series_id.is_some()) {
+        group_books_by_series(storage, &books)
+            .into_iter()
+            .flat_map(|entry| match entry {
+                BookEntry::SeriesGroup { books, .. } => books,
+                BookEntry::Standalone(book) => vec![book],
+            })
+            .collect()
+    } else {
+        let mut sorted_books = books;
+        sorted_books.sort_by(|a, b| {
+            let a_author = storage.author_name_for_book(a);
+            let b_author = storage.author_name_for_book(b);
+            a_author.cmp(b_author).then(a.title.cmp(&b.title))
+        });
+        sorted_books
+    }
+}
+
 /// Validates and stores a reading event. Returns an error if the referenced book doesn't exist.
-pub fn store_reading(storage: &mut Storage, reading: Reading) -> Result<(), String> {
+pub fn store_reading(storage: &mut Storage, reading: Reading) -> Result<(), StorageError> {
     // Validate that the book exists
     if !storage.books.contains_key(&reading.book_id) {
-        return Err(format!("Book with ID {} does not exist", reading.book_id));
+        return Err(StorageError::MissingBook(reading.book_id));
     }
 
     storage.add_reading(reading);
     Ok(())
 }
 
+/// Records a reading event for a book directly on the given storage.
+///
+/// `current_page` is stored as metadata when given (used for `Update` events).
+/// Returns an error if the book doesn't exist.
+pub fn record_reading_event(
+    storage: &mut Storage,
+    book_id: &str,
+    event: ReadingEvent,
+    current_page: Option<i32>,
+) -> Result<(), String> {
+    let reading = match current_page {
+        Some(page) => Reading::with_metadata(book_id.to_string(), event, page),
+        None => Reading::new(book_id.to_string(), event),
+    };
+    store_reading(storage, reading).map_err(String::from)
+}
+
+/// Records an `Update` reading with the given page for the book with the given ISBN.
+///
+/// Returns an error if no book has that ISBN, if the page is negative, or if the
+/// page exceeds the book's `total_pages` (when known).
+pub fn update_progress_by_isbn(storage: &mut Storage, isbn: &str, page: i32) -> Result<(), String> {
+    let book = storage
+        .get_book_by_isbn(isbn)
+        .ok_or_else(|| format!("No book found with ISBN {}", isbn))?;
+    let book_id = book.id.clone();
+    record_progress(storage, &book_id, page)
+}
+
+/// Like `update_progress_by_isbn`, but finds the book with `Storage::resolve_book_ref`,
+/// so an ID prefix or title works as well as an ISBN.
+pub fn update_progress_by_ref(
+    storage: &mut Storage,
+    reference: &str,
+    page: i32,
+) -> Result<(), String> {
+    let book_id = storage.resolve_book_ref(reference)?.id.clone();
+    record_progress(storage, &book_id, page)
+}
+
+/// Validates `page` against the book and records an `Update` reading for it.
+fn record_progress(storage: &mut Storage, book_id: &str, page: i32) -> Result<(), String> {
+    let book = storage
+        .get_book(book_id)
+        .ok_or_else(|| format!("No book found with ID {}", book_id))?;
+
+    if page < 0 {
+        return Err(format!("Page {} is not a valid page number", page));
+    }
+    if book.total_pages > 0 && page > book.total_pages {
+        return Err(format!(
+            "Page {} exceeds total pages ({}) for '{}'",
+            page, book.total_pages, book.title
+        ));
+    }
+
+    let reading = Reading::with_metadata(book.id.clone(), ReadingEvent::Update, page);
+    store_reading(storage, reading).map_err(String::from)
+}
+
+/// Parses a price entered when marking a book as bought, e.g. `249`, `12.50 USD`
+/// or `99,90 nok`. Blank input means no price. The currency is upper-cased.
+pub fn parse_price_input(input: &str) -> Result<Option<(f64, Option<String>)>, String> {
+    let mut parts = input.split_whitespace();
+    let Some(amount) = parts.next() else {
+        return Ok(None);
+    };
+    let currency = parts.next().map(|c| c.to_uppercase());
+    let price = match amount.replace(',', ".").parse::<f64>() {
+        Ok(price) if price >= 0.0 && price.is_finite() && parts.next().is_none() => price,
+        _ => {
+            return Err(format!(
+                "Invalid price '{}': expected an amount and an optional currency, e.g. 249 NOK",
+                input.trim()
+            ))
+        }
+    };
+    Ok(Some((price, currency)))
+}
+
+/// Formats per-currency spending as e.g. `249.00 NOK, 12.50 USD`. Amounts
+/// without a currency are shown as just the number.
+pub fn format_spending(spending: &BTreeMap<String, f64>) -> String {
+    spending
+        .iter()
+        .map(|(currency, amount)| {
+            if currency.is_empty() {
+                format!("{:.2}", amount)
+            } else {
+                format!("{:.2} {}", amount, currency)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Parses a `YYYY-MM-DD` date used as a `--after`/`--before` bound into the
+/// start of that day (UTC).
+pub fn parse_date_bound(input: &str) -> Result<DateTime<Utc>, String> {
+    let date = NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date '{}': expected YYYY-MM-DD", input))?;
+    Ok(date
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is always a valid time")
+        .and_utc())
+}
+
+/// Returns up to `limit` reading events starting at `offset`, newest first, optionally
+/// restricted to events on or after `after`, strictly before `before` (both `YYYY-MM-DD`)
+/// and of a single event type.
+pub fn filter_readings<'a>(
+    storage: &'a Storage,
+    after: Option<&str>,
+    before: Option<&str>,
+    event: Option<&str>,
+    offset: usize,
+    limit: usize,
+) -> Result<Vec<&'a Reading>, String> {
+    let from = after.map(parse_date_bound).transpose()?;
+    let to = before.map(parse_date_bound).transpose()?;
+    if let (Some(from), Some(to)) = (from, to) {
+        if from >= to {
+            return Err("--after must be earlier than --before".to_string());
+        }
+    }
+    let event = event.map(str::parse::<ReadingEvent>).transpose()?;
+
+    let readings: Vec<&Reading> = storage
+        .readings_in_period(from, to)
+        .into_iter()
+        .filter(|r| event.is_none_or(|e| r.event == e))
+        .collect();
+    Ok(paginate(readings, offset, Some(limit)))
+}
+
+/// Formats a reading event as a single feed line: "date — event — title by author",
+/// with the date in `date_format`.
+///
+/// Update events include the recorded page. Books or authors that no longer exist
+/// are shown as "Unknown Book" / "Unknown Author".
+pub fn format_reading_event_line(
+    storage: &Storage,
+    reading: &Reading,
+    date_format: &str,
+) -> String {
+    let book = storage.get_book(&reading.book_id);
+    let title = book.map(|b| b.title.as_str()).unwrap_or("Unknown Book");
+    let author = book
+        .map(|b| storage.author_name_for_book(b))
+        .filter(|name| !name.is_empty())
+        .unwrap_or("Unknown Author");
+
+    format!(
+        "{} \u{2014} {} \u{2014} {} by {}",
+        reading.created_on.format(date_format),
+        format_event_label(reading),
+        title,
+        author
+    )
+}
+
+/// Returns the event's label, with the recorded page for update events,
+/// e.g. "Progress update (page 120)".
+pub fn format_event_label(reading: &Reading) -> String {
+    match (reading.event, reading.metadata.current_page) {
+        (ReadingEvent::Update, Some(page)) => format!("{} (page {})", reading.event.label(), page),
+        _ => reading.event.label().to_string(),
+    }
+}
+
 /// Builds the structured table data for currently-reading books.
 ///
 /// Returns `Vec<TableRow>` with series grouping when any book has a series,
 /// or a flat table when no books have series. Returns empty vec if no started books.
 pub fn build_started_books_table(storage: &Storage) -> io::Result<Vec<TableRow>> {
+    build_started_books_table_with_clock(storage, &SystemClock, DEFAULT_DATE_FORMAT, false)
+}
+
+/// Like `build_started_books_table`, but "days since started" and projected finish
+/// dates are measured from `clock.now()`, with finish dates in `date_format`.
+/// Titles include the subtitle only when `full_titles` is set.
+pub fn build_started_books_table_with_clock(
+    storage: &Storage,
+    clock: &dyn Clock,
+    date_format: &str,
+    full_titles: bool,
+) -> io::Result<Vec<TableRow>> {
     let started_books = storage.get_started_books();
 
     if started_books.is_empty() {
@@ -120,6 +387,7 @@ pub fn build_started_books_table(storage: &Storage) -> io::Result<Vec<TableRow>>
         "Author".to_string(),
         "Days since started".to_string(),
         "Progress".to_string(),
+        "Est. finish".to_string(),
     ];
 
     let mut table_rows = vec![TableRow::Header(header)];
@@ -135,14 +403,20 @@ pub fn build_started_books_table(storage: &Storage) -> io::Result<Vec<TableRow>>
                         let title = format!(
                             "  {}{}",
                             format_position_prefix(book.position_in_series.as_deref()),
-                            book.title
+                            book.display_title(full_titles)
                         );
-                        let row = build_started_book_row(storage, book, title)?;
+                        let row = build_started_book_row(storage, book, title, clock, date_format)?;
                         table_rows.push(TableRow::Data(row));
                     }
                 }
                 BookEntry::Standalone(book) => {
-                    let row = build_started_book_row(storage, book, book.title.clone())?;
+                    let row = build_started_book_row(
+                        storage,
+                        book,
+                        book.display_title(full_titles),
+                        clock,
+                        date_format,
+                    )?;
                     table_rows.push(TableRow::Data(row));
                 }
             }
@@ -156,7 +430,13 @@ pub fn build_started_books_table(storage: &Storage) -> io::Result<Vec<TableRow>>
         });
 
         for book in sorted_books {
-            let row = build_started_book_row(storage, book, book.title.clone())?;
+            let row = build_started_book_row(
+                storage,
+                book,
+                book.display_title(full_titles),
+                clock,
+                date_format,
+            )?;
             table_rows.push(TableRow::Data(row));
         }
     }
@@ -169,23 +449,23 @@ fn build_started_book_row(
     storage: &Storage,
     book: &Book,
     title: String,
+    clock: &dyn Clock,
+    date_format: &str,
 ) -> io::Result<Vec<String>> {
     let author_name = storage.author_name_for_book(book);
 
-    let most_recent_reading = storage
-        .readings
-        .values()
-        .filter(|r| r.book_id == book.id && r.event == ReadingEvent::Started)
-        .max_by_key(|r| r.created_on)
-        .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Reading not found"))?;
+    let started_on = storage
+        .started_on(&book.id)
+        .ok_or_else(|| io::Error::other("Reading not found"))?;
 
-    let days = (Utc::now() - most_recent_reading.created_on).num_days();
+    let now = clock.now();
+    let days = (now - started_on).num_days();
 
     let most_recent_update = storage
         .readings
         .values()
         .filter(|r| r.book_id == book.id && r.event == ReadingEvent::Update)
-        .max_by_key(|r| r.created_on);
+        .max_by(|a, b| a.cmp_chronological(b));
 
     let progress = if let Some(update) = most_recent_update {
         if let Some(current_page) = update.metadata.current_page {
@@ -204,37 +484,160 @@ fn build_started_book_row(
         "".to_string()
     };
 
+    let projected_finish = storage
+        .projected_finish_date(&book.id, now)
+        .map(|date| date.format(date_format).to_string())
+        .unwrap_or_default();
+
     Ok(vec![
         title,
         author_name.to_string(),
         days.to_string(),
         progress,
+        projected_finish,
     ])
 }
 
-/// Displays a table of currently-reading books with author, days since started, and progress.
+/// Displays a table of currently-reading books with author, days since started, progress,
+/// and projected finish date.
 pub fn show_started_books(storage: &Storage) -> io::Result<()> {
-    let table_rows = build_started_books_table(storage)?;
+    let mut stdout = io::stdout();
+    let mut out = TableOutput::new(&mut stdout, TableStyle::Boxed).with_max_width(terminal_width());
+    show_started_books_with_clock(&mut out, storage, &SystemClock)
+}
+
+/// Like `show_started_books`, writing to `out` and measuring time from `clock.now()`.
+pub fn show_started_books_with_clock(
+    out: &mut TableOutput,
+    storage: &Storage,
+    clock: &dyn Clock,
+) -> io::Result<()> {
+    let table =
+        format_started_books_with_clock(storage, clock, out.date_format, false, out.max_width)?;
+    write!(out.writer, "{}", table)
+}
+
+/// Formats the currently-reading table shown by `show_started_books_with_clock`,
+/// fitted to `max_width` columns when given. Titles include the subtitle only
+/// when `full_titles` is set.
+pub fn format_started_books_with_clock(
+    storage: &Storage,
+    clock: &dyn Clock,
+    date_format: &str,
+    full_titles: bool,
+    max_width: Option<usize>,
+) -> io::Result<String> {
+    let table_rows =
+        build_started_books_table_with_clock(storage, clock, date_format, full_titles)?;
     if table_rows.is_empty() {
-        println!("No books currently being read.");
-    } else {
-        let alignments = [
-            Alignment::Left,  // Title
-            Alignment::Left,  // Author
-            Alignment::Right, // Days since started
-            Alignment::Right, // Progress
-        ];
-        print_structured_table(&table_rows, &alignments);
+        return Ok("No books currently being read.\n".to_string());
     }
-    Ok(())
+    let alignments = [
+        Alignment::Left,  // Title
+        Alignment::Left,  // Author
+        Alignment::Right, // Days since started
+        Alignment::Right, // Progress
+        Alignment::Right, // Est. finish
+    ];
+    Ok(format_structured_table_fitted(
+        &table_rows,
+        &alignments,
+        max_width,
+    ))
+}
+
+/// Formats the view shown when bookmon runs without a command: the
+/// currently-reading table, then reminders about stale books and the goal
+/// footer. Titles include the subtitle only when `full_titles` is set, and
+/// `quiet` leaves out the reminders and the footer.
+pub fn format_default_view(
+    storage: &Storage,
+    tz: &Timezone,
+    clock: &dyn Clock,
+    date_format: &str,
+    full_titles: bool,
+    stale_days: u32,
+    quiet: bool,
+) -> io::Result<String> {
+    let mut view = format_started_books_with_clock(
+        storage,
+        clock,
+        date_format,
+        full_titles,
+        terminal_width(),
+    )?;
+    if !quiet {
+        let warnings = stale_reading_warnings(storage, stale_days, clock);
+        if !warnings.is_empty() {
+            view.push_str(&format!("\n{}\n", warnings.join("\n")));
+        }
+        if let Some(footer) = crate::goal::format_goal_footer(storage, tz, clock.now()) {
+            view.push_str(&format!("\n{}\n", footer));
+        }
+    }
+    Ok(view)
+}
+
+/// Loads the storage file again and formats the default view from it, so
+/// `--watch` picks up changes made by other bookmon processes.
+pub fn reload_and_render(
+    storage_file: &str,
+    tz: &Timezone,
+    clock: &dyn Clock,
+    date_format: &str,
+    full_titles: bool,
+    stale_days: u32,
+    quiet: bool,
+) -> Result<String, String> {
+    let storage = crate::storage::load_storage(storage_file)
+        .map_err(|e| format!("Failed to load {}: {}", storage_file, e))?;
+    format_default_view(
+        &storage,
+        tz,
+        clock,
+        date_format,
+        full_titles,
+        stale_days,
+        quiet,
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// Returns a gentle reminder for each book being read that has had no activity
+/// for more than `stale_days` days, measured from `clock.now()`. A `stale_days`
+/// of 0 turns the reminders off.
+pub fn stale_reading_warnings(
+    storage: &Storage,
+    stale_days: u32,
+    clock: &dyn Clock,
+) -> Vec<String> {
+    if stale_days == 0 {
+        return Vec::new();
+    }
+    storage
+        .stalled_started_books(stale_days, clock.now())
+        .into_iter()
+        .map(|(book, days)| {
+            format!(
+                "You haven't touched \"{}\" in {} days. Still reading it?",
+                book.title, days
+            )
+        })
+        .collect()
 }
 
 /// Displays a table of finished books with author and finish date.
 pub fn show_finished_books(storage: &Storage) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    let mut out = TableOutput::new(&mut stdout, TableStyle::Boxed).with_max_width(terminal_width());
     show_finished_books_list(
+        &mut out,
         storage,
         storage.get_finished_books(),
         "No finished books found.",
+        false,
+        FinishedOrder::AuthorTitle,
+        false,
     )
 }
 
@@ -242,18 +645,25 @@ pub fn show_finished_books(storage: &Storage) -> io::Result<()> {
 ///
 /// When books belong to series, they are grouped under a series header row
 /// with no separators between books in the same group. The Series column is
-/// replaced by position prefixes (e.g. `#1`) on the book title.
+/// replaced by position prefixes (e.g. `#1`) on the book title. Titles include
+/// the subtitle only when `full_titles` is set. With a chronological `order`,
+/// books are listed by finish date in a flat table instead. The table is written
+/// to `out` in its style, with the rows in the opposite order when `reverse` is set.
 pub fn show_finished_books_list(
+    out: &mut TableOutput,
     storage: &Storage,
     finished_books: Vec<&Book>,
     empty_message: &str,
+    full_titles: bool,
+    order: FinishedOrder,
+    reverse: bool,
 ) -> io::Result<()> {
     if finished_books.is_empty() {
-        println!("{}", empty_message);
-        return Ok(());
+        return out.write_line(empty_message);
     }
 
-    let any_has_series = finished_books.iter().any(|b| b.series_id.is_some());
+    let any_has_series =
+        order == FinishedOrder::AuthorTitle && finished_books.iter().any(|b| b.series_id.is_some());
 
     if any_has_series {
         let entries = group_books_by_series(storage, &finished_books);
@@ -272,10 +682,10 @@ pub fn show_finished_books_list(
                         let title = format!(
                             "  {}{}",
                             format_position_prefix(book.position_in_series.as_deref()),
-                            book.title
+                            book.display_title(full_titles)
                         );
                         let author_name = storage.author_name_for_book(book);
-                        let finished_date = finished_date_for_book(storage, book)?;
+                        let finished_date = finished_date_for_book(storage, book, out.date_format)?;
                         table_rows.push(TableRow::Data(vec![
                             title,
                             author_name.to_string(),
@@ -285,9 +695,9 @@ pub fn show_finished_books_list(
                 }
                 BookEntry::Standalone(book) => {
                     let author_name = storage.author_name_for_book(book);
-                    let finished_date = finished_date_for_book(storage, book)?;
+                    let finished_date = finished_date_for_book(storage, book, out.date_format)?;
                     table_rows.push(TableRow::Data(vec![
-                        book.title.clone(),
+                        book.display_title(full_titles),
                         author_name.to_string(),
                         finished_date,
                     ]));
@@ -300,15 +710,18 @@ pub fn show_finished_books_list(
             Alignment::Left,  // Author
             Alignment::Right, // Finished on
         ];
-        print_structured_table(&table_rows, &alignments);
+        let table_rows = if reverse {
+            reverse_table_rows(table_rows)
+        } else {
+            table_rows
+        };
+        out.write_structured_table(&table_rows, &alignments)
     } else {
         // No series — use the flat table
-        let mut sorted_books = finished_books;
-        sorted_books.sort_by(|a, b| {
-            let a_author = storage.author_name_for_book(a);
-            let b_author = storage.author_name_for_book(b);
-            a_author.cmp(b_author).then(a.title.cmp(&b.title))
-        });
+        let mut sorted_books = sort_finished_books_by(storage, finished_books, order);
+        if reverse {
+            sorted_books.reverse();
+        }
 
         let header = vec![
             "Title".to_string(),
@@ -319,9 +732,9 @@ pub fn show_finished_books_list(
 
         for book in sorted_books {
             let author_name = storage.author_name_for_book(book);
-            let finished_date = finished_date_for_book(storage, book)?;
+            let finished_date = finished_date_for_book(storage, book, out.date_format)?;
             table_data.push(vec![
-                book.title.clone(),
+                book.display_title(full_titles),
                 author_name.to_string(),
                 finished_date,
             ]);
@@ -332,37 +745,134 @@ pub fn show_finished_books_list(
             Alignment::Left,  // Author
             Alignment::Right, // Finished on
         ];
-        print_table(&table_data, &alignments);
+        out.write_table(&table_data, &alignments)
     }
-    Ok(())
 }
 
-/// Returns the formatted finish date for a book (most recent Finished event).
-fn finished_date_for_book(storage: &Storage, book: &Book) -> io::Result<String> {
+/// Builds the finished-books table grouped by the month (in `tz`) each book was
+/// last finished, with a `GroupHeader` such as "2025-04" per month.
+///
+/// Books are listed by finish date, earliest first unless `order` is
+/// `NewestFirst`. Columns: Title, Author, Finished on (in `date_format`).
+/// Returns an empty vec if there are no books.
+pub fn build_finished_by_month_table(
+    storage: &Storage,
+    books: Vec<&Book>,
+    full_titles: bool,
+    order: FinishedOrder,
+    tz: &Timezone,
+    date_format: &str,
+) -> Vec<TableRow> {
+    if books.is_empty() {
+        return vec![];
+    }
+    let order = match order {
+        FinishedOrder::NewestFirst => FinishedOrder::NewestFirst,
+        _ => FinishedOrder::OldestFirst,
+    };
+
+    // Consecutive books in chronological order share a month group
+    let mut groups: Vec<(String, Vec<Vec<String>>)> = Vec::new();
+    for book in sort_finished_books_by(storage, books, order) {
+        let finished_on = last_finished_on(storage, book).map(|t| tz.local_date(&t));
+        let month = finished_on
+            .map(|d| d.format("%Y-%m").to_string())
+            .unwrap_or_default();
+        let row = vec![
+            book.display_title(full_titles),
+            storage.author_name_for_book(book).to_string(),
+            finished_on
+                .map(|d| d.format(date_format).to_string())
+                .unwrap_or_default(),
+        ];
+        match groups.last_mut() {
+            Some((last_month, rows)) if *last_month == month => rows.push(row),
+            _ => groups.push((month, vec![row])),
+        }
+    }
+
+    let mut table_rows = vec![TableRow::Header(vec![
+        "Title".to_string(),
+        "Author".to_string(),
+        "Finished on".to_string(),
+    ])];
+    for (month, rows) in groups {
+        table_rows.push(TableRow::GroupHeader(month, rows.len()));
+        table_rows.extend(rows.into_iter().map(TableRow::Data));
+    }
+    table_rows
+}
+
+/// Displays finished books grouped under month headers (see
+/// `build_finished_by_month_table`), writing them to `out`.
+pub fn show_finished_books_by_month(
+    out: &mut TableOutput,
+    storage: &Storage,
+    books: Vec<&Book>,
+    empty_message: &str,
+    full_titles: bool,
+    order: FinishedOrder,
+    tz: &Timezone,
+) -> io::Result<()> {
+    let table_rows =
+        build_finished_by_month_table(storage, books, full_titles, order, tz, out.date_format);
+    if table_rows.is_empty() {
+        return out.write_line(empty_message);
+    }
+    let alignments = [
+        Alignment::Left,  // Title
+        Alignment::Left,  // Author
+        Alignment::Right, // Finished on
+    ];
+    out.write_structured_table(&table_rows, &alignments)
+}
+
+/// Returns the finish date of a book (most recent Finished event) in `date_format`.
+fn finished_date_for_book(storage: &Storage, book: &Book, date_format: &str) -> io::Result<String> {
     let most_recent_reading = storage
         .readings
         .values()
         .filter(|r| r.book_id == book.id && r.event == ReadingEvent::Finished)
-        .max_by_key(|r| r.created_on)
-        .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Reading not found"))?;
+        .max_by(|a, b| a.cmp_chronological(b))
+        .ok_or_else(|| io::Error::other("Reading not found"))?;
     Ok(most_recent_reading
         .created_on
-        .format("%Y-%m-%d")
+        .format(date_format)
         .to_string())
 }
 
-/// Prints a table of books with common columns (Title, Author, Category, Added on, Bought, Want to read).
+/// Builds the structured table data for a list of books (backlog / want-to-read).
 ///
-/// When books belong to series, they are grouped under series header rows
-/// with position prefixes on titles. The Series column is omitted.
-pub fn print_book_list_table(
+/// Columns: Title, Author, Category, Added on, Bought, Want to read. When any book
+/// belongs to a series, books are grouped under series header rows with position
+/// prefixes on titles, and a "Series progress" column shows how much of each book's
+/// series has been read (blank for standalone books). Titles include the subtitle
+/// only when `full_titles` is set, and "Added on" dates are in `date_format`.
+/// Returns empty vec if no books.
+pub fn build_book_list_table(
     storage: &Storage,
     books: Vec<&Book>,
-    empty_message: &str,
-) -> io::Result<()> {
+    full_titles: bool,
+    date_format: &str,
+) -> io::Result<Vec<TableRow>> {
+    Ok(
+        build_book_list_rows(storage, books, full_titles, date_format)?
+            .into_iter()
+            .map(|(row, _)| row)
+            .collect(),
+    )
+}
+
+/// Builds the rows of `build_book_list_table`, each data row paired with the
+/// book it shows.
+fn build_book_list_rows<'a>(
+    storage: &'a Storage,
+    books: Vec<&'a Book>,
+    full_titles: bool,
+    date_format: &str,
+) -> io::Result<Vec<(TableRow, Option<&'a Book>)>> {
     if books.is_empty() {
-        println!("{}", empty_message);
-        return Ok(());
+        return Ok(vec![]);
     }
 
     let any_has_series = books.iter().any(|b| b.series_id.is_some());
@@ -373,7 +883,7 @@ pub fn print_book_list_table(
         .map(|b| b.id.as_str())
         .collect();
 
-    let header = vec![
+    let mut header = vec![
         "Title".to_string(),
         "Author".to_string(),
         "Category".to_string(),
@@ -381,42 +891,45 @@ pub fn print_book_list_table(
         "Bought".to_string(),
         "Want to read".to_string(),
     ];
+    if any_has_series {
+        header.push("Series progress".to_string());
+    }
+
+    let mut table_rows = vec![(TableRow::Header(header), None)];
 
     if any_has_series {
         let entries = group_books_by_series(storage, &books);
-        let mut table_rows = vec![TableRow::Header(header)];
 
         for entry in &entries {
             match entry {
                 BookEntry::SeriesGroup { name, books } => {
-                    table_rows.push(TableRow::GroupHeader(name.clone(), books.len()));
+                    table_rows.push((TableRow::GroupHeader(name.clone(), books.len()), None));
                     for book in books {
                         let title = format!(
                             "  {}{}",
                             format_position_prefix(book.position_in_series.as_deref()),
-                            book.title
+                            book.display_title(full_titles)
                         );
-                        let row = build_book_list_row(storage, book, title, &want_to_read_ids)?;
-                        table_rows.push(TableRow::Data(row));
+                        let mut row = build_book_list_row(
+                            storage,
+                            book,
+                            title,
+                            date_format,
+                            &want_to_read_ids,
+                        )?;
+                        row.push(format_series_progress_cell(storage, book));
+                        table_rows.push((TableRow::Data(row), Some(*book)));
                     }
                 }
                 BookEntry::Standalone(book) => {
-                    let row =
-                        build_book_list_row(storage, book, book.title.clone(), &want_to_read_ids)?;
-                    table_rows.push(TableRow::Data(row));
+                    let title = book.display_title(full_titles);
+                    let mut row =
+                        build_book_list_row(storage, book, title, date_format, &want_to_read_ids)?;
+                    row.push(format_series_progress_cell(storage, book));
+                    table_rows.push((TableRow::Data(row), Some(*book)));
                 }
             }
         }
-
-        let alignments = [
-            Alignment::Left,   // Title
-            Alignment::Left,   // Author
-            Alignment::Left,   // Category
-            Alignment::Right,  // Added on
-            Alignment::Center, // Bought
-            Alignment::Center, // Want to read
-        ];
-        print_structured_table(&table_rows, &alignments);
     } else {
         let mut sorted_books = books;
         sorted_books.sort_by(|a, b| {
@@ -425,24 +938,147 @@ pub fn print_book_list_table(
             a_author.cmp(b_author).then(a.title.cmp(&b.title))
         });
 
-        let mut table_data = vec![header];
-
         for book in sorted_books {
-            let row = build_book_list_row(storage, book, book.title.clone(), &want_to_read_ids)?;
-            table_data.push(row);
+            let title = book.display_title(full_titles);
+            let row = build_book_list_row(storage, book, title, date_format, &want_to_read_ids)?;
+            table_rows.push((TableRow::Data(row), Some(book)));
         }
+    }
 
-        let alignments = [
-            Alignment::Left,   // Title
-            Alignment::Left,   // Author
-            Alignment::Left,   // Category
-            Alignment::Right,  // Added on
-            Alignment::Center, // Bought
-            Alignment::Center, // Want to read
-        ];
-        print_table(&table_data, &alignments);
+    Ok(table_rows)
+}
+
+/// Prints a table of books with common columns (Title, Author, Category, Added on, Bought, Want to read).
+///
+/// When books belong to series, they are grouped under series header rows
+/// with position prefixes on titles, plus a "Series progress" column.
+/// The table is written to `out`.
+pub fn print_book_list_table(
+    out: &mut TableOutput,
+    storage: &Storage,
+    books: Vec<&Book>,
+    empty_message: &str,
+    full_titles: bool,
+    reverse: bool,
+) -> io::Result<()> {
+    let mut table_rows = build_book_list_table(storage, books, full_titles, out.date_format)?;
+    if table_rows.is_empty() {
+        return out.write_line(empty_message);
     }
-    Ok(())
+    if reverse {
+        table_rows = reverse_table_rows(table_rows);
+    }
+
+    let alignments = [
+        Alignment::Left,   // Title
+        Alignment::Left,   // Author
+        Alignment::Left,   // Category
+        Alignment::Right,  // Added on
+        Alignment::Center, // Bought
+        Alignment::Center, // Want to read
+        Alignment::Right,  // Series progress
+    ];
+    out.write_structured_table(&table_rows, &alignments)
+}
+
+/// Builds the table for the all-books view: the book list table (see
+/// `build_book_list_table`) with a leading Status column marking finished and
+/// currently-reading books with `symbols`.
+pub fn build_all_books_table(
+    storage: &Storage,
+    books: Vec<&Book>,
+    full_titles: bool,
+    date_format: &str,
+    symbols: &StatusSymbols,
+) -> io::Result<Vec<TableRow>> {
+    let table_rows = build_book_list_rows(storage, books, full_titles, date_format)?
+        .into_iter()
+        .map(|(row, book)| match row {
+            TableRow::Header(mut cells) => {
+                cells.insert(0, "Status".to_string());
+                TableRow::Header(cells)
+            }
+            TableRow::Data(mut cells) => {
+                let symbol = book
+                    .map(|book| status_symbol(storage, &book.id, symbols))
+                    .unwrap_or("");
+                cells.insert(0, symbol.to_string());
+                TableRow::Data(cells)
+            }
+            group => group,
+        })
+        .collect();
+    Ok(table_rows)
+}
+
+/// Formats the footer under the all-books view, e.g.
+/// "42 books: 12 finished, 3 reading, 27 not started."
+///
+/// Statuses follow the Status column: a book is finished, otherwise reading if
+/// started, otherwise not started.
+pub fn format_library_counts(storage: &Storage, books: &[&Book]) -> String {
+    let (mut finished, mut reading) = (0, 0);
+    for book in books {
+        if storage.is_book_finished(&book.id) {
+            finished += 1;
+        } else if storage.is_book_started(&book.id) {
+            reading += 1;
+        }
+    }
+    let not_started = books.len() - finished - reading;
+    format!(
+        "{} {}: {} finished, {} reading, {} not started.",
+        books.len(),
+        if books.len() == 1 { "book" } else { "books" },
+        finished,
+        reading,
+        not_started
+    )
+}
+
+/// Prints the all-books view (see `build_all_books_table`) to `out`.
+pub fn print_all_books_table(
+    out: &mut TableOutput,
+    storage: &Storage,
+    books: Vec<&Book>,
+    empty_message: &str,
+    full_titles: bool,
+    symbols: &StatusSymbols,
+    reverse: bool,
+) -> io::Result<()> {
+    let mut table_rows =
+        build_all_books_table(storage, books, full_titles, out.date_format, symbols)?;
+    if table_rows.is_empty() {
+        return out.write_line(empty_message);
+    }
+    if reverse {
+        table_rows = reverse_table_rows(table_rows);
+    }
+
+    let alignments = [
+        Alignment::Center, // Status
+        Alignment::Left,   // Title
+        Alignment::Left,   // Author
+        Alignment::Left,   // Category
+        Alignment::Right,  // Added on
+        Alignment::Center, // Bought
+        Alignment::Center, // Want to read
+        Alignment::Right,  // Series progress
+    ];
+    out.write_structured_table(&table_rows, &alignments)
+}
+
+/// Formats the "Series progress" cell for a book, e.g. "3/7 read" or "3 read"
+/// when the series length is unknown. Blank for standalone books.
+fn format_series_progress_cell(storage: &Storage, book: &Book) -> String {
+    book.series_id
+        .as_deref()
+        .filter(|sid| storage.get_series(sid).is_some())
+        .map(|sid| match storage.series_progress(sid) {
+            (finished, Some(total)) => format!("{}/{} read", finished, total),
+            (finished, None) => format!("{} read", finished),
+        })
+        .unwrap_or_default()
 }
 
 /// Builds a data row for the book list table (backlog / want-to-read).
@@ -450,6 +1086,7 @@ fn build_book_list_row(
     storage: &Storage,
     book: &Book,
     title: String,
+    date_format: &str,
     want_to_read_ids: &std::collections::HashSet<&str>,
 ) -> io::Result<Vec<String>> {
     let author_name = storage.author_name_for_book(book);
@@ -457,7 +1094,7 @@ fn build_book_list_row(
     let category = storage
         .categories
         .get(&book.category_id)
-        .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Category not found"))?;
+        .ok_or_else(|| io::Error::other("Category not found"))?;
 
     let has_bought_event = storage
         .readings
@@ -466,7 +1103,7 @@ fn build_book_list_row(
 
     let is_want_to_read = want_to_read_ids.contains(book.id.as_str());
 
-    let added_date = book.added_on.format("%Y-%m-%d").to_string();
+    let added_date = book.added_on.format(date_format).to_string();
 
     Ok(vec![
         title,
@@ -0,0 +1,160 @@
+use crate::storage::{Book, ReadingEvent, Storage};
+use crate::table::{format_table_styled, Alignment, TableStyle};
+use crate::time::Timezone;
+use chrono::{DateTime, Utc};
+
+/// Returns the books finished in `year` (in `tz`) with their finish times, in
+/// the order they were finished (ties broken by title).
+fn finished_in_year<'a>(
+    storage: &'a Storage,
+    year: i32,
+    tz: &Timezone,
+) -> Vec<(DateTime<Utc>, &'a Book)> {
+    let mut finished: Vec<_> = storage
+        .readings
+        .values()
+        .filter(|r| r.event == ReadingEvent::Finished && tz.year_of(&r.created_on) == year)
+        .filter_map(|r| {
+            storage
+                .get_book(&r.book_id)
+                .map(|book| (r.created_on, book))
+        })
+        .collect();
+    finished
+        .sort_by(|(a_date, a), (b_date, b)| a_date.cmp(b_date).then_with(|| a.title.cmp(&b.title)));
+    finished
+}
+
+/// Escapes characters that markdown would otherwise treat as formatting:
+/// emphasis (`*`, `_`), links (`[`, `]`), code (`` ` ``), table cells (`|`)
+/// and the backslash itself.
+pub fn markdown_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '\\' | '*' | '_' | '[' | ']' | '`' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Builds a markdown report of the books finished in `year` (in `tz`), with
+/// finish dates in `date_format`.
+///
+/// Starts with a heading and a line with the number of books and pages (and the
+/// goal, if one is set for the year), followed by a table of the finished books
+/// in the order they were finished, with their average rating when rated.
+/// The table uses `TableStyle::Markdown`; titles and author names are escaped
+/// with `markdown_escape`.
+pub fn year_report_markdown(
+    storage: &Storage,
+    year: i32,
+    tz: &Timezone,
+    date_format: &str,
+) -> String {
+    let finished = finished_in_year(storage, year, tz);
+
+    let mut lines = vec![format!("# Reading report {}", year), String::new()];
+
+    let pages: i64 = finished
+        .iter()
+        .map(|(_, book)| i64::from(book.total_pages.max(0)))
+        .sum();
+    let mut summary = format!(
+        "{} {} finished, {} pages.",
+        finished.len(),
+        crate::goal::pluralize_book(finished.len() as u32),
+        pages
+    );
+    if let Some(target) = storage.get_goal(year) {
+        summary.push_str(&format!(" Goal: {} books.", target));
+    }
+    lines.push(summary);
+
+    if !finished.is_empty() {
+        let mut rows = vec![vec![
+            "Finished".to_string(),
+            "Title".to_string(),
+            "Author".to_string(),
+            "Rating".to_string(),
+        ]];
+        for (date, book) in &finished {
+            rows.push(vec![
+                tz.local_date(date).format(date_format).to_string(),
+                markdown_escape(&book.title),
+                markdown_escape(storage.author_name_for_book(book)),
+                storage
+                    .book_average_rating(&book.id)
+                    .map(|r| format!("{:.1}/5", r))
+                    .unwrap_or_default(),
+            ]);
+        }
+        let alignments = [
+            Alignment::Left,  // Finished
+            Alignment::Left,  // Title
+            Alignment::Left,  // Author
+            Alignment::Right, // Rating
+        ];
+        lines.push(String::new());
+        lines.push(
+            format_table_styled(&rows, &alignments, TableStyle::Markdown)
+                .trim_end()
+                .to_string(),
+        );
+    }
+
+    lines.join("\n")
+}
+
+/// Builds a markdown reading journal for `year` (in `tz`): a section for each
+/// book finished that year, in the order they were finished, with the author,
+/// finish date (in `date_format`) and average rating, followed by the full text
+/// of its reviews (oldest first). Books without reviews get just the metadata
+/// line. Titles, author names and review texts are escaped with `markdown_escape`.
+pub fn reading_journal_markdown(
+    storage: &Storage,
+    year: i32,
+    tz: &Timezone,
+    date_format: &str,
+) -> String {
+    let finished = finished_in_year(storage, year, tz);
+    let mut lines = vec![format!("# Reading journal {}", year)];
+    if finished.is_empty() {
+        lines.push(String::new());
+        lines.push(format!("No books finished in {}.", year));
+    }
+
+    for (date, book) in finished {
+        lines.push(String::new());
+        lines.push(format!("## {}", markdown_escape(&book.title)));
+        lines.push(String::new());
+
+        let mut meta = Vec::new();
+        let author = storage.author_name_for_book(book);
+        if !author.is_empty() {
+            meta.push(format!("By {}", markdown_escape(author)));
+        }
+        meta.push(format!(
+            "Finished {}",
+            tz.local_date(&date).format(date_format)
+        ));
+        if let Some(rating) = storage.book_average_rating(&book.id) {
+            meta.push(format!("Rating {:.1}/5", rating));
+        }
+        lines.push(meta.join(" \u{00b7} "));
+
+        let mut reviews: Vec<_> = storage
+            .reviews
+            .values()
+            .filter(|r| r.book_id == book.id)
+            .collect();
+        reviews.sort_by_key(|r| r.created_on);
+        for review in reviews {
+            lines.push(String::new());
+            lines.push(markdown_escape(review.text.trim()));
+        }
+    }
+
+    lines.join("\n")
+}
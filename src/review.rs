@@ -1,16 +1,32 @@
-use crate::storage::{Review, Storage};
+use crate::storage::{Review, Storage, StorageError};
 use std::io;
 
 /// Validates and stores a review. Returns an error if the referenced book doesn't exist.
-pub fn store_review(storage: &mut Storage, review: Review) -> Result<(), String> {
+pub fn store_review(storage: &mut Storage, review: Review) -> Result<(), StorageError> {
     if !storage.books.contains_key(&review.book_id) {
-        return Err(format!("Book with ID {} does not exist", review.book_id));
+        return Err(StorageError::MissingBook(review.book_id));
     }
 
     storage.add_review(review);
     Ok(())
 }
 
+/// Parses a star rating entered by the user. Blank input means no rating.
+/// Returns an error unless the input is a whole number from 1 to 5.
+pub fn parse_rating_input(input: &str) -> Result<Option<u8>, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    match trimmed.parse::<u8>() {
+        Ok(rating) if (1..=5).contains(&rating) => Ok(Some(rating)),
+        _ => Err(format!(
+            "Invalid rating '{}': expected a number from 1 to 5",
+            trimmed
+        )),
+    }
+}
+
 /// Strips comment lines (starting with #) and trims whitespace from editor text.
 /// Returns None if the resulting text is empty (indicating the user aborted).
 pub fn strip_editor_text(text: &str) -> Option<String> {
@@ -76,8 +92,9 @@ pub fn get_review_text_from_editor(
     Ok(strip_editor_text(&content))
 }
 
-/// Displays a table of all reviews with book title, author, date, and a text preview.
-pub fn show_reviews(storage: &Storage) -> io::Result<()> {
+/// Displays a table of all reviews with book title, author, date (in `date_format`),
+/// and a text preview.
+pub fn show_reviews(storage: &Storage, date_format: &str) -> io::Result<()> {
     use crate::table::{print_table, Alignment};
 
     let mut reviews: Vec<&Review> = storage.reviews.values().collect();
@@ -88,7 +105,7 @@ pub fn show_reviews(storage: &Storage) -> io::Result<()> {
     }
 
     // Sort by creation date, newest first
-    reviews.sort_by(|a, b| b.created_on.cmp(&a.created_on));
+    reviews.sort_by_key(|r| std::cmp::Reverse(r.created_on));
 
     let mut table_data = vec![vec![
         "Title".to_string(),
@@ -103,7 +120,7 @@ pub fn show_reviews(storage: &Storage) -> io::Result<()> {
         let author_name = book
             .map(|b| storage.author_name_for_book(b))
             .unwrap_or("Unknown Author");
-        let date = review.created_on.format("%Y-%m-%d").to_string();
+        let date = review.created_on.format(date_format).to_string();
         let preview = truncate_text(&review.text, 60);
 
         table_data.push(vec![
@@ -124,8 +141,8 @@ pub fn show_reviews(storage: &Storage) -> io::Result<()> {
     Ok(())
 }
 
-/// Displays the full text of a single review.
-pub fn show_review_detail(storage: &Storage, review_id: &str) -> io::Result<()> {
+/// Displays the full text of a single review, dated in `date_format`.
+pub fn show_review_detail(storage: &Storage, review_id: &str, date_format: &str) -> io::Result<()> {
     let review = storage
         .reviews
         .get(review_id)
@@ -136,11 +153,14 @@ pub fn show_review_detail(storage: &Storage, review_id: &str) -> io::Result<()>
     let author_name = book
         .map(|b| storage.author_name_for_book(b))
         .unwrap_or("Unknown Author");
-    let date = review.created_on.format("%Y-%m-%d").to_string();
+    let date = review.created_on.format(date_format).to_string();
 
     println!();
     println!("Review of \"{}\" by {}", title, author_name);
     println!("Written on {}", date);
+    if let Some(rating) = review.rating {
+        println!("Rating: {}/5", rating);
+    }
     println!("{}", "-".repeat(60));
     println!("{}", review.text);
     println!();
@@ -151,7 +171,8 @@ pub fn show_review_detail(storage: &Storage, review_id: &str) -> io::Result<()>
 /// Truncates text to a maximum number of characters, appending "..." if truncated.
 /// Replaces newlines with spaces for single-line display.
 /// Uses char count (not byte count) to avoid panicking on multi-byte UTF-8 characters.
-fn truncate_text(text: &str, max_chars: usize) -> String {
+/// Used for review previews and lookup descriptions.
+pub fn truncate_text(text: &str, max_chars: usize) -> String {
     let single_line = text.replace('\n', " ");
     if single_line.chars().count() <= max_chars {
         single_line
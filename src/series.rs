@@ -1,7 +1,8 @@
-use crate::storage::{Series, Storage};
+use crate::config::StatusSymbols;
+use crate::storage::{parse_position_key, Series, Storage, StorageError};
 
 /// Stores a series in the storage.
-pub fn store_series(storage: &mut Storage, series: Series) -> Result<(), String> {
+pub fn store_series(storage: &mut Storage, series: Series) -> Result<(), StorageError> {
     storage.add_series(series);
     Ok(())
 }
@@ -26,16 +27,29 @@ pub fn format_position_prefix(position: Option<&str>) -> String {
     }
 }
 
+/// Returns the symbol in `symbols` for a book's current status: finished, being
+/// read or unread.
+pub fn status_symbol<'a>(storage: &Storage, book_id: &str, symbols: &'a StatusSymbols) -> &'a str {
+    if storage.is_book_finished(book_id) {
+        &symbols.finished
+    } else if storage.is_book_started(book_id) {
+        &symbols.reading
+    } else {
+        &symbols.unread
+    }
+}
+
 /// Parses a position-in-series input string. Returns `Some(position)` for valid
-/// non-negative numbers (integers like "1", "0" or decimals like "2.5").
-/// Returns `None` for empty/whitespace, negative numbers, or non-numeric input.
+/// non-negative numbers (integers like "1", "0" or decimals like "2.5") and numbers
+/// with a letter suffix ("2a").
+/// Returns `None` for empty/whitespace, negative numbers, or other input.
 pub fn parse_position_input(input: &str) -> Option<String> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
         return None;
     }
-    match trimmed.parse::<f64>() {
-        Ok(val) if val >= 0.0 => Some(trimmed.to_string()),
+    match parse_position_key(trimmed) {
+        Some((val, _)) if val >= 0.0 => Some(trimmed.to_string()),
         _ => None,
     }
 }
@@ -46,9 +60,30 @@ pub fn parse_position_input(input: &str) -> Option<String> {
 /// - Header line with series name and progress (e.g. "Harry Potter (3/7 read)")
 /// - Unicode separator line
 /// - Books listed with status indicators: `✓` finished, `▸` reading, blank for unread
+/// - When any book is rated, a line with the series' average rating and best book
 ///
 /// Returns the formatted string (no trailing newline).
 pub fn format_series_display(storage: &Storage, series_id: &str) -> String {
+    format_series_display_with_symbols(storage, series_id, &StatusSymbols::default())
+}
+
+/// Like `format_series_display`, marking book statuses with the given symbols.
+pub fn format_series_display_with_symbols(
+    storage: &Storage,
+    series_id: &str,
+    symbols: &StatusSymbols,
+) -> String {
+    format_series_display_with_limit(storage, series_id, symbols, None)
+}
+
+/// Like `format_series_display_with_symbols`, listing at most `limit` books
+/// followed by a line saying how many more there are.
+pub fn format_series_display_with_limit(
+    storage: &Storage,
+    series_id: &str,
+    symbols: &StatusSymbols,
+    limit: Option<usize>,
+) -> String {
     let series = match storage.get_series(series_id) {
         Some(s) => s,
         None => return String::new(),
@@ -65,50 +100,14 @@ pub fn format_series_display(storage: &Storage, series_id: &str) -> String {
         return lines.join("\n");
     }
 
-    // Count reading status
-    let finished_count = books
-        .iter()
-        .filter(|b| storage.is_book_finished(&b.id))
-        .count();
-    let reading_count = books
-        .iter()
-        .filter(|b| storage.is_book_started(&b.id))
-        .count();
-
-    // Build header with progress
-    let progress = match series.total_books {
-        Some(total) => {
-            let mut parts = vec![format!("{}/{} read", finished_count, total)];
-            if reading_count > 0 {
-                parts.push(format!("{} reading", reading_count));
-            }
-            parts.join(", ")
-        }
-        None => {
-            let mut parts = vec![format!("{} read", finished_count)];
-            if reading_count > 0 {
-                parts.push(format!("{} reading", reading_count));
-            }
-            parts.join(", ")
-        }
-    };
-
-    let header = format!("{} ({})", series.name, progress);
+    let header = format!("{} ({})", series.name, format_progress(storage, series_id));
     lines.push(header.clone());
     lines.push("\u{2500}".repeat(header.len()));
 
     // List books with status indicators
-    for book in &books {
-        let is_finished = storage.is_book_finished(&book.id);
-        let is_started = storage.is_book_started(&book.id);
-
-        let status_indicator = if is_finished {
-            "\u{2713}" // ✓
-        } else if is_started {
-            "\u{25b8}" // ▸
-        } else {
-            " "
-        };
+    let shown = limit.unwrap_or(books.len()).min(books.len());
+    for book in &books[..shown] {
+        let status_indicator = status_symbol(storage, &book.id, symbols);
 
         let author_name = storage.author_name_for_book(book);
         let author_name = if author_name.is_empty() {
@@ -129,9 +128,58 @@ pub fn format_series_display(storage: &Storage, series_id: &str) -> String {
         ));
     }
 
+    if shown < books.len() {
+        lines.push(format!("  \u{2026} and {} more", books.len() - shown));
+    }
+
+    if let (Some(average), Some(best)) = storage.series_rating_summary(series_id) {
+        let best_rating = storage.book_average_rating(&best.id).unwrap_or(average);
+        lines.push(format!(
+            "  Average rating {:.1}/5, best: \"{}\" ({:.1}/5)",
+            average, best.title, best_rating
+        ));
+    }
+
     lines.join("\n")
 }
 
+/// Formats reading progress in a series, e.g. "3/7 read, 1 reading", or
+/// "3 read" when the series' length isn't known.
+fn format_progress(storage: &Storage, series_id: &str) -> String {
+    let (finished_count, total_books) = storage.series_progress(series_id);
+    let reading_count = storage
+        .get_books_in_series(series_id)
+        .iter()
+        .filter(|b| storage.is_book_started(&b.id))
+        .count();
+
+    let mut parts = vec![match total_books {
+        Some(total) => format!("{}/{} read", finished_count, total),
+        None => format!("{} read", finished_count),
+    }];
+    if reading_count > 0 {
+        parts.push(format!("{} reading", reading_count));
+    }
+    parts.join(", ")
+}
+
+/// Formats a one-line summary of a series, e.g. "Discworld — 12/41 read, 1 reading".
+/// Series without books are summarized as "Name — no books".
+/// Returns an empty string if the series doesn't exist.
+pub fn format_series_summary(storage: &Storage, series_id: &str) -> String {
+    let Some(series) = storage.get_series(series_id) else {
+        return String::new();
+    };
+    if storage.get_books_in_series(series_id).is_empty() {
+        return format!("{} \u{2014} no books", series.name);
+    }
+    format!(
+        "{} \u{2014} {}",
+        series.name,
+        format_progress(storage, series_id)
+    )
+}
+
 /// Checks if a position is already occupied by another book in the series.
 /// Returns the title of the book at that position, or None if the position is free.
 pub fn is_position_occupied(storage: &Storage, series_id: &str, position: &str) -> Option<String> {
@@ -145,6 +193,22 @@ pub fn is_position_occupied(storage: &Storage, series_id: &str, position: &str)
         .map(|b| b.title.clone())
 }
 
+/// Like `is_position_occupied`, but returns a `StorageError::PositionOccupied`
+/// naming the book at that position, for callers that want to report it.
+pub fn check_position_free(
+    storage: &Storage,
+    series_id: &str,
+    position: &str,
+) -> Result<(), StorageError> {
+    match is_position_occupied(storage, series_id, position) {
+        Some(title) => Err(StorageError::PositionOccupied {
+            title,
+            position: position.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
 /// Finds an existing series by name (case-insensitive) or creates a new one.
 /// Returns the series ID.
 pub fn get_or_create_series(storage: &mut Storage, name: &str) -> String {
@@ -166,9 +230,9 @@ pub fn get_or_create_series(storage: &mut Storage, name: &str) -> String {
 
 /// Deletes a series and unlinks all books that belong to it.
 /// Returns an error if the series does not exist.
-pub fn delete_series(storage: &mut Storage, series_id: &str) -> Result<(), String> {
+pub fn delete_series(storage: &mut Storage, series_id: &str) -> Result<(), StorageError> {
     if storage.series.remove(series_id).is_none() {
-        return Err("Series not found. It may have already been deleted.".to_string());
+        return Err(StorageError::SeriesNotFound);
     }
 
     // Unlink all books from this series
@@ -218,6 +282,26 @@ pub fn find_matching_series_names(storage: &Storage, filter: &str) -> Vec<String
     names
 }
 
+/// Like `find_matching_series_names`, but ranks the names for suggestions: an
+/// exact match first, then names starting with `filter`, then names containing it
+/// elsewhere, each group sorted alphabetically (case-insensitive).
+pub fn find_matching_series_ranked(storage: &Storage, filter: &str) -> Vec<String> {
+    let filter_lower = filter.to_lowercase();
+    let mut names = find_matching_series_names(storage, filter);
+    // The sort is stable, so each rank keeps the alphabetical order
+    names.sort_by_key(|name| {
+        let name = name.to_lowercase();
+        if name == filter_lower {
+            0
+        } else if name.starts_with(&filter_lower) {
+            1
+        } else {
+            2
+        }
+    });
+    names
+}
+
 /// Builds a helpful empty-result message when a `--series` filter yields no books.
 ///
 /// - If the filter term matches known series names, tells the user no books matched.
@@ -246,15 +330,19 @@ pub fn format_series_filter_empty_message(storage: &Storage, filter: &str) -> St
 
 /// Renames a series. Returns an error if the series does not exist, if the new
 /// name is empty, or if another series with the new name already exists (case-insensitive).
-pub fn rename_series(storage: &mut Storage, series_id: &str, new_name: &str) -> Result<(), String> {
+pub fn rename_series(
+    storage: &mut Storage,
+    series_id: &str,
+    new_name: &str,
+) -> Result<(), StorageError> {
     let new_name_trimmed = new_name.trim();
     if new_name_trimmed.is_empty() {
-        return Err("Series name cannot be empty".to_string());
+        return Err(StorageError::EmptyName);
     }
 
     // Check that the series exists
     if !storage.series.contains_key(series_id) {
-        return Err("Series not found. It may have already been deleted.".to_string());
+        return Err(StorageError::SeriesNotFound);
     }
 
     // Check for duplicate name (case-insensitive), excluding the series being renamed
@@ -263,10 +351,7 @@ pub fn rename_series(storage: &mut Storage, series_id: &str, new_name: &str) ->
         .iter()
         .any(|(id, s)| id != series_id && s.name.to_lowercase() == new_name_trimmed.to_lowercase());
     if duplicate {
-        return Err(format!(
-            "A series named '{}' already exists",
-            new_name_trimmed
-        ));
+        return Err(StorageError::DuplicateName(new_name_trimmed.to_string()));
     }
 
     // Rename
@@ -276,3 +361,16 @@ pub fn rename_series(storage: &mut Storage, series_id: &str, new_name: &str) ->
 
     Ok(())
 }
+
+/// Counts the books in `books` that match an optional `--series` filter.
+/// With no filter, every book is counted.
+pub fn count_books_matching_series(
+    storage: &Storage,
+    books: &[&crate::storage::Book],
+    filter: Option<&str>,
+) -> usize {
+    match filter {
+        Some(filter) => filter_books_by_series(storage, books, filter).len(),
+        None => books.len(),
+    }
+}
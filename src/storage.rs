@@ -1,12 +1,14 @@
-use chrono::{DateTime, Datelike, TimeZone, Utc};
+use crate::backend::backend_for_path;
+use crate::id::{IdGen, UuidGen};
+use crate::lookup::book_lookup_dto::{parse_publish_year, BookLookupDTO};
+use crate::time::Timezone;
+use chrono::{DateTime, Datelike, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::value::Value;
 use serde_json::Map;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
-use std::fs;
-use std::path::Path;
-use uuid::Uuid;
+use std::path::{Path, PathBuf};
 
 /// A book author with a unique ID and creation timestamp.
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -78,26 +80,106 @@ pub struct Book {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "deserialize_position")]
     pub position_in_series: Option<String>,
+    /// Optional subtitle, kept apart from `title` so tables can show the short title.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// Optional description or blurb, typically filled in from an ISBN lookup.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional URL of a cover image, typically filled in from an ISBN lookup.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_url: Option<String>,
+    /// Optional name of the lookup provider that supplied the book's details
+    /// (e.g. "openlibrary"). `None` for books entered by hand or added before
+    /// this was recorded.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Optional publisher of the edition, typically filled in from an ISBN lookup.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    /// Optional year the edition was published, parsed from the lookup's publish date.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publish_year: Option<i32>,
+    /// Copies of the book in other formats (e.g. an audiobook next to the paperback),
+    /// each with its own length so progress can be tracked separately.
+    /// Readings without an `edition_id` belong to the book itself.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub editions: Vec<Edition>,
+}
+
+/// The format of an edition of a book.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum EditionFormat {
+    Paperback,
+    Hardcover,
+    Ebook,
+    Audiobook,
+}
+
+/// A copy of a book in a particular format. Print and ebook editions are
+/// measured in pages, audiobooks in minutes.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Edition {
+    pub id: String,
+    pub format: EditionFormat,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<i32>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_minutes: Option<i32>,
+}
+
+impl Edition {
+    /// Creates a new edition with a generated UUID and no known length.
+    pub fn new(format: EditionFormat) -> Self {
+        Self::new_with_id_gen(format, &UuidGen)
+    }
+
+    /// Like `new`, taking the ID from `ids`.
+    pub fn new_with_id_gen(format: EditionFormat, ids: &dyn IdGen) -> Self {
+        Self {
+            id: ids.new_id(),
+            format,
+            total_pages: None,
+            duration_minutes: None,
+        }
+    }
 }
 
 /// Custom deserializer for `position_in_series` that accepts both JSON numbers
 /// (from old i32 format) and strings (new format) for backward compatibility.
+///
+/// Strings are trimmed, and whole-number floats such as `3.0` become `"3"`.
+/// Null, empty and whitespace-only values mean no position.
 fn deserialize_position<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
     let value: Option<serde_json::Value> = Option::deserialize(deserializer)?;
     match value {
-        None => Ok(None),
+        None | Some(serde_json::Value::Null) => Ok(None),
         Some(serde_json::Value::String(s)) => {
-            if s.is_empty() {
+            let trimmed = s.trim();
+            if trimmed.is_empty() {
                 Ok(None)
             } else {
-                Ok(Some(s))
+                Ok(Some(trimmed.to_string()))
             }
         }
-        Some(serde_json::Value::Number(n)) => Ok(Some(n.to_string())),
-        Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Number(n)) => match n.as_f64() {
+            Some(f) if n.is_f64() && f.fract() == 0.0 && f.abs() < i64::MAX as f64 => {
+                Ok(Some((f as i64).to_string()))
+            }
+            _ => Ok(Some(n.to_string())),
+        },
         Some(other) => Err(serde::de::Error::custom(format!(
             "unexpected type for position_in_series: {}",
             other
@@ -109,21 +191,116 @@ where
 ///
 /// The most recent event determines the book's current status.
 /// `Update` and `Bought` are non-status events that don't affect started/finished determination.
+///
+/// Stored in snake_case (`want_to_read`); the PascalCase names older files use
+/// (`WantToRead`) still load.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
 pub enum ReadingEvent {
+    #[serde(alias = "Finished")]
     Finished,
+    #[serde(alias = "Started")]
     Started,
+    #[serde(alias = "Update")]
     Update,
+    #[serde(alias = "Bought")]
     Bought,
+    #[serde(alias = "WantToRead")]
     WantToRead,
+    #[serde(alias = "UnmarkedAsWantToRead")]
     UnmarkedAsWantToRead,
+    /// The book was abandoned without being finished. Like `Finished`, it ends
+    /// the current read.
+    #[serde(alias = "DidNotFinish")]
+    DidNotFinish,
+}
+
+impl ReadingEvent {
+    /// Every event type, in declaration order.
+    pub const ALL: [ReadingEvent; 7] = [
+        ReadingEvent::Finished,
+        ReadingEvent::Started,
+        ReadingEvent::Update,
+        ReadingEvent::Bought,
+        ReadingEvent::WantToRead,
+        ReadingEvent::UnmarkedAsWantToRead,
+        ReadingEvent::DidNotFinish,
+    ];
+
+    /// Returns a short human-readable label for the event, e.g. "Want to read".
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReadingEvent::Finished => "Finished",
+            ReadingEvent::Started => "Started",
+            ReadingEvent::Update => "Progress update",
+            ReadingEvent::Bought => "Bought",
+            ReadingEvent::WantToRead => "Want to read",
+            ReadingEvent::UnmarkedAsWantToRead => "Removed from want to read",
+            ReadingEvent::DidNotFinish => "Did not finish",
+        }
+    }
+}
+
+/// Formats the event's canonical name, e.g. `want-to-read`, as accepted by `FromStr`.
+impl std::fmt::Display for ReadingEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ReadingEvent::Finished => "finished",
+            ReadingEvent::Started => "started",
+            ReadingEvent::Update => "update",
+            ReadingEvent::Bought => "bought",
+            ReadingEvent::WantToRead => "want-to-read",
+            ReadingEvent::UnmarkedAsWantToRead => "unmarked-as-want-to-read",
+            ReadingEvent::DidNotFinish => "did-not-finish",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Parses an event name such as `started` or `want-to-read`. Matching is
+/// case-insensitive and ignores `-`, `_` and spaces, so `WantToRead` works too.
+/// `dnf` is accepted for `did-not-finish`.
+impl std::str::FromStr for ReadingEvent {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalize = |s: &str| -> String {
+            s.chars()
+                .filter(|c| !matches!(c, '-' | '_' | ' '))
+                .collect::<String>()
+                .to_lowercase()
+        };
+        let wanted = normalize(input);
+        if wanted == "dnf" {
+            return Ok(ReadingEvent::DidNotFinish);
+        }
+        ReadingEvent::ALL
+            .into_iter()
+            .find(|event| normalize(&event.to_string()) == wanted)
+            .ok_or_else(|| {
+                let names: Vec<String> = ReadingEvent::ALL.iter().map(|e| e.to_string()).collect();
+                format!(
+                    "Unknown event type '{}': expected one of {}",
+                    input,
+                    names.join(", ")
+                )
+            })
+    }
 }
 
-/// Optional metadata attached to a reading event (e.g. current page for Update events).
+/// Optional metadata attached to a reading event (e.g. current page for Update
+/// events, or what was paid for Bought events).
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct ReadingMetadata {
     #[serde(default)]
     pub current_page: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Minutes listened so far, for `Update` events on an audiobook edition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_minute: Option<i32>,
 }
 
 /// A timestamped reading event for a book (event-sourcing pattern).
@@ -138,6 +315,11 @@ pub struct Reading {
     pub event: ReadingEvent,
     #[serde(default)]
     pub metadata: ReadingMetadata,
+    /// Optional FK -> Edition.id within the book. None means the event is for
+    /// the book itself rather than one of its editions.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edition_id: Option<String>,
 }
 
 /// A user-written review of a book.
@@ -151,16 +333,65 @@ pub struct Review {
     pub created_on: DateTime<Utc>,
     pub book_id: String,
     pub text: String,
+    /// Optional star rating from 1 to 5.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<u8>,
 }
 
 impl Review {
     /// Creates a new review with a generated UUID and current timestamp.
     pub fn new(book_id: String, text: String) -> Self {
+        Self::new_with_id_gen(book_id, text, &UuidGen)
+    }
+
+    /// Like `new`, taking the ID from `ids`.
+    pub fn new_with_id_gen(book_id: String, text: String, ids: &dyn IdGen) -> Self {
+        Self {
+            id: ids.new_id(),
+            created_on: Utc::now(),
+            book_id,
+            text,
+            rating: None,
+        }
+    }
+}
+
+/// A memorable quote or highlight from a book.
+///
+/// Like reviews, each quote is linked to a book by `book_id` and a book can have
+/// any number of them.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Quote {
+    pub id: String,
+    pub created_on: DateTime<Utc>,
+    pub book_id: String,
+    pub text: String,
+    /// The page the quote is on, if noted.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i32>,
+}
+
+impl Quote {
+    /// Creates a new quote with a generated UUID and current timestamp.
+    pub fn new(book_id: String, text: String, page: Option<i32>) -> Self {
+        Self::new_with_id_gen(book_id, text, page, &UuidGen)
+    }
+
+    /// Like `new`, taking the ID from `ids`.
+    pub fn new_with_id_gen(
+        book_id: String,
+        text: String,
+        page: Option<i32>,
+        ids: &dyn IdGen,
+    ) -> Self {
         Self {
-            id: Uuid::new_v4().to_string(),
+            id: ids.new_id(),
             created_on: Utc::now(),
             book_id,
             text,
+            page,
         }
     }
 }
@@ -168,8 +399,13 @@ impl Review {
 impl Author {
     /// Creates a new author with a generated UUID and current timestamp.
     pub fn new(name: String) -> Self {
+        Self::new_with_id_gen(name, &UuidGen)
+    }
+
+    /// Like `new`, taking the ID from `ids`.
+    pub fn new_with_id_gen(name: String, ids: &dyn IdGen) -> Self {
         Self {
-            id: Uuid::new_v4().to_string(),
+            id: ids.new_id(),
             name,
             created_on: Utc::now(),
         }
@@ -184,9 +420,21 @@ impl Book {
         category_id: String,
         author_id: String,
         total_pages: i32,
+    ) -> Self {
+        Self::new_with_id_gen(title, isbn, category_id, author_id, total_pages, &UuidGen)
+    }
+
+    /// Like `new`, taking the ID from `ids`.
+    pub fn new_with_id_gen(
+        title: String,
+        isbn: String,
+        category_id: String,
+        author_id: String,
+        total_pages: i32,
+        ids: &dyn IdGen,
     ) -> Self {
         Self {
-            id: Uuid::new_v4().to_string(),
+            id: ids.new_id(),
             title,
             added_on: Utc::now(),
             isbn,
@@ -195,9 +443,62 @@ impl Book {
             total_pages,
             series_id: None,
             position_in_series: None,
+            subtitle: None,
+            description: None,
+            cover_url: None,
+            source: None,
+            publisher: None,
+            publish_year: None,
+            editions: Vec::new(),
+        }
+    }
+
+    /// Creates a new book from an ISBN lookup result.
+    ///
+    /// Copies the title (trimmed), subtitle, ISBN, page count (0 when unknown),
+    /// description, cover URL, source and, when the result names a series, the
+    /// position in it. The series itself is not linked, since that needs the
+    /// storage; callers set `series_id` (e.g. with `get_or_create_series`).
+    pub fn from_lookup(dto: &BookLookupDTO, category_id: String, author_id: String) -> Self {
+        let mut book = Self::new(
+            dto.title.trim().to_string(),
+            dto.isbn.trim().to_string(),
+            category_id,
+            author_id,
+            dto.total_pages.filter(|p| *p > 0).unwrap_or(0),
+        );
+        book.subtitle = dto.subtitle.clone();
+        book.description = dto.description.clone();
+        book.cover_url = dto.cover_url.clone();
+        book.source = dto.source.clone();
+        book.publisher = dto.publisher.clone();
+        book.publish_year = dto.publish_date.as_deref().and_then(parse_publish_year);
+        if dto
+            .series_name
+            .as_deref()
+            .is_some_and(|name| !name.trim().is_empty())
+        {
+            book.position_in_series = dto.series_position.clone();
+        }
+        book
+    }
+
+    /// Returns the title for display, with the subtitle appended ("Title: Subtitle")
+    /// when `full` is set and the book has one.
+    pub fn display_title(&self, full: bool) -> String {
+        match (&self.subtitle, full) {
+            (Some(subtitle), true) if !subtitle.trim().is_empty() => {
+                format!("{}: {}", self.title, subtitle)
+            }
+            _ => self.title.clone(),
         }
     }
 
+    /// Returns true if the book belongs to the given series.
+    pub fn is_in_series(&self, series_id: &str) -> bool {
+        self.series_id.as_deref() == Some(series_id)
+    }
+
     /// Creates a display string for a book with its status and author name
     pub fn to_display_string(&self, storage: &Storage, status: &str) -> Result<String, String> {
         let author = storage
@@ -209,59 +510,67 @@ impl Book {
             status, self.title, author.name
         ))
     }
-
-    /// Extracts a book title from a display string formatted as `[Status] "Title" by Author`
-    ///
-    /// Handles titles that contain " by " by finding the quoted title between the first
-    /// pair of double quotes after the status bracket.
-    pub fn title_from_display_string(display: &str) -> Result<String, String> {
-        // Find the first '"' after '] '
-        let after_bracket = display
-            .find("] \"")
-            .ok_or_else(|| format!("Invalid display string format: {}", display))?;
-        let title_start = after_bracket + 3; // skip '] "'
-
-        // Find the closing '"' before ' by ' — search from the end for the last '" by '
-        let remaining = &display[title_start..];
-        let title_end = remaining
-            .rfind("\" by ")
-            .ok_or_else(|| format!("Invalid display string format: {}", display))?;
-
-        Ok(remaining[..title_end].to_string())
-    }
 }
 
 impl Reading {
     /// Creates a new reading event with a generated UUID and current timestamp.
     pub fn new(book_id: String, event: ReadingEvent) -> Self {
+        Self::new_with_id_gen(book_id, event, &UuidGen)
+    }
+
+    /// Like `new`, taking the ID from `ids`.
+    pub fn new_with_id_gen(book_id: String, event: ReadingEvent, ids: &dyn IdGen) -> Self {
         Self {
-            id: Uuid::new_v4().to_string(),
+            id: ids.new_id(),
             created_on: Utc::now(),
             book_id,
             event,
-            metadata: ReadingMetadata { current_page: None },
+            metadata: ReadingMetadata::default(),
+            edition_id: None,
         }
     }
 
     /// Creates a new reading event with page progress metadata.
     pub fn with_metadata(book_id: String, event: ReadingEvent, current_page: i32) -> Self {
-        Self {
-            id: Uuid::new_v4().to_string(),
-            created_on: Utc::now(),
-            book_id,
-            event,
-            metadata: ReadingMetadata {
-                current_page: Some(current_page),
-            },
-        }
+        let mut reading = Self::new(book_id, event);
+        reading.metadata.current_page = Some(current_page);
+        reading
+    }
+
+    /// Creates a new `Bought` event recording what was paid for the book.
+    pub fn with_price(book_id: String, price: f64, currency: Option<String>) -> Self {
+        let mut reading = Self::new(book_id, ReadingEvent::Bought);
+        reading.metadata.price = Some(price);
+        reading.metadata.currency = currency;
+        reading
+    }
+
+    /// Attaches the reading to an edition of its book.
+    pub fn for_edition(mut self, edition_id: &str) -> Self {
+        self.edition_id = Some(edition_id.to_string());
+        self
+    }
+
+    /// Orders readings by when they happened. Readings with the same timestamp,
+    /// as bulk imports often create, are ordered by ID so the order never depends
+    /// on how the storage happens to iterate them.
+    pub fn cmp_chronological(&self, other: &Reading) -> std::cmp::Ordering {
+        self.created_on
+            .cmp(&other.created_on)
+            .then_with(|| self.id.cmp(&other.id))
     }
 }
 
 impl Category {
     /// Creates a new category with a generated UUID and current timestamp.
     pub fn new(name: String, description: Option<String>) -> Self {
+        Self::new_with_id_gen(name, description, &UuidGen)
+    }
+
+    /// Like `new`, taking the ID from `ids`.
+    pub fn new_with_id_gen(name: String, description: Option<String>, ids: &dyn IdGen) -> Self {
         Self {
-            id: Uuid::new_v4().to_string(),
+            id: ids.new_id(),
             name,
             description,
             created_on: Utc::now(),
@@ -272,8 +581,13 @@ impl Category {
 impl Series {
     /// Creates a new series with a generated UUID and current timestamp.
     pub fn new(name: String) -> Self {
+        Self::new_with_id_gen(name, &UuidGen)
+    }
+
+    /// Like `new`, taking the ID from `ids`.
+    pub fn new_with_id_gen(name: String, ids: &dyn IdGen) -> Self {
         Self {
-            id: Uuid::new_v4().to_string(),
+            id: ids.new_id(),
             name,
             created_on: Utc::now(),
             status: None,
@@ -282,7 +596,128 @@ impl Series {
     }
 }
 
-/// The central data store containing all books, readings, authors, categories, and reviews.
+/// Errors returned when storing or changing books, readings, reviews and series.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StorageError {
+    /// No author with this ID exists.
+    MissingAuthor(String),
+    /// No category with this ID exists.
+    MissingCategory(String),
+    /// No series with this ID exists.
+    MissingSeries(String),
+    /// No book with this ID exists.
+    MissingBook(String),
+    /// The series being changed no longer exists. Unlike `MissingSeries`, the
+    /// message leaves out the ID, since it is shown to the user.
+    SeriesNotFound,
+    /// Another series already has this name.
+    DuplicateName(String),
+    /// Another book already has this position in the series.
+    PositionOccupied { title: String, position: String },
+    /// A name was empty or only whitespace.
+    EmptyName,
+    /// The ISBN was rejected by the `strict_isbn` check.
+    InvalidIsbn(String),
+}
+
+impl std::fmt::Display for StorageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageError::MissingAuthor(id) => write!(f, "Author with ID {} does not exist", id),
+            StorageError::MissingCategory(id) => {
+                write!(f, "Category with ID {} does not exist", id)
+            }
+            StorageError::MissingSeries(id) => write!(f, "Series with ID {} does not exist", id),
+            StorageError::MissingBook(id) => write!(f, "Book with ID {} does not exist", id),
+            StorageError::SeriesNotFound => {
+                f.write_str("Series not found. It may have already been deleted.")
+            }
+            StorageError::DuplicateName(name) => {
+                write!(f, "A series named '{}' already exists", name)
+            }
+            StorageError::PositionOccupied { title, position } => {
+                write!(f, "'{}' is already #{} in this series", title, position)
+            }
+            StorageError::EmptyName => f.write_str("Series name cannot be empty"),
+            StorageError::InvalidIsbn(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+/// Lets `?` pass a `StorageError` up through functions that return `Result<_, String>`.
+impl From<StorageError> for String {
+    fn from(error: StorageError) -> Self {
+        error.to_string()
+    }
+}
+
+/// The kind of problem an `IntegrityIssue` describes.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrityIssueKind {
+    /// A series has more linked books than its `total_books`.
+    OverfullSeries,
+    /// A reading event refers to a book that doesn't exist.
+    DanglingReading,
+    /// A review refers to a book that doesn't exist.
+    DanglingReview,
+    /// A quote refers to a book that doesn't exist.
+    DanglingQuote,
+    /// A record is stored under a key other than its own `id`.
+    MismatchedKey,
+}
+
+/// A problem found by `Storage::integrity_issues`, naming the affected record.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct IntegrityIssue {
+    pub kind: IntegrityIssueKind,
+    pub entity_id: String,
+    pub detail: String,
+}
+
+/// Reports the records in `map` whose key differs from their id.
+fn mismatched_keys<'a, T>(
+    map: &'a HashMap<String, T>,
+    id: fn(&T) -> &String,
+    label: &'a str,
+) -> impl Iterator<Item = IntegrityIssue> + 'a {
+    map.iter()
+        .filter(move |(key, value)| *key != id(value))
+        .map(move |(key, value)| IntegrityIssue {
+            kind: IntegrityIssueKind::MismatchedKey,
+            entity_id: id(value).clone(),
+            detail: format!("{} {} is stored under key {}", label, id(value), key),
+        })
+}
+
+/// Moves the records in `map` whose key differs from their id to the right key.
+/// Returns how many were moved.
+fn rekey<T>(map: &mut HashMap<String, T>, id: fn(&T) -> &String) -> usize {
+    let wrong: Vec<String> = map
+        .iter()
+        .filter(|(key, value)| *key != id(value))
+        .map(|(key, _)| key.clone())
+        .collect();
+    for key in &wrong {
+        if let Some(value) = map.remove(key) {
+            map.insert(id(&value).clone(), value);
+        }
+    }
+    wrong.len()
+}
+
+/// The goal `Storage::suggested_goal` suggests when there's no reading history
+/// to go by: a book a month.
+pub const DEFAULT_GOAL_SUGGESTION: u32 = 12;
+
+/// How far into a book (in percent) the reader must be for `Storage::next_up` to
+/// call it nearly done.
+pub const NEARLY_DONE_PERCENT: f64 = 75.0;
+
+/// The central data store containing all books, readings, authors, categories, reviews
+/// and quotes.
 ///
 /// Persisted as a single JSON file. All collections are keyed by UUID string.
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -297,10 +732,18 @@ pub struct Storage {
     /// Uses `#[serde(default)]` for backward compatibility with existing JSON files.
     #[serde(default)]
     pub goals: HashMap<i32, u32>,
+    /// Per-category reading challenge: year -> category name -> books to finish.
+    /// Uses `#[serde(default)]` for backward compatibility with existing JSON files.
+    #[serde(default)]
+    pub category_goals: HashMap<i32, HashMap<String, u32>>,
     /// Book series (e.g. "Harry Potter", "A Song of Ice and Fire").
     /// Uses `#[serde(default)]` for backward compatibility with existing JSON files.
     #[serde(default)]
     pub series: HashMap<String, Series>,
+    /// Quotes and highlights collected from books.
+    /// Uses `#[serde(default)]` for backward compatibility with existing JSON files.
+    #[serde(default)]
+    pub quotes: HashMap<String, Quote>,
 }
 
 impl Default for Storage {
@@ -318,7 +761,9 @@ impl Storage {
             categories: HashMap::new(),
             reviews: HashMap::new(),
             goals: HashMap::new(),
+            category_goals: HashMap::new(),
             series: HashMap::new(),
+            quotes: HashMap::new(),
         }
     }
 
@@ -337,6 +782,14 @@ impl Storage {
         self.readings.insert(reading.id.clone(), reading)
     }
 
+    /// Removes a reading event by its full ID and returns it.
+    ///
+    /// Book statuses are derived from the remaining events, so removing e.g. a
+    /// `Finished` event puts the book back in whatever state it was in before.
+    pub fn remove_reading(&mut self, reading_id: &str) -> Option<Reading> {
+        self.readings.remove(reading_id)
+    }
+
     pub fn add_author(&mut self, author: Author) -> Option<Author> {
         self.authors.insert(author.id.clone(), author)
     }
@@ -354,14 +807,14 @@ impl Storage {
     }
 
     /// Returns all books that belong to a given series, sorted by position_in_series.
-    /// Positions are parsed as f64 for numeric sorting (supports "1", "2.5", "0", etc.).
-    /// Non-numeric positions are sorted lexicographically after numeric ones.
+    /// Positions sort numerically (supports "1", "2.5", "0", "2a", "IV", etc., see
+    /// `compare_positions`). Unrecognised positions are sorted lexicographically after them.
     /// Books without a position are placed at the end.
     pub fn get_books_in_series(&self, series_id: &str) -> Vec<&Book> {
         let mut books: Vec<&Book> = self
             .books
             .values()
-            .filter(|b| b.series_id.as_deref() == Some(series_id))
+            .filter(|b| b.is_in_series(series_id))
             .collect();
         books.sort_by(|a, b| {
             let a_pos = a.position_in_series.as_deref();
@@ -371,6 +824,236 @@ impl Storage {
         books
     }
 
+    /// Finds a book in the series with the given title (case-insensitive, ignoring
+    /// surrounding whitespace). Used to catch probable duplicates when adding books.
+    pub fn find_book_in_series_by_title(&self, series_id: &str, title: &str) -> Option<&Book> {
+        let title = title.trim().to_lowercase();
+        self.books
+            .values()
+            .find(|b| b.is_in_series(series_id) && b.title.trim().to_lowercase() == title)
+    }
+
+    /// Suggests the book to read next in a series: the first book in series order
+    /// that has never been started, finished or abandoned and comes after the last
+    /// finished or abandoned book. Logging a page counts as starting a book (see
+    /// `is_book_started`). Missing positions are simply skipped. When every book
+    /// after the last finished one has been read, falls back to the earliest book
+    /// that was skipped. Returns `None` when the whole series has been read or started.
+    pub fn next_in_series(&self, series_id: &str) -> Option<&Book> {
+        let books = self.get_books_in_series(series_id);
+        let untouched = |book: &Book| self.most_recent_status_event(&book.id).is_none();
+        let after_last_finished = books
+            .iter()
+            .rposition(|book| {
+                matches!(
+                    self.most_recent_status_event(&book.id),
+                    Some(ReadingEvent::Finished | ReadingEvent::DidNotFinish)
+                )
+            })
+            .map_or(0, |index| index + 1);
+
+        books[after_last_finished..]
+            .iter()
+            .chain(&books[..after_last_finished])
+            .find(|book| untouched(book))
+            .copied()
+    }
+
+    /// Adds a `WantToRead` reading for every book in the series that isn't being read
+    /// (see `is_book_started`), finished or already on the want-to-read list.
+    /// Returns how many books were marked.
+    pub fn mark_series_want_to_read(&mut self, series_id: &str) -> usize {
+        let to_mark: Vec<String> = self
+            .get_books_in_series(series_id)
+            .into_iter()
+            .filter(|book| !self.is_book_started(&book.id) && !self.is_book_finished(&book.id))
+            .filter(|book| {
+                self.most_recent_reading_event(&book.id) != Some(ReadingEvent::WantToRead)
+            })
+            .map(|book| book.id.clone())
+            .collect();
+
+        for book_id in &to_mark {
+            self.add_reading(Reading::new(book_id.clone(), ReadingEvent::WantToRead));
+        }
+        to_mark.len()
+    }
+
+    /// Adds a `Finished` reading for each of the given books, skipping unknown IDs,
+    /// duplicates and books that are already finished.
+    /// Returns how many books were marked.
+    pub fn mark_books_finished(&mut self, ids: &[String]) -> usize {
+        let mut marked = 0;
+        for id in ids {
+            if !self.books.contains_key(id) || self.is_book_finished(id) {
+                continue;
+            }
+            self.add_reading(Reading::new(id.clone(), ReadingEvent::Finished));
+            marked += 1;
+        }
+        marked
+    }
+
+    /// Returns the average rating of a book's rated reviews, or `None` if none are rated.
+    pub fn book_average_rating(&self, book_id: &str) -> Option<f64> {
+        let ratings: Vec<u8> = self
+            .reviews
+            .values()
+            .filter(|r| r.book_id == book_id)
+            .filter_map(|r| r.rating)
+            .collect();
+        if ratings.is_empty() {
+            return None;
+        }
+        Some(ratings.iter().map(|r| *r as f64).sum::<f64>() / ratings.len() as f64)
+    }
+
+    /// Returns the books whose average rating (see `book_average_rating`) is at
+    /// least `min_rating`. Books without a rating are left out.
+    pub fn books_with_min_rating(&self, min_rating: f64) -> Vec<&Book> {
+        self.books
+            .values()
+            .filter(|b| {
+                self.book_average_rating(&b.id)
+                    .is_some_and(|rating| rating >= min_rating)
+            })
+            .collect()
+    }
+
+    /// Returns the average rating across a series and its highest-rated book.
+    ///
+    /// Only books with at least one rated review are considered; each counts once,
+    /// by its own average. Ties go to the earlier book in the series.
+    /// Returns `(None, None)` when no book in the series is rated.
+    pub fn series_rating_summary(&self, series_id: &str) -> (Option<f64>, Option<&Book>) {
+        let rated: Vec<(&Book, f64)> = self
+            .get_books_in_series(series_id)
+            .into_iter()
+            .filter_map(|book| self.book_average_rating(&book.id).map(|avg| (book, avg)))
+            .collect();
+        if rated.is_empty() {
+            return (None, None);
+        }
+
+        let average = rated.iter().map(|(_, avg)| avg).sum::<f64>() / rated.len() as f64;
+        let best = rated
+            .iter()
+            .fold(None, |best: Option<&(&Book, f64)>, entry| match best {
+                Some(b) if b.1 >= entry.1 => Some(b),
+                _ => Some(entry),
+            })
+            .map(|(book, _)| *book);
+        (Some(average), best)
+    }
+
+    /// Returns reading progress for a series: the number of finished books in it,
+    /// and the series' known total length (if set).
+    pub fn series_progress(&self, series_id: &str) -> (usize, Option<u32>) {
+        let finished = self
+            .books
+            .values()
+            .filter(|b| b.series_id.as_deref() == Some(series_id))
+            .filter(|b| self.is_book_finished(&b.id))
+            .count();
+        let total = self.get_series(series_id).and_then(|s| s.total_books);
+        (finished, total)
+    }
+
+    /// Returns the series that have more linked books than their `total_books`,
+    /// sorted by name. Series without a known total are never overfull.
+    pub fn series_overfull(&self) -> Vec<&Series> {
+        let mut overfull: Vec<&Series> = self
+            .series
+            .values()
+            .filter(|s| {
+                s.total_books
+                    .is_some_and(|total| self.get_books_in_series(&s.id).len() > total as usize)
+            })
+            .collect();
+        overfull.sort_by_key(|s| s.name.to_lowercase());
+        overfull
+    }
+
+    /// Returns every inconsistency found in the library, for the `check`
+    /// command. An empty list means no problems were found.
+    pub fn integrity_issues(&self) -> Vec<IntegrityIssue> {
+        let mut issues: Vec<IntegrityIssue> = self
+            .series_overfull()
+            .into_iter()
+            .map(|s| IntegrityIssue {
+                kind: IntegrityIssueKind::OverfullSeries,
+                entity_id: s.id.clone(),
+                detail: format!(
+                    "Series '{}' has {} books but total_books is {}",
+                    s.name,
+                    self.get_books_in_series(&s.id).len(),
+                    s.total_books.unwrap_or_default()
+                ),
+            })
+            .collect();
+
+        let mut dangling: Vec<IntegrityIssue> = self
+            .readings
+            .values()
+            .filter(|r| !self.books.contains_key(&r.book_id))
+            .map(|r| IntegrityIssue {
+                kind: IntegrityIssueKind::DanglingReading,
+                entity_id: r.id.clone(),
+                detail: format!(
+                    "Reading event '{}' refers to missing book {}",
+                    r.event, r.book_id
+                ),
+            })
+            .chain(
+                self.reviews
+                    .values()
+                    .filter(|r| !self.books.contains_key(&r.book_id))
+                    .map(|r| IntegrityIssue {
+                        kind: IntegrityIssueKind::DanglingReview,
+                        entity_id: r.id.clone(),
+                        detail: format!("Review refers to missing book {}", r.book_id),
+                    }),
+            )
+            .chain(
+                self.quotes
+                    .values()
+                    .filter(|q| !self.books.contains_key(&q.book_id))
+                    .map(|q| IntegrityIssue {
+                        kind: IntegrityIssueKind::DanglingQuote,
+                        entity_id: q.id.clone(),
+                        detail: format!("Quote refers to missing book {}", q.book_id),
+                    }),
+            )
+            .collect();
+        dangling.sort_by(|a, b| a.entity_id.cmp(&b.entity_id));
+        issues.extend(dangling);
+
+        let mut mismatched: Vec<IntegrityIssue> = mismatched_keys(&self.books, |b| &b.id, "Book")
+            .chain(mismatched_keys(&self.readings, |r| &r.id, "Reading"))
+            .chain(mismatched_keys(&self.authors, |a| &a.id, "Author"))
+            .chain(mismatched_keys(&self.categories, |c| &c.id, "Category"))
+            .chain(mismatched_keys(&self.reviews, |r| &r.id, "Review"))
+            .chain(mismatched_keys(&self.series, |s| &s.id, "Series"))
+            .chain(mismatched_keys(&self.quotes, |q| &q.id, "Quote"))
+            .collect();
+        mismatched.sort_by(|a, b| a.entity_id.cmp(&b.entity_id));
+        issues.extend(mismatched);
+        issues
+    }
+
+    /// Re-keys every collection so each record is stored under its own `id`,
+    /// fixing lookups such as `get_book(id)` after a bad import. References
+    /// between records are left as they are. Returns how many records were moved.
+    pub fn repair_keys(&mut self) -> usize {
+        rekey(&mut self.books, |b| &b.id)
+            + rekey(&mut self.readings, |r| &r.id)
+            + rekey(&mut self.authors, |a| &a.id)
+            + rekey(&mut self.categories, |c| &c.id)
+            + rekey(&mut self.reviews, |r| &r.id)
+            + rekey(&mut self.series, |s| &s.id)
+            + rekey(&mut self.quotes, |q| &q.id)
+    }
+
     /// Returns the series name for a given book, or an empty string if the book has no series
     pub fn series_name_for_book(&self, book: &Book) -> &str {
         book.series_id
@@ -392,6 +1075,18 @@ impl Storage {
         self.books.get(id)
     }
 
+    /// Returns the first book whose ISBN matches the given one.
+    /// Hyphens and spaces are ignored on both sides (e.g. "978-0-14-241034-9" matches "9780142410349").
+    pub fn get_book_by_isbn(&self, isbn: &str) -> Option<&Book> {
+        let wanted = strip_isbn_separators(isbn);
+        if wanted.is_empty() {
+            return None;
+        }
+        self.books
+            .values()
+            .find(|b| strip_isbn_separators(&b.isbn) == wanted)
+    }
+
     pub fn get_reading(&self, id: &str) -> Option<&Reading> {
         self.readings.get(id)
     }
@@ -404,6 +1099,102 @@ impl Storage {
         self.categories.get(id)
     }
 
+    /// Finds a book by a reference given on the command line: a full ID, an ISBN
+    /// (hyphens and spaces ignored), an exact title (case-insensitive) or a unique
+    /// ID prefix, tried in that order.
+    ///
+    /// Returns an error when nothing matches, or when the reference matches several
+    /// books, listing the candidates so the user can pick a more specific reference.
+    pub fn resolve_book_ref(&self, reference: &str) -> Result<&Book, String> {
+        let reference = reference.trim();
+        if reference.is_empty() {
+            return Err("No book reference given".to_string());
+        }
+        if let Some(book) = self.books.get(reference) {
+            return Ok(book);
+        }
+
+        let isbn = strip_isbn_separators(reference);
+        let title = reference.to_lowercase();
+        let matchers: [&dyn Fn(&Book) -> bool; 3] = [
+            &|b| strip_isbn_separators(&b.isbn) == isbn,
+            &|b| b.title.to_lowercase() == title,
+            &|b| b.id.starts_with(reference),
+        ];
+
+        for matches in matchers {
+            let mut candidates: Vec<&Book> = self.books.values().filter(|b| matches(b)).collect();
+            match candidates.len() {
+                0 => continue,
+                1 => return Ok(candidates[0]),
+                _ => {
+                    candidates.sort_by(|a, b| a.title.cmp(&b.title).then_with(|| a.id.cmp(&b.id)));
+                    let listed: Vec<String> = candidates
+                        .iter()
+                        .map(|b| format!("  {} \"{}\" (ISBN {})", short_id(&b.id), b.title, b.isbn))
+                        .collect();
+                    return Err(format!(
+                        "'{}' matches {} books, use a longer ID prefix:\n{}",
+                        reference,
+                        candidates.len(),
+                        listed.join("\n")
+                    ));
+                }
+            }
+        }
+
+        Err(format!("No book matches '{}'", reference))
+    }
+
+    /// Finds a reading event by its full ID or a unique ID prefix.
+    ///
+    /// Returns an error when nothing matches, or when the prefix matches several
+    /// events, listing them so the user can pick a longer prefix.
+    pub fn resolve_reading_ref(&self, reference: &str) -> Result<&Reading, String> {
+        let reference = reference.trim();
+        if reference.is_empty() {
+            return Err("No event ID given".to_string());
+        }
+        if let Some(reading) = self.readings.get(reference) {
+            return Ok(reading);
+        }
+
+        let mut candidates: Vec<&Reading> = self
+            .readings
+            .values()
+            .filter(|r| r.id.starts_with(reference))
+            .collect();
+        match candidates.len() {
+            0 => Err(format!("No reading event matches '{}'", reference)),
+            1 => Ok(candidates[0]),
+            _ => {
+                candidates.sort_by(|a, b| a.cmp_chronological(b));
+                let listed: Vec<String> = candidates
+                    .iter()
+                    .map(|r| {
+                        let title = self
+                            .get_book(&r.book_id)
+                            .map(|b| b.title.as_str())
+                            .unwrap_or("Unknown Book");
+                        format!(
+                            "  {} {} {} \"{}\"",
+                            short_id(&r.id),
+                            r.created_on.format("%Y-%m-%d"),
+                            r.event,
+                            title
+                        )
+                    })
+                    .collect();
+                Err(format!(
+                    "'{}' matches {} events, use a longer ID prefix:\n{}",
+                    reference,
+                    candidates.len(),
+                    listed.join("\n")
+                ))
+            }
+        }
+    }
+
     pub fn add_review(&mut self, review: Review) -> Option<Review> {
         self.reviews.insert(review.id.clone(), review)
     }
@@ -419,10 +1210,57 @@ impl Storage {
             .values()
             .filter(|r| r.book_id == book_id)
             .collect();
-        reviews.sort_by(|a, b| b.created_on.cmp(&a.created_on));
+        reviews.sort_by_key(|r| std::cmp::Reverse(r.created_on));
         reviews
     }
 
+    pub fn add_quote(&mut self, quote: Quote) -> Option<Quote> {
+        self.quotes.insert(quote.id.clone(), quote)
+    }
+
+    /// Returns all quotes from a given book in page order. Quotes without a page
+    /// come last; quotes on the same page are in the order they were added.
+    pub fn get_quotes_for_book(&self, book_id: &str) -> Vec<&Quote> {
+        let mut quotes: Vec<&Quote> = self
+            .quotes
+            .values()
+            .filter(|q| q.book_id == book_id)
+            .collect();
+        quotes.sort_by(|a, b| {
+            (a.page.is_none(), a.page, a.created_on, &a.id).cmp(&(
+                b.page.is_none(),
+                b.page,
+                b.created_on,
+                &b.id,
+            ))
+        });
+        quotes
+    }
+
+    /// Returns the `limit` most recent reading events, newest first.
+    pub fn recent_readings(&self, limit: usize) -> Vec<&Reading> {
+        let mut readings = self.readings_in_period(None, None);
+        readings.truncate(limit);
+        readings
+    }
+
+    /// Returns reading events with `from <= created_on < to`, newest first.
+    /// A `None` bound leaves that end of the period open.
+    pub fn readings_in_period(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Vec<&Reading> {
+        let mut readings: Vec<&Reading> = self
+            .readings
+            .values()
+            .filter(|r| from.is_none_or(|from| r.created_on >= from))
+            .filter(|r| to.is_none_or(|to| r.created_on < to))
+            .collect();
+        readings.sort_by(|a, b| b.cmp_chronological(a));
+        readings
+    }
+
     pub fn get_readings_by_event(&self, event_type: ReadingEvent) -> Vec<&Reading> {
         self.readings
             .values()
@@ -430,12 +1268,23 @@ impl Storage {
             .collect()
     }
 
+    /// Returns the backlog: books that have never been started or finished.
+    ///
+    /// Only `Started`, `Finished` and page `Update` events take a book out of the
+    /// backlog, so a book that has only been bought or marked as want to read is
+    /// still in it.
     pub fn get_unstarted_books(&self) -> Vec<&Book> {
-        // Get all book IDs that have either started or finished readings
+        // Get all book IDs that have either started or finished readings,
+        // counting page updates as starting the book and abandoned books as finished
         let started_or_finished: std::collections::HashSet<String> = self
             .readings
             .iter()
-            .filter(|(_, r)| matches!(r.event, ReadingEvent::Started | ReadingEvent::Finished))
+            .filter(|(_, r)| {
+                matches!(
+                    r.event,
+                    ReadingEvent::Started | ReadingEvent::Finished | ReadingEvent::DidNotFinish
+                ) || (r.event == ReadingEvent::Update && r.metadata.current_page.is_some())
+            })
             .map(|(_, r)| r.book_id.clone())
             .collect();
 
@@ -446,13 +1295,41 @@ impl Storage {
             .collect()
     }
 
-    /// Returns the most recent reading event for a given book, or None if no readings exist
-    pub fn most_recent_reading_event(&self, book_id: &str) -> Option<ReadingEvent> {
+    /// Returns the most recent reading for a given book, or None if no readings exist.
+    /// Of readings with the same timestamp, the one with the greatest ID counts as the
+    /// most recent (see `Reading::cmp_chronological`).
+    pub fn most_recent_event_for_book(&self, book_id: &str) -> Option<&Reading> {
         self.readings
             .values()
             .filter(|r| r.book_id == book_id)
-            .max_by_key(|r| r.created_on)
-            .map(|r| r.event)
+            .max_by(|a, b| a.cmp_chronological(b))
+    }
+
+    /// Returns the most recent reading event for a given book, or None if no readings exist
+    pub fn most_recent_reading_event(&self, book_id: &str) -> Option<ReadingEvent> {
+        self.most_recent_event_for_book(book_id).map(|r| r.event)
+    }
+
+    /// Returns the books being read whose latest reading event is more than
+    /// `stale_days` days before `now`, with the number of days since then.
+    /// The longest untouched books come first.
+    pub fn stalled_started_books(&self, stale_days: u32, now: DateTime<Utc>) -> Vec<(&Book, i64)> {
+        let mut stalled: Vec<(&Book, i64)> = self
+            .get_started_books()
+            .into_iter()
+            .filter_map(|book| {
+                let last_activity = self
+                    .readings
+                    .values()
+                    .filter(|r| r.book_id == book.id)
+                    .map(|r| r.created_on)
+                    .max()?;
+                let days = (now - last_activity).num_days();
+                (days > stale_days as i64).then_some((book, days))
+            })
+            .collect();
+        stalled.sort_by(|(a, a_days), (b, b_days)| b_days.cmp(a_days).then(a.title.cmp(&b.title)));
+        stalled
     }
 
     pub fn get_started_books(&self) -> Vec<&Book> {
@@ -462,6 +1339,33 @@ impl Storage {
             .collect()
     }
 
+    /// Returns the latest event deciding whether a book is being read or finished:
+    /// `Started`, `Finished`, `DidNotFinish` or an `Update` with a page. An event
+    /// ending the read wins when several happened at the same time, and after that
+    /// the greatest ID, like in `Reading::cmp_chronological`.
+    fn most_recent_status_event(&self, book_id: &str) -> Option<ReadingEvent> {
+        self.readings
+            .values()
+            .filter(|r| r.book_id == book_id)
+            .filter(|r| match r.event {
+                ReadingEvent::Started | ReadingEvent::Finished | ReadingEvent::DidNotFinish => true,
+                ReadingEvent::Update => r.metadata.current_page.is_some(),
+                ReadingEvent::Bought
+                | ReadingEvent::WantToRead
+                | ReadingEvent::UnmarkedAsWantToRead => false,
+            })
+            .max_by(|a, b| {
+                let ends_read = |r: &Reading| {
+                    matches!(r.event, ReadingEvent::Finished | ReadingEvent::DidNotFinish)
+                };
+                a.created_on
+                    .cmp(&b.created_on)
+                    .then_with(|| ends_read(a).cmp(&ends_read(b)))
+                    .then_with(|| a.id.cmp(&b.id))
+            })
+            .map(|r| r.event)
+    }
+
     /// Helper method to get books with a specific event as their most recent reading
     pub fn get_books_by_most_recent_event(&self, target_event: ReadingEvent) -> Vec<&Book> {
         self.books
@@ -470,16 +1374,197 @@ impl Storage {
             .collect()
     }
 
+    /// Returns the finished books (see `is_book_finished`).
     pub fn get_finished_books(&self) -> Vec<&Book> {
-        self.get_books_by_most_recent_event(ReadingEvent::Finished)
+        self.books
+            .values()
+            .filter(|book| self.is_book_finished(&book.id))
+            .collect()
     }
 
+    /// Returns books whose most recent event is `Bought`, leaving out finished
+    /// books (see `is_book_finished`) whose events are out of order.
     pub fn get_bought_books(&self) -> Vec<&Book> {
         self.get_books_by_most_recent_event(ReadingEvent::Bought)
+            .into_iter()
+            .filter(|book| !self.is_book_finished(&book.id))
+            .collect()
+    }
+
+    /// Buckets finished books by page count: under 200, 200–399, 400–599 and 600+.
+    ///
+    /// Every bucket is returned, in that order, even when empty. Books without a
+    /// page count are left out.
+    pub fn finished_page_buckets(&self) -> Vec<(String, usize)> {
+        let mut buckets: Vec<(String, usize)> =
+            ["< 200", "200\u{2013}399", "400\u{2013}599", "600+"]
+                .iter()
+                .map(|label| (label.to_string(), 0))
+                .collect();
+        for book in self.get_finished_books() {
+            let index = match book.total_pages {
+                pages if pages <= 0 => continue,
+                1..=199 => 0,
+                200..=399 => 1,
+                400..=599 => 2,
+                _ => 3,
+            };
+            buckets[index].1 += 1;
+        }
+        buckets
+    }
+
+    /// Returns the average page count of the finished books, leaving out books
+    /// without a page count. Returns `None` when there are no such books.
+    pub fn average_finished_pages(&self) -> Option<f64> {
+        let pages: Vec<i32> = self
+            .get_finished_books()
+            .iter()
+            .map(|book| book.total_pages)
+            .filter(|pages| *pages > 0)
+            .collect();
+        if pages.is_empty() {
+            return None;
+        }
+        Some(pages.iter().map(|p| f64::from(*p)).sum::<f64>() / pages.len() as f64)
     }
 
+    /// Suggests up to `limit` unread books similar to `book_id`.
+    ///
+    /// Only books that have never been started or finished are considered. Books
+    /// by the same author rank above books that only share the category (and
+    /// books sharing both rank highest); ties are broken by title. Books sharing
+    /// neither are left out.
+    pub fn recommendations_for(&self, book_id: &str, limit: usize) -> Vec<&Book> {
+        let Some(book) = self.books.get(book_id) else {
+            return Vec::new();
+        };
+        let mut scored: Vec<(u8, &Book)> = self
+            .get_unstarted_books()
+            .into_iter()
+            .filter(|candidate| candidate.id != book.id)
+            .filter_map(|candidate| {
+                let mut score = 0;
+                if candidate.author_id == book.author_id {
+                    score += 2;
+                }
+                if candidate.category_id == book.category_id {
+                    score += 1;
+                }
+                (score > 0).then_some((score, candidate))
+            })
+            .collect();
+        scored.sort_by(|(a_score, a), (b_score, b)| {
+            b_score
+                .cmp(a_score)
+                .then_with(|| a.title.cmp(&b.title))
+                .then_with(|| a.isbn.cmp(&b.isbn))
+        });
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(_, book)| book)
+            .collect()
+    }
+
+    /// Suggests up to `limit` books to read next, blending three sources in this order:
+    ///
+    /// 1. Books being read that are at least `NEARLY_DONE_PERCENT` through, furthest
+    ///    along first.
+    /// 2. The next book (see `next_in_series`) of every series with a book started,
+    ///    finished or abandoned, by series name.
+    /// 3. The want-to-read list, the books that have waited longest since they were
+    ///    last marked as want-to-read first.
+    ///
+    /// A book that qualifies through more than one source is only listed once, at its
+    /// highest rank.
+    pub fn next_up(&self, limit: usize) -> Vec<&Book> {
+        let mut nearly_done: Vec<(&Book, f64)> = self
+            .get_started_books()
+            .into_iter()
+            .filter_map(|book| {
+                let progress = self.edition_progress(&book.id, None)?;
+                (progress >= NEARLY_DONE_PERCENT).then_some((book, progress))
+            })
+            .collect();
+        nearly_done.sort_by(|(a, a_progress), (b, b_progress)| {
+            b_progress
+                .total_cmp(a_progress)
+                .then_with(|| a.title.cmp(&b.title))
+        });
+
+        let mut series: Vec<&Series> = self
+            .series
+            .values()
+            .filter(|series| {
+                self.get_books_in_series(&series.id)
+                    .iter()
+                    .any(|book| self.most_recent_status_event(&book.id).is_some())
+            })
+            .collect();
+        series.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+        let next_in_series = series
+            .into_iter()
+            .filter_map(|series| self.next_in_series(&series.id));
+
+        let mut want_to_read: Vec<(&Book, DateTime<Utc>)> = self
+            .get_want_to_read_books()
+            .into_iter()
+            .filter_map(|book| {
+                let marked = self
+                    .readings
+                    .values()
+                    .filter(|r| r.book_id == book.id && r.event == ReadingEvent::WantToRead)
+                    .max_by(|a, b| a.cmp_chronological(b))?
+                    .created_on;
+                Some((book, marked))
+            })
+            .collect();
+        want_to_read.sort_by(|(a, a_marked), (b, b_marked)| {
+            a_marked.cmp(b_marked).then_with(|| a.title.cmp(&b.title))
+        });
+
+        let mut seen = std::collections::HashSet::new();
+        nearly_done
+            .into_iter()
+            .map(|(book, _)| book)
+            .chain(next_in_series)
+            .chain(want_to_read.into_iter().map(|(book, _)| book))
+            .filter(|book| seen.insert(book.id.clone()))
+            .take(limit)
+            .collect()
+    }
+
+    /// Returns books that don't belong to any series, sorted by title.
+    pub fn standalone_books(&self) -> Vec<&Book> {
+        let mut books: Vec<&Book> = self
+            .books
+            .values()
+            .filter(|book| book.series_id.is_none())
+            .collect();
+        books.sort_by(|a, b| a.title.cmp(&b.title).then_with(|| a.isbn.cmp(&b.isbn)));
+        books
+    }
+
+    /// Returns books without a usable page count (`total_pages` of zero or less),
+    /// sorted by title. A read-only counterpart to the check in `handle_missing_fields`.
+    pub fn books_missing_pages(&self) -> Vec<&Book> {
+        let mut books: Vec<&Book> = self
+            .books
+            .values()
+            .filter(|book| book.total_pages <= 0)
+            .collect();
+        books.sort_by(|a, b| a.title.cmp(&b.title).then_with(|| a.isbn.cmp(&b.isbn)));
+        books
+    }
+
+    /// Returns books whose most recent event is `WantToRead`, leaving out finished
+    /// books (see `is_book_finished`) whose events are out of order.
     pub fn get_want_to_read_books(&self) -> Vec<&Book> {
         self.get_books_by_most_recent_event(ReadingEvent::WantToRead)
+            .into_iter()
+            .filter(|book| !self.is_book_finished(&book.id))
+            .collect()
     }
 
     /// Returns books that are currently being read or marked as want to read
@@ -511,32 +1596,191 @@ impl Storage {
 
     /// Returns true if the book is currently being read (most recent status-relevant event is Started)
     ///
-    /// Note: Update, Bought, WantToRead, and UnmarkedAsWantToRead events are skipped
-    /// when determining started/finished status — only Started and Finished events matter.
+    /// Note: Bought, WantToRead, and UnmarkedAsWantToRead events are skipped when
+    /// determining started/finished status, and a `DidNotFinish` ends the read. An `Update` with a page number counts as
+    /// starting the book, so logging progress on a book that was never explicitly
+    /// started puts it among the books being read; updates without a page are skipped.
     pub fn is_book_started(&self, book_id: &str) -> bool {
-        let mut readings: Vec<_> = self
+        matches!(
+            self.most_recent_status_event(book_id),
+            Some(ReadingEvent::Started | ReadingEvent::Update)
+        )
+    }
+
+    /// Returns when the current read of a book began: the latest `Started` event
+    /// since the book was last finished or abandoned, or, for books only started by
+    /// logging a page (see `is_book_started`), the first such `Update`.
+    pub fn started_on(&self, book_id: &str) -> Option<DateTime<Utc>> {
+        let readings: Vec<&Reading> = self
             .readings
             .values()
             .filter(|r| r.book_id == book_id)
             .collect();
+        let last_finished = readings
+            .iter()
+            .filter(|r| matches!(r.event, ReadingEvent::Finished | ReadingEvent::DidNotFinish))
+            .map(|r| r.created_on)
+            .max();
+        let current = readings
+            .iter()
+            .filter(|r| last_finished.is_none_or(|finished| r.created_on > finished));
+
+        current
+            .clone()
+            .filter(|r| r.event == ReadingEvent::Started)
+            .map(|r| r.created_on)
+            .max()
+            .or_else(|| {
+                current
+                    .filter(|r| {
+                        r.event == ReadingEvent::Update && r.metadata.current_page.is_some()
+                    })
+                    .map(|r| r.created_on)
+                    .min()
+            })
+    }
 
-        readings.sort_by(|a, b| b.created_on.cmp(&a.created_on));
+    /// Returns true if the book's most recent status-relevant event is `Finished`.
+    ///
+    /// Like `is_book_started`, this skips Bought, WantToRead and UnmarkedAsWantToRead
+    /// events, so a book stays finished even if one of those was recorded after it
+    /// was finished.
+    pub fn is_book_finished(&self, book_id: &str) -> bool {
+        self.most_recent_status_event(book_id) == Some(ReadingEvent::Finished)
+    }
 
-        for reading in readings {
-            match reading.event {
-                ReadingEvent::Started => return true,
-                ReadingEvent::Finished => return false,
-                ReadingEvent::Update
-                | ReadingEvent::Bought
-                | ReadingEvent::WantToRead
-                | ReadingEvent::UnmarkedAsWantToRead => continue,
+    /// Returns the reading velocity (pages per day) for a book.
+    ///
+    /// Measured from the most recent `Started` event to the latest `Update` with a page
+    /// number recorded after it. Returns `None` when there is no such update or no time
+    /// has passed between the two events.
+    pub fn reading_velocity(&self, book_id: &str) -> Option<f64> {
+        let started = self
+            .readings
+            .values()
+            .filter(|r| r.book_id == book_id && r.event == ReadingEvent::Started)
+            .max_by(|a, b| a.cmp_chronological(b))?;
+
+        let (updated, current_page) = self
+            .readings
+            .values()
+            .filter(|r| {
+                r.book_id == book_id
+                    && r.event == ReadingEvent::Update
+                    && r.created_on >= started.created_on
+            })
+            .filter_map(|r| r.metadata.current_page.map(|page| (r, page)))
+            .max_by(|(a, _), (b, _)| a.cmp_chronological(b))?;
+
+        let elapsed_days =
+            (updated.created_on - started.created_on).num_seconds() as f64 / 86_400.0;
+        if elapsed_days <= 0.0 {
+            return None;
+        }
+        Some(current_page as f64 / elapsed_days)
+    }
+
+    /// Returns how far into a book or one of its editions the reader is, as a
+    /// percentage.
+    ///
+    /// With `edition_id` set, only updates for that edition count and its own length is
+    /// used: minutes listened against the duration for audiobooks, pages otherwise.
+    /// With `None`, updates without an edition are measured against the book's pages.
+    /// Returns `None` when there is no such update or the length is unknown.
+    pub fn edition_progress(&self, book_id: &str, edition_id: Option<&str>) -> Option<f64> {
+        let book = self.books.get(book_id)?;
+        let edition = match edition_id {
+            Some(id) => Some(book.editions.iter().find(|e| e.id == id)?),
+            None => None,
+        };
+
+        let latest = self
+            .readings
+            .values()
+            .filter(|r| {
+                r.book_id == book_id
+                    && r.event == ReadingEvent::Update
+                    && r.edition_id.as_deref() == edition_id
+            })
+            .max_by(|a, b| a.cmp_chronological(b))?;
+
+        let (position, length) = match edition {
+            Some(e) if e.format == EditionFormat::Audiobook => {
+                (latest.metadata.current_minute?, e.duration_minutes?)
             }
+            Some(e) => (latest.metadata.current_page?, e.total_pages?),
+            None => (latest.metadata.current_page?, book.total_pages),
+        };
+        if length <= 0 {
+            return None;
         }
-        false
+        Some(position as f64 / length as f64 * 100.0)
     }
 
-    pub fn is_book_finished(&self, book_id: &str) -> bool {
-        self.most_recent_reading_event(book_id) == Some(ReadingEvent::Finished)
+    /// Projects when a book will be finished at its current reading velocity.
+    ///
+    /// Remaining pages are taken from the latest page update of the current read
+    /// (see `started_on`), so a re-read doesn't pick up pages from an earlier read.
+    /// Returns `None` when the total page count is unknown or the velocity can't be
+    /// computed or is zero.
+    pub fn projected_finish_date(
+        &self,
+        book_id: &str,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let book = self.books.get(book_id)?;
+        if book.total_pages <= 0 {
+            return None;
+        }
+
+        let velocity = self.reading_velocity(book_id)?;
+        if velocity <= 0.0 {
+            return None;
+        }
+
+        let started_on = self.started_on(book_id)?;
+        let current_page = self
+            .readings
+            .values()
+            .filter(|r| {
+                r.book_id == book_id
+                    && r.event == ReadingEvent::Update
+                    && r.created_on >= started_on
+            })
+            .filter_map(|r| r.metadata.current_page.map(|page| (r, page)))
+            .max_by(|(a, _), (b, _)| a.cmp_chronological(b))
+            .map(|(_, page)| page)?;
+
+        let remaining_pages = (book.total_pages - current_page).max(0) as f64;
+        let remaining_seconds = (remaining_pages / velocity * 86_400.0).round() as i64;
+        Some(now + chrono::Duration::seconds(remaining_seconds))
+    }
+
+    /// Returns the number of pages left across all books currently being read.
+    ///
+    /// For each started book with a known page count, the pages after the latest
+    /// page update of the current read (see `started_on`) are counted, or the whole
+    /// book when no page has been recorded. Books without a page count add nothing.
+    pub fn total_pages_remaining(&self) -> i64 {
+        self.get_started_books()
+            .into_iter()
+            .filter(|book| book.total_pages > 0)
+            .map(|book| {
+                let started_on = self.started_on(&book.id);
+                let current_page = self
+                    .readings
+                    .values()
+                    .filter(|r| {
+                        r.book_id == book.id
+                            && r.event == ReadingEvent::Update
+                            && started_on.is_none_or(|started| r.created_on >= started)
+                    })
+                    .filter_map(|r| r.metadata.current_page.map(|page| (r, page)))
+                    .max_by(|(a, _), (b, _)| a.cmp_chronological(b))
+                    .map_or(0, |(_, page)| page);
+                i64::from((book.total_pages - current_page).max(0))
+            })
+            .sum()
     }
 
     /// Sorts books by reading status, author name, and title
@@ -598,26 +1842,150 @@ impl Storage {
             .collect()
     }
 
-    /// Returns the earliest year in which a book was finished
-    pub fn get_earliest_finished_year(&self) -> Option<i32> {
+    /// Returns the earliest year (in `tz`) in which a book was finished
+    pub fn get_earliest_finished_year(&self, tz: &Timezone) -> Option<i32> {
         self.readings
             .values()
             .filter(|r| r.event == ReadingEvent::Finished)
-            .map(|r| r.created_on.year())
+            .map(|r| tz.year_of(&r.created_on))
             .min()
     }
 
-    /// Returns all books that were finished in a specific year
-    pub fn get_books_finished_in_year(&self, year: i32) -> Vec<&Book> {
-        let from = Utc
-            .with_ymd_and_hms(year, 1, 1, 0, 0, 0)
-            .single()
-            .expect("Jan 1 00:00:00 is always a valid UTC date");
-        let to = Utc
-            .with_ymd_and_hms(year, 12, 31, 23, 59, 59)
-            .single()
-            .expect("Dec 31 23:59:59 is always a valid UTC date");
-        self.get_read_books_by_time_period(from, to)
+    /// Returns the books finished on a given month and day (local to `tz`) in any
+    /// year, with the year they were finished, newest first.
+    pub fn finished_on_day(&self, month: u32, day: u32, tz: &Timezone) -> Vec<(&Book, i32)> {
+        let mut finished: Vec<(&Book, i32)> = self
+            .readings
+            .values()
+            .filter(|r| r.event == ReadingEvent::Finished)
+            .filter_map(|r| {
+                let date = tz.local_date(&r.created_on);
+                if date.month() != month || date.day() != day {
+                    return None;
+                }
+                self.books.get(&r.book_id).map(|book| (book, date.year()))
+            })
+            .collect();
+        finished.sort_by(|(a, a_year), (b, b_year)| {
+            b_year.cmp(a_year).then_with(|| a.title.cmp(&b.title))
+        });
+        finished
+    }
+
+    /// Returns all books that were finished in a specific year, with years
+    /// following the given timezone
+    pub fn get_books_finished_in_year(&self, year: i32, tz: &Timezone) -> Vec<&Book> {
+        self.readings
+            .values()
+            .filter(|r| r.event == ReadingEvent::Finished && tz.year_of(&r.created_on) == year)
+            .filter_map(|reading| self.books.get(&reading.book_id))
+            .collect()
+    }
+
+    /// Returns the books abandoned (`DidNotFinish`) in a specific year, with years
+    /// following the given timezone, sorted by title.
+    pub fn get_dnf_books_in_year(&self, year: i32, tz: &Timezone) -> Vec<&Book> {
+        let mut books: Vec<&Book> = self
+            .readings
+            .values()
+            .filter(|r| r.event == ReadingEvent::DidNotFinish && tz.year_of(&r.created_on) == year)
+            .filter_map(|reading| self.books.get(&reading.book_id))
+            .collect();
+        books.sort_by(|a, b| a.title.cmp(&b.title).then_with(|| a.isbn.cmp(&b.isbn)));
+        books
+    }
+
+    /// Counts `Finished` events in `year` up to and including day `day_of_year`
+    /// (1 = Jan 1), using local dates in `tz`. Days past the end of the year
+    /// simply count the whole year.
+    pub fn finished_count_through_day_of_year(
+        &self,
+        year: i32,
+        day_of_year: u32,
+        tz: &Timezone,
+    ) -> usize {
+        self.readings
+            .values()
+            .filter(|r| r.event == ReadingEvent::Finished)
+            .map(|r| tz.local_date(&r.created_on))
+            .filter(|date| date.year() == year && date.ordinal() <= day_of_year)
+            .count()
+    }
+
+    /// Counts finished books per category, optionally limited to one year.
+    ///
+    /// Every `Finished` event is counted (so re-reads count again), matching
+    /// `get_books_finished_in_year`. Books whose category no longer exists are
+    /// bucketed under "Uncategorized". Sorted by count descending, then name.
+    pub fn finished_counts_by_category(
+        &self,
+        year: Option<i32>,
+        tz: &Timezone,
+    ) -> Vec<(String, usize)> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for reading in self.readings.values() {
+            if reading.event != ReadingEvent::Finished {
+                continue;
+            }
+            if year.is_some_and(|y| tz.year_of(&reading.created_on) != y) {
+                continue;
+            }
+            let Some(book) = self.books.get(&reading.book_id) else {
+                continue;
+            };
+            let name = self
+                .categories
+                .get(&book.category_id)
+                .map(|c| c.name.clone())
+                .unwrap_or_else(|| "Uncategorized".to_string());
+            *counts.entry(name).or_insert(0) += 1;
+        }
+
+        let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        counts
+    }
+
+    /// Returns the earliest year (in `tz`) in which a book was added to the library
+    pub fn get_earliest_added_year(&self, tz: &Timezone) -> Option<i32> {
+        self.books.values().map(|b| tz.year_of(&b.added_on)).min()
+    }
+
+    /// Returns all books that were added to the library in a specific year.
+    ///
+    /// Uses the same year boundaries as `get_books_finished_in_year`.
+    pub fn books_added_in_year(&self, year: i32, tz: &Timezone) -> Vec<&Book> {
+        self.books
+            .values()
+            .filter(|b| tz.year_of(&b.added_on) == year)
+            .collect()
+    }
+
+    /// Returns what was spent on books, summed per currency, from the prices on
+    /// `Bought` events, optionally limited to one year. Prices entered without
+    /// a currency are summed under an empty string.
+    pub fn spending_by_currency(&self, year: Option<i32>, tz: &Timezone) -> BTreeMap<String, f64> {
+        let mut spent: BTreeMap<String, f64> = BTreeMap::new();
+        for reading in self.readings.values() {
+            if reading.event != ReadingEvent::Bought {
+                continue;
+            }
+            if year.is_some_and(|y| tz.year_of(&reading.created_on) != y) {
+                continue;
+            }
+            if let Some(price) = reading.metadata.price {
+                let currency = reading.metadata.currency.clone().unwrap_or_default();
+                *spent.entry(currency).or_insert(0.0) += price;
+            }
+        }
+        spent
+    }
+
+    /// Returns the total price of all `Bought` events, optionally limited to one
+    /// year. Prices in different currencies are added together as-is, so use
+    /// `spending_by_currency` when more than one currency is in use.
+    pub fn total_spent(&self, year: Option<i32>, tz: &Timezone) -> f64 {
+        self.spending_by_currency(year, tz).values().sum()
     }
 
     /// Sets a yearly reading goal (number of books to finish).
@@ -630,6 +1998,149 @@ impl Storage {
         self.goals.get(&year).copied()
     }
 
+    /// Suggests a reading goal for `current_year`: the average number of books
+    /// finished per year over the three years before it, rounded to the nearest
+    /// book. Years before the first finished book don't count, and without any
+    /// finished books in those years the suggestion is `DEFAULT_GOAL_SUGGESTION`.
+    pub fn suggested_goal(&self, current_year: i32, tz: &Timezone) -> u32 {
+        let first_year = match self.get_earliest_finished_year(tz) {
+            Some(earliest) => earliest.max(current_year - 3),
+            None => return DEFAULT_GOAL_SUGGESTION,
+        };
+        if first_year >= current_year {
+            return DEFAULT_GOAL_SUGGESTION;
+        }
+
+        let years = first_year..current_year;
+        let year_count = years.len() as f64;
+        let finished: usize = years
+            .map(|year| self.get_books_finished_in_year(year, tz).len())
+            .sum();
+        ((finished as f64 / year_count).round() as u32).max(1)
+    }
+
+    /// Moves every book in the category named `from_name` to the one named `to_name`,
+    /// creating the target category if it doesn't exist. Names match case-insensitively.
+    ///
+    /// With `delete_source`, the source category is removed once it's empty.
+    /// Returns the number of books moved, or an error if the source category doesn't
+    /// exist or both names refer to the same category.
+    pub fn recategorize(
+        &mut self,
+        from_name: &str,
+        to_name: &str,
+        delete_source: bool,
+    ) -> Result<usize, String> {
+        let find = |categories: &HashMap<String, Category>, name: &str| {
+            let wanted = name.trim().to_lowercase();
+            categories
+                .values()
+                .find(|c| c.name.to_lowercase() == wanted)
+                .map(|c| c.id.clone())
+        };
+
+        let from_id = find(&self.categories, from_name)
+            .ok_or_else(|| format!("No category named '{}'", from_name.trim()))?;
+        let to_id = match find(&self.categories, to_name) {
+            Some(id) if id == from_id => {
+                return Err(format!(
+                    "'{}' and '{}' are the same category",
+                    from_name.trim(),
+                    to_name.trim()
+                ))
+            }
+            Some(id) => id,
+            None => {
+                let name = to_name.trim();
+                if name.is_empty() {
+                    return Err(StorageError::EmptyName.into());
+                }
+                let category = Category::new(name.to_string(), None);
+                let id = category.id.clone();
+                self.add_category(category);
+                id
+            }
+        };
+
+        let mut moved = 0;
+        for book in self.books.values_mut() {
+            if book.category_id == from_id {
+                book.category_id = to_id.clone();
+                moved += 1;
+            }
+        }
+
+        if delete_source {
+            self.categories.remove(&from_id);
+        }
+        Ok(moved)
+    }
+
+    /// Sets how many books to finish in a category during `year`.
+    ///
+    /// The category is looked up by name (case-insensitive) and stored under its
+    /// canonical name. Returns an error if no such category exists.
+    pub fn set_category_goal(
+        &mut self,
+        year: i32,
+        category: &str,
+        target: u32,
+    ) -> Result<(), String> {
+        let wanted = category.trim().to_lowercase();
+        let name = self
+            .categories
+            .values()
+            .find(|c| c.name.to_lowercase() == wanted)
+            .map(|c| c.name.clone())
+            .ok_or_else(|| format!("No category named '{}'", category.trim()))?;
+        self.category_goals
+            .entry(year)
+            .or_default()
+            .insert(name, target);
+        Ok(())
+    }
+
+    /// Returns `(category, target, finished)` for every category goal set for
+    /// `year`, sorted by category name. Finished counts come from
+    /// `finished_counts_by_category` for that year.
+    pub fn challenge_status(&self, year: i32, tz: &Timezone) -> Vec<(String, u32, usize)> {
+        let Some(targets) = self.category_goals.get(&year) else {
+            return Vec::new();
+        };
+        let finished = self.finished_counts_by_category(Some(year), tz);
+        let mut status: Vec<(String, u32, usize)> = targets
+            .iter()
+            .map(|(name, &target)| {
+                let count = finished
+                    .iter()
+                    .find(|(category, _)| category == name)
+                    .map(|(_, count)| *count)
+                    .unwrap_or(0);
+                (name.clone(), target, count)
+            })
+            .collect();
+        status.sort_by_key(|(name, _, _)| name.to_lowercase());
+        status
+    }
+
+    /// Returns `(year, target, finished)` for every year with a goal, oldest first.
+    /// Finished books are counted by the year (in `tz`) they were finished.
+    pub fn goal_vs_actual(&self, tz: &Timezone) -> Vec<(i32, u32, usize)> {
+        let mut history: Vec<(i32, u32, usize)> = self
+            .goals
+            .iter()
+            .map(|(&year, &target)| {
+                (
+                    year,
+                    target,
+                    self.get_books_finished_in_year(year, tz).len(),
+                )
+            })
+            .collect();
+        history.sort_by_key(|(year, _, _)| *year);
+        history
+    }
+
     /// Removes the reading goal for a given year, returning the previous value if it existed.
     pub fn remove_goal(&mut self, year: i32) -> Option<u32> {
         self.goals.remove(&year)
@@ -637,8 +2148,9 @@ impl Storage {
 }
 
 /// Compares two optional position strings for sorting.
-/// Numeric positions (e.g. "1", "2.5") are sorted numerically.
-/// Non-numeric positions are sorted lexicographically after all numeric ones.
+/// Positions are ordered by their numeric part (see `parse_position_key`), then by
+/// letter suffix, so "1" < "1.5" < "2a" < "2b" < "3".
+/// Unrecognised positions are sorted lexicographically after all others.
 /// `None` is sorted last.
 pub fn compare_positions(a: Option<&str>, b: Option<&str>) -> std::cmp::Ordering {
     match (a, b) {
@@ -646,12 +2158,11 @@ pub fn compare_positions(a: Option<&str>, b: Option<&str>) -> std::cmp::Ordering
         (None, Some(_)) => std::cmp::Ordering::Greater,
         (Some(_), None) => std::cmp::Ordering::Less,
         (Some(a_str), Some(b_str)) => {
-            let a_num = a_str.parse::<f64>().ok();
-            let b_num = b_str.parse::<f64>().ok();
-            match (a_num, b_num) {
-                (Some(a_val), Some(b_val)) => a_val
+            match (parse_position_key(a_str), parse_position_key(b_str)) {
+                (Some((a_val, a_suffix)), Some((b_val, b_suffix))) => a_val
                     .partial_cmp(&b_val)
-                    .unwrap_or(std::cmp::Ordering::Equal),
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| a_suffix.cmp(&b_suffix)),
                 (Some(_), None) => std::cmp::Ordering::Less,
                 (None, Some(_)) => std::cmp::Ordering::Greater,
                 (None, None) => a_str.cmp(b_str),
@@ -660,6 +2171,41 @@ pub fn compare_positions(a: Option<&str>, b: Option<&str>) -> std::cmp::Ordering
     }
 }
 
+/// Splits a series position into a numeric value and a lowercase letter suffix.
+///
+/// Accepts plain numbers ("3", "2.5") and a number followed by letters ("2a", "2B").
+/// Returns `None` for anything else.
+pub fn parse_position_key(position: &str) -> Option<(f64, String)> {
+    let trimmed = position.trim();
+    if let Ok(value) = trimmed.parse::<f64>() {
+        return Some((value, String::new()));
+    }
+
+    let split = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(trimmed.len());
+    let (number, suffix) = trimmed.split_at(split);
+    if !number.is_empty() && !suffix.is_empty() && suffix.chars().all(|c| c.is_ascii_alphabetic()) {
+        if let Ok(value) = number.parse::<f64>() {
+            return Some((value, suffix.to_lowercase()));
+        }
+    }
+
+    None
+}
+
+/// Returns the first 8 characters of an ID, enough to tell books apart in practice.
+pub fn short_id(id: &str) -> &str {
+    id.get(..8).unwrap_or(id)
+}
+
+/// Removes hyphens and whitespace from an ISBN so differently formatted ISBNs compare equal.
+pub fn strip_isbn_separators(isbn: &str) -> String {
+    isbn.chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .collect()
+}
+
 pub fn sort_json_value(value: Value) -> Value {
     match value {
         Value::Object(map) => {
@@ -679,25 +2225,50 @@ pub fn write_storage(
     storage_path: &str,
     storage: &Storage,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    backend_for_path(storage_path).save(storage)
+}
+
+/// Returns where `backup` puts a snapshot by default: next to the storage file,
+/// named after it with the local time appended, e.g. `storage-20250301-091500.json`.
+pub fn default_backup_path(storage_path: &str, now: NaiveDateTime) -> PathBuf {
     let path = Path::new(storage_path);
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "storage".to_string());
+    let extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "json".to_string());
+    path.with_file_name(format!(
+        "{}-{}.{}",
+        stem,
+        now.format("%Y%m%d-%H%M%S"),
+        extension
+    ))
+}
 
-    // Ensure the parent directory exists
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)?;
+/// Copies the storage file byte-for-byte to `destination`, creating parent
+/// directories if they don't exist.
+pub fn backup_storage(
+    storage_path: &str,
+    destination: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if destination == Path::new(storage_path) {
+        return Err("The backup path is the storage file itself".into());
     }
-
-    // Write the storage data using the new method
-    fs::write(path, storage.to_sorted_json_string()?)?;
-
+    if let Some(parent) = destination.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::copy(storage_path, destination)?;
     Ok(())
 }
 
 pub fn initialize_storage_file(storage_path: &str) -> Result<(), Box<dyn std::error::Error>> {
-    let path = Path::new(storage_path);
+    let backend = backend_for_path(storage_path);
 
-    if !path.exists() {
-        let initial_storage = Storage::new();
-        write_storage(storage_path, &initial_storage)?;
+    if !backend.exists() {
+        backend.save(&Storage::new())?;
     }
 
     Ok(())
@@ -713,6 +2284,8 @@ pub trait RepairPrompter {
         &self,
         reading_id: &str,
     ) -> Result<BookRepairInput, Box<dyn std::error::Error>>;
+    /// Shows every problem found before any are repaired, in `RepairMode::SummaryFirst`.
+    fn show_summary(&self, _issues: &[MissingField]) {}
 }
 
 /// Input data needed to repair a missing book reference
@@ -724,156 +2297,278 @@ pub struct BookRepairInput {
     pub category_name: String,
 }
 
-pub fn handle_missing_fields(
-    storage: &mut Storage,
-    storage_path: &str,
-    prompter: &dyn RepairPrompter,
-) -> Result<(), Box<dyn std::error::Error>> {
-    // First, collect all missing references
-    let mut missing_authors: Vec<(String, String)> = Vec::new(); // (book_id, book_title)
-    let mut missing_categories: Vec<(String, String)> = Vec::new(); // (book_id, book_title)
-    let mut missing_books: Vec<(String, String)> = Vec::new(); // (reading_id, book_id)
-    let mut books_missing_fields: Vec<String> = Vec::new(); // book_ids
-    let mut orphaned_series: Vec<String> = Vec::new(); // book_ids with invalid series_id
+/// A broken or missing piece of data that `handle_missing_fields` can repair.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MissingField {
+    /// A book refers to an author that doesn't exist.
+    Author { book_id: String, book_title: String },
+    /// A book refers to a category that doesn't exist.
+    Category { book_id: String, book_title: String },
+    /// A book refers to a series that doesn't exist. Cleared without asking,
+    /// since series are optional.
+    Series { book_id: String, book_title: String },
+    /// A book has no page count.
+    TotalPages { book_id: String, book_title: String },
+    /// A reading event refers to a book that doesn't exist.
+    Book { reading_id: String, book_id: String },
+}
 
-    // Check books for missing fields and references
-    for (book_id, book) in storage.books.iter() {
-        if !storage.authors.contains_key(&book.author_id) {
-            missing_authors.push((book_id.clone(), book.title.clone()));
-        }
-        if !storage.categories.contains_key(&book.category_id) {
-            missing_categories.push((book_id.clone(), book.title.clone()));
-        }
-        if book.total_pages <= 0 {
-            books_missing_fields.push(book_id.clone());
-        }
-        if let Some(ref sid) = book.series_id {
-            if !storage.series.contains_key(sid) {
-                orphaned_series.push(book_id.clone());
+impl MissingField {
+    /// Describes the problem in one line, for the summary shown before repairing.
+    pub fn describe(&self) -> String {
+        match self {
+            MissingField::Author { book_title, .. } => {
+                format!("Book '{}' references a missing author", book_title)
             }
+            MissingField::Category { book_title, .. } => {
+                format!("Book '{}' references a missing category", book_title)
+            }
+            MissingField::Series { book_title, .. } => format!(
+                "Book '{}' references a missing series (the link will be removed)",
+                book_title
+            ),
+            MissingField::TotalPages { book_title, .. } => {
+                format!("Book '{}' is missing total pages", book_title)
+            }
+            MissingField::Book {
+                reading_id,
+                book_id,
+            } => format!("Reading {} references missing book {}", reading_id, book_id),
         }
     }
+}
 
-    // Check readings for missing book references
-    for (reading_id, reading) in storage.readings.iter() {
-        if !storage.books.contains_key(&reading.book_id) {
-            missing_books.push((reading_id.clone(), reading.book_id.clone()));
-        }
-    }
-
-    // Handle missing authors — create new author AND update book's author_id
-    for (book_id, book_title) in missing_authors {
-        let author_name = prompter.prompt_author_name(&book_title)?;
+/// How `handle_missing_fields` goes through the problems it finds.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum RepairMode {
+    /// Ask about one problem at a time, saving after each fix.
+    #[default]
+    Incremental,
+    /// List every problem first, then ask about each and save once at the end.
+    /// What has been fixed is still saved if a prompt fails.
+    SummaryFirst,
+}
 
-        let author = Author::new(author_name.trim().to_string());
-        let new_author_id = author.id.clone();
-        storage.add_author(author);
+/// Finds everything `handle_missing_fields` would repair, in the order it asks
+/// about them: missing authors, categories and series, missing page counts, and
+/// finally readings of missing books. Each kind is sorted by book title or reading ID.
+pub fn detect_missing_fields(storage: &Storage) -> Vec<MissingField> {
+    let mut books: Vec<&Book> = storage.books.values().collect();
+    books.sort_by(|a, b| a.title.cmp(&b.title).then(a.id.cmp(&b.id)));
+    let book_issue = |book: &Book| (book.id.clone(), book.title.clone());
 
-        // Update the book's author_id to point to the new author
-        if let Some(book) = storage.books.get_mut(&book_id) {
-            book.author_id = new_author_id;
+    let mut issues: Vec<MissingField> = Vec::new();
+    issues.extend(
+        books
+            .iter()
+            .filter(|b| !storage.authors.contains_key(&b.author_id))
+            .map(|b| {
+                let (book_id, book_title) = book_issue(b);
+                MissingField::Author {
+                    book_id,
+                    book_title,
+                }
+            }),
+    );
+    issues.extend(
+        books
+            .iter()
+            .filter(|b| !storage.categories.contains_key(&b.category_id))
+            .map(|b| {
+                let (book_id, book_title) = book_issue(b);
+                MissingField::Category {
+                    book_id,
+                    book_title,
+                }
+            }),
+    );
+    issues.extend(
+        books
+            .iter()
+            .filter(|b| {
+                b.series_id
+                    .as_ref()
+                    .is_some_and(|sid| !storage.series.contains_key(sid))
+            })
+            .map(|b| {
+                let (book_id, book_title) = book_issue(b);
+                MissingField::Series {
+                    book_id,
+                    book_title,
+                }
+            }),
+    );
+    issues.extend(books.iter().filter(|b| b.total_pages <= 0).map(|b| {
+        let (book_id, book_title) = book_issue(b);
+        MissingField::TotalPages {
+            book_id,
+            book_title,
         }
+    }));
+
+    let mut readings: Vec<&Reading> = storage
+        .readings
+        .values()
+        .filter(|r| !storage.books.contains_key(&r.book_id))
+        .collect();
+    readings.sort_by(|a, b| a.id.cmp(&b.id));
+    issues.extend(readings.into_iter().map(|r| MissingField::Book {
+        reading_id: r.id.clone(),
+        book_id: r.book_id.clone(),
+    }));
+    issues
+}
 
-        // Save after each fix
-        write_storage(storage_path, storage)?;
-    }
+/// Repairs missing references and fields, asking `prompter` for what's needed and
+/// saving after each fix (see `handle_missing_fields_with_mode`).
+pub fn handle_missing_fields(
+    storage: &mut Storage,
+    storage_path: &str,
+    prompter: &dyn RepairPrompter,
+) -> Result<(), Box<dyn std::error::Error>> {
+    handle_missing_fields_with_mode(storage, storage_path, prompter, RepairMode::Incremental)
+}
 
-    // Handle missing categories — create new category AND update book's category_id
-    for (book_id, book_title) in missing_categories {
-        let category_name = prompter.prompt_category_name(&book_title)?;
+/// Repairs everything `detect_missing_fields` finds, going through the problems
+/// as `mode` says.
+pub fn handle_missing_fields_with_mode(
+    storage: &mut Storage,
+    storage_path: &str,
+    prompter: &dyn RepairPrompter,
+    mode: RepairMode,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let issues = detect_missing_fields(storage);
+    if issues.is_empty() {
+        return Ok(());
+    }
 
-        let category = Category::new(category_name.trim().to_string(), None);
-        let new_category_id = category.id.clone();
-        storage.add_category(category);
+    if mode == RepairMode::SummaryFirst {
+        prompter.show_summary(&issues);
+    }
 
-        // Update the book's category_id to point to the new category
-        if let Some(book) = storage.books.get_mut(&book_id) {
-            book.category_id = new_category_id;
+    for issue in &issues {
+        if let Err(e) = repair_missing_field(storage, prompter, issue) {
+            if mode == RepairMode::SummaryFirst {
+                // Keep what was fixed before the failing prompt
+                write_storage(storage_path, storage)?;
+            }
+            return Err(e);
+        }
+        if mode == RepairMode::Incremental {
+            write_storage(storage_path, storage)?;
         }
+    }
 
-        // Save after each fix
+    if mode == RepairMode::SummaryFirst {
         write_storage(storage_path, storage)?;
     }
+    Ok(())
+}
 
-    // Handle orphaned series_id — silently clear since series is optional
-    if !orphaned_series.is_empty() {
-        for book_id in &orphaned_series {
+/// Repairs a single problem, asking `prompter` for any details needed.
+fn repair_missing_field(
+    storage: &mut Storage,
+    prompter: &dyn RepairPrompter,
+    issue: &MissingField,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match issue {
+        // Create a new author AND update the book's author_id
+        MissingField::Author {
+            book_id,
+            book_title,
+        } => {
+            let author_name = prompter.prompt_author_name(book_title)?;
+            let author = Author::new(author_name.trim().to_string());
+            let new_author_id = author.id.clone();
+            storage.add_author(author);
+            if let Some(book) = storage.books.get_mut(book_id) {
+                book.author_id = new_author_id;
+            }
+        }
+        // Create a new category AND update the book's category_id
+        MissingField::Category {
+            book_id,
+            book_title,
+        } => {
+            let category_name = prompter.prompt_category_name(book_title)?;
+            let category = Category::new(category_name.trim().to_string(), None);
+            let new_category_id = category.id.clone();
+            storage.add_category(category);
+            if let Some(book) = storage.books.get_mut(book_id) {
+                book.category_id = new_category_id;
+            }
+        }
+        // Silently clear the series since it is optional
+        MissingField::Series { book_id, .. } => {
             if let Some(book) = storage.books.get_mut(book_id) {
                 book.series_id = None;
                 book.position_in_series = None;
             }
         }
-        write_storage(storage_path, storage)?;
-    }
-
-    // Handle books with missing fields
-    for book_id in books_missing_fields {
-        let book_title = storage
-            .books
-            .get(&book_id)
-            .map(|b| b.title.clone())
-            .unwrap_or_default();
-
-        let total_pages = prompter.prompt_total_pages(&book_title)?;
-
-        if let Some(book) = storage.books.get_mut(&book_id) {
-            book.total_pages = total_pages;
+        MissingField::TotalPages {
+            book_id,
+            book_title,
+        } => {
+            let total_pages = prompter.prompt_total_pages(book_title)?;
+            if let Some(book) = storage.books.get_mut(book_id) {
+                book.total_pages = total_pages;
+            }
         }
-
-        // Save after each book's total_pages is updated
-        write_storage(storage_path, storage)?;
-    }
-
-    // Handle missing books — create book with new author and category,
-    // then update the orphaned reading's book_id to point to the new book
-    for (reading_id, _book_id) in missing_books {
-        let input = prompter.prompt_book_details(&reading_id)?;
-
-        // Create author
-        let author = Author::new(input.author_name.trim().to_string());
-        let author_id = author.id.clone();
-        storage.add_author(author);
-
-        // Create category
-        let category = Category::new(input.category_name.trim().to_string(), None);
-        let category_id = category.id.clone();
-        storage.add_category(category);
-
-        // Create and add the book
-        let book = Book::new(
-            input.title.trim().to_string(),
-            input.isbn.trim().to_string(),
-            category_id,
-            author_id,
-            input.total_pages,
-        );
-        let new_book_id = book.id.clone();
-        storage.add_book(book);
-
-        // Update the reading's book_id to point to the newly created book
-        if let Some(reading) = storage.readings.get_mut(&reading_id) {
-            reading.book_id = new_book_id;
+        // Create the book with a new author and category, then point the
+        // orphaned reading at it
+        MissingField::Book { reading_id, .. } => {
+            let input = prompter.prompt_book_details(reading_id)?;
+
+            let author = Author::new(input.author_name.trim().to_string());
+            let author_id = author.id.clone();
+            storage.add_author(author);
+
+            let category = Category::new(input.category_name.trim().to_string(), None);
+            let category_id = category.id.clone();
+            storage.add_category(category);
+
+            let book = Book::new(
+                input.title.trim().to_string(),
+                input.isbn.trim().to_string(),
+                category_id,
+                author_id,
+                input.total_pages,
+            );
+            let new_book_id = book.id.clone();
+            storage.add_book(book);
+
+            if let Some(reading) = storage.readings.get_mut(reading_id) {
+                reading.book_id = new_book_id;
+            }
         }
-
-        // Save after book is added
-        write_storage(storage_path, storage)?;
     }
-
     Ok(())
 }
 
 pub fn load_storage(storage_path: &str) -> Result<Storage, Box<dyn std::error::Error>> {
-    let contents = fs::read_to_string(storage_path)?;
-    let storage: Storage = serde_json::from_str(&contents)?;
-    Ok(storage)
+    backend_for_path(storage_path).load()
 }
 
 /// Loads storage and repairs any missing references using the given prompter
 pub fn load_and_repair_storage(
     storage_path: &str,
     prompter: &dyn RepairPrompter,
+) -> Result<Storage, Box<dyn std::error::Error>> {
+    load_and_repair_storage_with_mode(storage_path, prompter, RepairMode::Incremental)
+}
+
+/// Like `load_and_repair_storage`, going through the problems as `mode` says.
+pub fn load_and_repair_storage_with_mode(
+    storage_path: &str,
+    prompter: &dyn RepairPrompter,
+    mode: RepairMode,
 ) -> Result<Storage, Box<dyn std::error::Error>> {
     let mut storage = load_storage(storage_path)?;
-    handle_missing_fields(&mut storage, storage_path, prompter)?;
+    // Re-key first, or readings of a book stored under the wrong key would
+    // look orphaned and the user would be asked to recreate the book.
+    if storage.repair_keys() > 0 {
+        write_storage(storage_path, &storage)?;
+    }
+    handle_missing_fields_with_mode(&mut storage, storage_path, prompter, mode)?;
     Ok(storage)
 }
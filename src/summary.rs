@@ -0,0 +1,80 @@
+use crate::storage::Storage;
+
+/// Counts describing the whole library, as shown by the `summary` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LibrarySummary {
+    pub total: usize,
+    pub finished: usize,
+    pub reading: usize,
+    pub backlog: usize,
+    pub want_to_read: usize,
+    pub authors: usize,
+    pub series: usize,
+    pub reviews: usize,
+    /// Pages left in the books currently being read (see `Storage::total_pages_remaining`)
+    pub pages_remaining: usize,
+}
+
+impl LibrarySummary {
+    /// Computes the summary for the given storage.
+    pub fn from_storage(storage: &Storage) -> Self {
+        Self {
+            total: storage.books.len(),
+            finished: storage.get_finished_books().len(),
+            reading: storage.get_started_books().len(),
+            backlog: storage.get_unstarted_books().len(),
+            want_to_read: storage.get_want_to_read_books().len(),
+            authors: storage.authors.len(),
+            series: storage.series.len(),
+            reviews: storage.reviews.len(),
+            pages_remaining: usize::try_from(storage.total_pages_remaining()).unwrap_or(0),
+        }
+    }
+
+    /// Returns `(porcelain key, label, value)` triples in a fixed order.
+    fn fields(&self) -> Vec<(&'static str, &'static str, usize)> {
+        vec![
+            ("total", "Books", self.total),
+            ("finished", "Finished", self.finished),
+            ("reading", "Reading", self.reading),
+            ("backlog", "Backlog", self.backlog),
+            ("want_to_read", "Want to read", self.want_to_read),
+            ("authors", "Authors", self.authors),
+            ("series", "Series", self.series),
+            ("reviews", "Reviews", self.reviews),
+            ("pages_remaining", "Pages left", self.pages_remaining),
+        ]
+    }
+
+    /// Formats the summary for humans, one labelled count per line.
+    pub fn format_pretty(&self) -> String {
+        let fields = self.fields();
+        let width = fields
+            .iter()
+            .map(|(_, label, _)| label.len())
+            .max()
+            .unwrap_or(0);
+        let mut lines = vec!["Library summary".to_string()];
+        for (_, label, value) in fields {
+            lines.push(format!(
+                "  {:<width$}  {}",
+                format!("{}:", label),
+                value,
+                width = width + 1
+            ));
+        }
+        lines.join("\n")
+    }
+
+    /// Formats the summary as stable `key=value` lines for scripts.
+    ///
+    /// Keys and their order are part of the output contract: new keys may be
+    /// appended, but existing ones are never renamed or reordered.
+    pub fn format_porcelain(&self) -> String {
+        self.fields()
+            .into_iter()
+            .map(|(key, _, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
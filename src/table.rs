@@ -1,4 +1,11 @@
-use unicode_width::UnicodeWidthStr;
+use crate::config::DEFAULT_DATE_FORMAT;
+use std::io::{self, IsTerminal, Write};
+use terminal_size::{terminal_size, Width};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Narrowest a column (including its padding) is shrunk to when fitting a table
+/// to the terminal.
+const MIN_COLUMN_WIDTH: usize = 5;
 
 /// Column alignment for table cells.
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
@@ -12,6 +19,19 @@ pub enum Alignment {
     Right,
 }
 
+/// How a table is drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TableStyle {
+    /// Bordered with `+`, `|`, `=` and `-` (the default).
+    #[default]
+    Boxed,
+    /// Aligned columns separated by spaces, with no borders or separator
+    /// lines. Handy for pasting into emails.
+    Plain,
+    /// A GitHub-flavored markdown table.
+    Markdown,
+}
+
 /// A row in a structured table that supports series grouping.
 ///
 /// - `Header`: Column headers (rendered with thick `=` separators).
@@ -33,6 +53,36 @@ pub enum TableRow {
     GroupHeader(String, usize),
 }
 
+/// Reverses the order of a structured table's rows, keeping groups together.
+///
+/// The header stays first. Groups (a `GroupHeader` and its `Data` rows) and
+/// ungrouped `Data` rows swap places as blocks, and the rows within each group
+/// are reversed too, so the books come out in exactly the opposite order.
+pub fn reverse_table_rows(rows: Vec<TableRow>) -> Vec<TableRow> {
+    let mut header = Vec::new();
+    let mut blocks: Vec<Vec<TableRow>> = Vec::new();
+    let mut group_rows_remaining = 0;
+    for row in rows {
+        match row {
+            TableRow::Header(_) => header.push(row),
+            TableRow::GroupHeader(_, count) => {
+                group_rows_remaining = count;
+                blocks.push(vec![row]);
+            }
+            TableRow::Data(_) if group_rows_remaining > 0 => {
+                group_rows_remaining -= 1;
+                match blocks.last_mut() {
+                    Some(block) => block.insert(1, row),
+                    None => blocks.push(vec![row]),
+                }
+            }
+            TableRow::Data(_) => blocks.push(vec![row]),
+        }
+    }
+    header.extend(blocks.into_iter().rev().flatten());
+    header
+}
+
 /// Formats a structured table with support for group headers and column alignment.
 ///
 /// Group headers span the full table width as a left-aligned, indented label.
@@ -46,6 +96,17 @@ pub enum TableRow {
 ///
 /// The first row must be a `Header` variant.
 pub fn format_structured_table(rows: &[TableRow], alignments: &[Alignment]) -> String {
+    format_structured_table_fitted(rows, alignments, None)
+}
+
+/// Like `format_structured_table`, but when `max_width` is given and the table
+/// would be wider, the widest columns are shrunk (see `fit_to_width`) and cells
+/// that no longer fit are cut off with an ellipsis.
+pub fn format_structured_table_fitted(
+    rows: &[TableRow],
+    alignments: &[Alignment],
+    max_width: Option<usize>,
+) -> String {
     if rows.is_empty() {
         return String::new();
     }
@@ -78,6 +139,10 @@ pub fn format_structured_table(rows: &[TableRow], alignments: &[Alignment]) -> S
         }
     }
 
+    if let Some(max_width) = max_width {
+        col_widths = fit_to_width(&col_widths, max_width);
+    }
+
     let total_width = col_widths.iter().sum::<usize>() + col_count + 1; // +1 for each `|` and outer `|`
 
     let mut output = String::new();
@@ -106,7 +171,11 @@ pub fn format_structured_table(rows: &[TableRow], alignments: &[Alignment]) -> S
             TableRow::GroupHeader(label, count) => {
                 // Start a new group with the specified number of Data rows
                 group_rows_remaining = *count;
-                output.push_str(&format_group_header(label, total_width));
+                output.push_str(&format_group_header(
+                    label,
+                    total_width,
+                    max_width.is_some(),
+                ));
                 output.push('\n');
             }
             TableRow::Data(cells) => {
@@ -131,9 +200,173 @@ pub fn format_structured_table(rows: &[TableRow], alignments: &[Alignment]) -> S
     output
 }
 
-/// Prints a structured table with group support to stdout.
+/// Prints a structured table with group support to stdout, fitted to the
+/// terminal width when stdout is a terminal.
 pub fn print_structured_table(rows: &[TableRow], alignments: &[Alignment]) {
-    print!("{}", format_structured_table(rows, alignments));
+    print!(
+        "{}",
+        format_structured_table_fitted(rows, alignments, terminal_width())
+    );
+}
+
+/// Like `format_structured_table`, drawing the table in the given style.
+///
+/// `Plain` puts group headers on a line of their own; `Markdown` renders them
+/// as a row with the label in bold in the first column.
+pub fn format_structured_table_styled(
+    rows: &[TableRow],
+    alignments: &[Alignment],
+    style: TableStyle,
+) -> String {
+    match style {
+        TableStyle::Boxed => format_structured_table(rows, alignments),
+        TableStyle::Plain => format_plain_table(rows, alignments),
+        TableStyle::Markdown => format_markdown_table(rows, alignments),
+    }
+}
+
+/// Prints a structured table in the given style. Boxed tables are fitted to
+/// the terminal width like `print_structured_table`; the other styles are
+/// printed as-is so they can be copied.
+pub fn print_structured_table_styled(
+    rows: &[TableRow],
+    alignments: &[Alignment],
+    style: TableStyle,
+) {
+    match style {
+        TableStyle::Boxed => print_structured_table(rows, alignments),
+        _ => print!(
+            "{}",
+            format_structured_table_styled(rows, alignments, style)
+        ),
+    }
+}
+
+/// Like `format_table`, drawing the table in the given style.
+pub fn format_table_styled(
+    rows: &[Vec<String>],
+    alignments: &[Alignment],
+    style: TableStyle,
+) -> String {
+    match style {
+        TableStyle::Boxed => format_table(rows, alignments),
+        _ => format_structured_table_styled(&to_table_rows(rows), alignments, style),
+    }
+}
+
+/// Treats the first row as the header and the rest as data rows.
+fn to_table_rows(rows: &[Vec<String>]) -> Vec<TableRow> {
+    rows.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            if i == 0 {
+                TableRow::Header(row.clone())
+            } else {
+                TableRow::Data(row.clone())
+            }
+        })
+        .collect()
+}
+
+/// Formats rows as space-separated, aligned columns without borders.
+fn format_plain_table(rows: &[TableRow], alignments: &[Alignment]) -> String {
+    let mut col_widths: Vec<usize> = Vec::new();
+    for row in rows {
+        if let TableRow::Header(cells) | TableRow::Data(cells) = row {
+            col_widths.resize(col_widths.len().max(cells.len()), 0);
+            for (i, cell) in cells.iter().enumerate() {
+                col_widths[i] = col_widths[i].max(UnicodeWidthStr::width(cell.as_str()));
+            }
+        }
+    }
+
+    let mut output = String::new();
+    for row in rows {
+        let line = match row {
+            TableRow::Header(cells) | TableRow::Data(cells) => cells
+                .iter()
+                .zip(&col_widths)
+                .enumerate()
+                .map(|(i, (cell, &width))| {
+                    let padding = width.saturating_sub(UnicodeWidthStr::width(cell.as_str()));
+                    let (left_pad, right_pad) = match alignments.get(i).copied().unwrap_or_default()
+                    {
+                        Alignment::Left => (0, padding),
+                        Alignment::Right => (padding, 0),
+                        Alignment::Center => (padding / 2, padding - padding / 2),
+                    };
+                    format!("{}{}{}", " ".repeat(left_pad), cell, " ".repeat(right_pad))
+                })
+                .collect::<Vec<_>>()
+                .join("  "),
+            TableRow::GroupHeader(label, _) => label.clone(),
+        };
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+/// Escapes the pipes in a markdown table cell so they don't start a new column.
+/// Pipes that are already escaped (preceded by an odd number of backslashes)
+/// are left alone, so cells escaped beforehand aren't escaped twice.
+fn escape_cell_pipes(cell: &str) -> String {
+    let mut escaped = String::with_capacity(cell.len());
+    let mut backslashes = 0;
+    for c in cell.chars() {
+        if c == '|' && backslashes % 2 == 0 {
+            escaped.push('\\');
+        }
+        backslashes = if c == '\\' { backslashes + 1 } else { 0 };
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Formats rows as GitHub-flavored markdown: a header row, an alignment row
+/// (`---` left, `---:` right, `:---:` centered) and one `|`-delimited row per
+/// data row. Pipes inside cells are escaped (see `escape_cell_pipes`).
+fn format_markdown_table(rows: &[TableRow], alignments: &[Alignment]) -> String {
+    let markdown_row = |cells: &[String]| {
+        format!(
+            "| {} |",
+            cells
+                .iter()
+                .map(|c| escape_cell_pipes(c))
+                .collect::<Vec<_>>()
+                .join(" | ")
+        )
+    };
+
+    let mut output = String::new();
+    for row in rows {
+        match row {
+            TableRow::Header(cells) => {
+                output.push_str(&markdown_row(cells));
+                output.push('\n');
+                let separators: Vec<String> = (0..cells.len())
+                    .map(|i| match alignments.get(i).copied().unwrap_or_default() {
+                        Alignment::Left => "---".to_string(),
+                        Alignment::Right => "---:".to_string(),
+                        Alignment::Center => ":---:".to_string(),
+                    })
+                    .collect();
+                output.push_str(&format!("| {} |", separators.join(" | ")));
+            }
+            TableRow::Data(cells) => output.push_str(&markdown_row(cells)),
+            TableRow::GroupHeader(label, _) => {
+                let col_count = match rows.first() {
+                    Some(TableRow::Header(header)) => header.len(),
+                    _ => 1,
+                };
+                let mut cells = vec![format!("**{}**", label)];
+                cells.resize(col_count.max(1), String::new());
+                output.push_str(&markdown_row(&cells));
+            }
+        }
+        output.push('\n');
+    }
+    output
 }
 
 /// Formats a 2-D vector of strings as a pretty-printed table string.
@@ -146,6 +379,16 @@ pub fn print_structured_table(rows: &[TableRow], alignments: &[Alignment]) {
 /// `alignments` specifies per-column alignment. If shorter than the column
 /// count, missing columns default to `Alignment::Left`.
 pub fn format_table(rows: &[Vec<String>], alignments: &[Alignment]) -> String {
+    format_table_fitted(rows, alignments, None)
+}
+
+/// Like `format_table`, but shrinks the widest columns to fit within `max_width`
+/// when given (see `format_structured_table_fitted`).
+pub fn format_table_fitted(
+    rows: &[Vec<String>],
+    alignments: &[Alignment],
+    max_width: Option<usize>,
+) -> String {
     if rows.is_empty() {
         return String::new();
     }
@@ -169,6 +412,10 @@ pub fn format_table(rows: &[Vec<String>], alignments: &[Alignment]) -> String {
         }
     }
 
+    if let Some(max_width) = max_width {
+        col_widths = fit_to_width(&col_widths, max_width);
+    }
+
     let mut output = String::new();
 
     // Header separator (thick)
@@ -194,9 +441,183 @@ pub fn format_table(rows: &[Vec<String>], alignments: &[Alignment]) -> String {
     output
 }
 
-/// Prints a 2-D vector of strings as a pretty-printed table to stdout.
+/// Prints a 2-D vector of strings as a pretty-printed table to stdout, fitted to
+/// the terminal width when stdout is a terminal.
 pub fn print_table(rows: &[Vec<String>], alignments: &[Alignment]) {
-    print!("{}", format_table(rows, alignments));
+    print!(
+        "{}",
+        format_table_fitted(rows, alignments, terminal_width())
+    );
+}
+
+/// Prints a table in the given style (see `print_structured_table_styled`).
+pub fn print_table_styled(rows: &[Vec<String>], alignments: &[Alignment], style: TableStyle) {
+    match style {
+        TableStyle::Boxed => print_table(rows, alignments),
+        _ => print!("{}", format_table_styled(rows, alignments, style)),
+    }
+}
+
+/// Where and how listings are written: the writer they go to, the table style,
+/// the width boxed tables are fitted to (`None` leaves them as wide as their
+/// contents) and the strftime-style format dates are shown in.
+pub struct TableOutput<'a> {
+    pub writer: &'a mut dyn Write,
+    pub style: TableStyle,
+    pub max_width: Option<usize>,
+    pub date_format: &'a str,
+}
+
+impl<'a> TableOutput<'a> {
+    /// Creates an output drawing tables in `style` to `writer`, without fitting them
+    /// and with dates in `DEFAULT_DATE_FORMAT`.
+    pub fn new(writer: &'a mut dyn Write, style: TableStyle) -> Self {
+        Self {
+            writer,
+            style,
+            max_width: None,
+            date_format: DEFAULT_DATE_FORMAT,
+        }
+    }
+
+    /// Fits boxed tables to `max_width` columns when given.
+    pub fn with_max_width(mut self, max_width: Option<usize>) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Shows dates in `date_format` instead of `DEFAULT_DATE_FORMAT`.
+    pub fn with_date_format(mut self, date_format: &'a str) -> Self {
+        self.date_format = date_format;
+        self
+    }
+
+    /// Writes a structured table with group support (see `print_structured_table_styled`).
+    pub fn write_structured_table(
+        &mut self,
+        rows: &[TableRow],
+        alignments: &[Alignment],
+    ) -> io::Result<()> {
+        let table = match self.style {
+            TableStyle::Boxed => format_structured_table_fitted(rows, alignments, self.max_width),
+            style => format_structured_table_styled(rows, alignments, style),
+        };
+        write!(self.writer, "{}", table)
+    }
+
+    /// Writes a table whose first row is the header (see `print_table_styled`).
+    pub fn write_table(
+        &mut self,
+        rows: &[Vec<String>],
+        alignments: &[Alignment],
+    ) -> io::Result<()> {
+        let table = match self.style {
+            TableStyle::Boxed => format_table_fitted(rows, alignments, self.max_width),
+            style => format_table_styled(rows, alignments, style),
+        };
+        write!(self.writer, "{}", table)
+    }
+
+    /// Writes a line of text, e.g. a message shown instead of an empty table.
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        writeln!(self.writer, "{}", line)
+    }
+}
+
+/// Formats labelled counts as a horizontal bar chart, one line per entry:
+/// `label | ████ count`. The largest count gets a bar of `max_bar` blocks and the
+/// others are scaled to match; non-zero counts always get at least one block.
+pub fn format_bar_chart(entries: &[(String, usize)], max_bar: usize) -> String {
+    let label_width = entries
+        .iter()
+        .map(|(label, _)| UnicodeWidthStr::width(label.as_str()))
+        .max()
+        .unwrap_or(0);
+    let max_count = entries.iter().map(|(_, count)| *count).max().unwrap_or(0);
+
+    entries
+        .iter()
+        .map(|(label, count)| {
+            let bar_len = if max_count == 0 || *count == 0 {
+                0
+            } else {
+                ((*count * max_bar) as f64 / max_count as f64)
+                    .round()
+                    .max(1.0) as usize
+            };
+            let padding = label_width - UnicodeWidthStr::width(label.as_str());
+            format!(
+                "  {}{} | {}{}{}",
+                " ".repeat(padding),
+                label,
+                "\u{2588}".repeat(bar_len),
+                if bar_len > 0 { " " } else { "" },
+                count
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the terminal width in columns, or `None` when stdout isn't a
+/// terminal or its size can't be determined.
+pub fn terminal_width() -> Option<usize> {
+    if !std::io::stdout().is_terminal() {
+        return None;
+    }
+    terminal_size().map(|(Width(width), _)| width as usize)
+}
+
+/// Shrinks column widths so the rendered table (columns plus one `|` border per
+/// column and one outer border) is at most `max_total` wide.
+///
+/// The widest column is narrowed one step at a time, so wide columns are evened
+/// out before narrow ones are touched. Columns are never shrunk below
+/// `MIN_COLUMN_WIDTH`, so the result can still exceed `max_total` for tables
+/// with many columns. Widths that already fit are returned unchanged.
+pub fn fit_to_width(col_widths: &[usize], max_total: usize) -> Vec<usize> {
+    let mut widths = col_widths.to_vec();
+    let budget = max_total.saturating_sub(widths.len() + 1);
+    let mut total: usize = widths.iter().sum();
+
+    while total > budget {
+        let widest = widths
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| **w > MIN_COLUMN_WIDTH)
+            .max_by(|(ia, a), (ib, b)| a.cmp(b).then(ib.cmp(ia)))
+            .map(|(i, _)| i);
+        match widest {
+            Some(i) => {
+                widths[i] -= 1;
+                total -= 1;
+            }
+            None => break,
+        }
+    }
+    widths
+}
+
+/// Cuts `text` down to at most `max_width` display columns, ending with `…`
+/// when anything was removed.
+fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if UnicodeWidthStr::width(text) <= max_width {
+        return text.to_string();
+    }
+    let mut result = String::new();
+    let mut width = 0;
+    for ch in text.chars() {
+        let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if width + ch_width + 1 > max_width {
+            break;
+        }
+        result.push(ch);
+        width += ch_width;
+    }
+    if max_width > 0 {
+        result.push('\u{2026}');
+    }
+    result
 }
 
 /// Formats a group header label left-aligned with a 2-space indent.
@@ -204,16 +625,22 @@ pub fn print_table(rows: &[Vec<String>], alignments: &[Alignment]) {
 /// Rendered as `|   ── Label ── ...padding... |` with `──` decorations
 /// flanking the label. The 2-space indent (plus 1-space gutter = 3 spaces)
 /// aligns the group header with indented series book titles below it.
-fn format_group_header(label: &str, total_width: usize) -> String {
+fn format_group_header(label: &str, total_width: usize, fitted: bool) -> String {
     // Inner width is total_width minus the outer `|` characters (2)
     let inner_width = total_width.saturating_sub(2);
 
-    // Build decorated label: "── Label ──"
-    let decorated = format!("\u{2500}\u{2500} {} \u{2500}\u{2500}", label);
-    let display_width = UnicodeWidthStr::width(decorated.as_str());
-
     // Left-aligned with 2-space indent (plus 1-space gutter = 3 leading spaces)
     let left_pad = 3; // 1 space gutter + 2 space indent
+
+    // Build decorated label: "── Label ──", cut short if the table was fitted
+    // to a maximum width
+    let decorated = format!("\u{2500}\u{2500} {} \u{2500}\u{2500}", label);
+    let decorated = if fitted {
+        truncate_to_width(&decorated, inner_width.saturating_sub(left_pad + 1))
+    } else {
+        decorated
+    };
+    let display_width = UnicodeWidthStr::width(decorated.as_str());
     let right_pad = inner_width.saturating_sub(left_pad + display_width);
     format!(
         "|{}{}{}|",
@@ -238,6 +665,7 @@ fn format_row(row: &[String], col_widths: &[usize], alignments: &[Alignment]) ->
     let mut result = String::from("|");
     for (i, (cell, &col_width)) in row.iter().zip(col_widths.iter()).enumerate() {
         let alignment = alignments.get(i).copied().unwrap_or_default();
+        let cell = truncate_to_width(cell, col_width.saturating_sub(2));
         let display_width = UnicodeWidthStr::width(cell.as_str());
         let total_padding = col_width.saturating_sub(display_width);
         let (left_pad, right_pad) = match alignment {
@@ -249,7 +677,7 @@ fn format_row(row: &[String], col_widths: &[usize], alignments: &[Alignment]) ->
             }
         };
         result.push_str(&" ".repeat(left_pad));
-        result.push_str(cell);
+        result.push_str(&cell);
         result.push_str(&" ".repeat(right_pad));
         result.push('|');
     }
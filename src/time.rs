@@ -0,0 +1,52 @@
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+
+/// The timezone used to decide which day, month and year an event falls in.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Timezone {
+    #[default]
+    Utc,
+    /// A fixed offset from UTC, e.g. `+02:00`
+    Fixed(FixedOffset),
+    /// An IANA timezone, e.g. `Europe/Oslo`, including daylight saving time
+    Named(Tz),
+}
+
+impl Timezone {
+    /// Returns the local calendar date of a UTC timestamp.
+    pub fn local_date(&self, time: &DateTime<Utc>) -> NaiveDate {
+        self.local_datetime(time).date()
+    }
+
+    /// Returns the local wall-clock time of a UTC timestamp.
+    pub fn local_datetime(&self, time: &DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            Timezone::Utc => time.naive_utc(),
+            Timezone::Fixed(offset) => time.with_timezone(offset).naive_local(),
+            Timezone::Named(tz) => time.with_timezone(tz).naive_local(),
+        }
+    }
+
+    /// Returns the local year of a UTC timestamp.
+    pub fn year_of(&self, time: &DateTime<Utc>) -> i32 {
+        self.local_date(time).year()
+    }
+}
+
+/// Parses a timezone setting: `UTC` (or `Z`), a fixed offset like `+02:00`, or an
+/// IANA name like `Europe/Oslo`.
+pub fn parse_timezone(value: &str) -> Result<Timezone, String> {
+    let trimmed = value.trim();
+    if trimmed.eq_ignore_ascii_case("utc") || trimmed == "Z" {
+        return Ok(Timezone::Utc);
+    }
+    if let Ok(offset) = trimmed.parse::<FixedOffset>() {
+        return Ok(Timezone::Fixed(offset));
+    }
+    trimmed.parse::<Tz>().map(Timezone::Named).map_err(|_| {
+        format!(
+            "Invalid timezone '{}': expected UTC, an offset like +02:00 or a name like Europe/Oslo",
+            value
+        )
+    })
+}
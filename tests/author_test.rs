@@ -1,7 +1,9 @@
-use bookmon::author::{get_author_by_id, store_author};
-use bookmon::storage::{Author, Storage};
+use bookmon::author::{
+    find_author_by_name, find_similar_authors, get_author_by_id, get_or_create_author,
+    merge_authors, normalize_author_name, store_author,
+};
+use bookmon::storage::{Author, Book, Storage};
 use chrono::{DateTime, Utc};
-use serde_json;
 
 #[test]
 fn test_store_and_retrieve_author() {
@@ -61,3 +63,150 @@ fn test_author_timestamp_format() {
     let deserialized: Author = serde_json::from_str(&json).expect("Failed to deserialize author");
     assert_eq!(deserialized.created_on, author.created_on);
 }
+
+#[test]
+fn test_normalize_author_name_flips_last_first() {
+    assert_eq!(normalize_author_name("Rowling, J. K."), "J. K. Rowling");
+    assert_eq!(
+        normalize_author_name("  Le Guin ,  Ursula  K. "),
+        "Ursula K. Le Guin"
+    );
+}
+
+#[test]
+fn test_normalize_author_name_keeps_first_last() {
+    assert_eq!(normalize_author_name("J. K.   Rowling"), "J. K. Rowling");
+    assert_eq!(normalize_author_name("Rowling"), "Rowling");
+}
+
+#[test]
+fn test_normalize_author_name_does_not_flip_suffixes_or_multiple_commas() {
+    assert_eq!(
+        normalize_author_name("Martin Luther King, Jr."),
+        "Martin Luther King, Jr."
+    );
+    assert_eq!(
+        normalize_author_name("Smith, John, Jr."),
+        "Smith, John, Jr."
+    );
+    assert_eq!(normalize_author_name("Rowling,"), "Rowling,");
+}
+
+#[test]
+fn test_find_author_by_name_matches_either_form() {
+    let mut storage = Storage::new();
+    let author = Author::new("J. K. Rowling".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    assert_eq!(
+        find_author_by_name(&storage, "Rowling, J. K.").map(|a| a.id.as_str()),
+        Some(author_id.as_str())
+    );
+    assert_eq!(
+        find_author_by_name(&storage, "j. k. rowling").map(|a| a.id.as_str()),
+        Some(author_id.as_str())
+    );
+    assert!(find_author_by_name(&storage, "Rowling").is_none());
+}
+
+/// Adds authors with the given names, returning their IDs in the same order.
+fn add_authors(storage: &mut Storage, names: &[&str]) -> Vec<String> {
+    names
+        .iter()
+        .map(|name| {
+            let author = Author::new(name.to_string());
+            let id = author.id.clone();
+            storage.add_author(author);
+            id
+        })
+        .collect()
+}
+
+fn group_names(storage: &Storage) -> Vec<Vec<String>> {
+    find_similar_authors(storage)
+        .iter()
+        .map(|group| group.iter().map(|a| a.name.clone()).collect())
+        .collect()
+}
+
+#[test]
+fn test_find_similar_authors_groups_normalized_matches() {
+    let mut storage = Storage::new();
+    add_authors(
+        &mut storage,
+        &[
+            "Rowling, J. K.",
+            "J. K. Rowling",
+            "j. k.  rowling",
+            "Terry Pratchett",
+        ],
+    );
+
+    assert_eq!(
+        group_names(&storage),
+        vec![vec!["j. k.  rowling", "J. K. Rowling", "Rowling, J. K."]]
+    );
+}
+
+#[test]
+fn test_find_similar_authors_groups_one_character_typos() {
+    let mut storage = Storage::new();
+    add_authors(
+        &mut storage,
+        &[
+            "Terry Pratchett",
+            "Terry Pratchet",
+            "Neil Gaiman",
+            "Neil Gaimen",
+            "Ursula",
+        ],
+    );
+
+    assert_eq!(
+        group_names(&storage),
+        vec![
+            vec!["Neil Gaiman", "Neil Gaimen"],
+            vec!["Terry Pratchet", "Terry Pratchett"],
+        ]
+    );
+}
+
+#[test]
+fn test_find_similar_authors_ignores_names_two_edits_apart() {
+    let mut storage = Storage::new();
+    add_authors(&mut storage, &["Anne Rice", "Anna Rica"]);
+    assert!(find_similar_authors(&storage).is_empty());
+}
+
+#[test]
+fn test_merge_authors_repoints_books_and_removes_duplicates() {
+    let mut storage = Storage::new();
+    let ids = add_authors(&mut storage, &["Terry Pratchett", "Terry Pratchet"]);
+    for (title, author_id) in [("Mort", &ids[0]), ("Guards! Guards!", &ids[1])] {
+        storage.add_book(Book::new(
+            title.to_string(),
+            String::new(),
+            "category".to_string(),
+            author_id.clone(),
+            250,
+        ));
+    }
+
+    assert_eq!(merge_authors(&mut storage, &ids[0], &ids).unwrap(), 1);
+
+    assert_eq!(storage.authors.len(), 1);
+    assert!(storage.books.values().all(|b| b.author_id == ids[0]));
+    assert!(merge_authors(&mut storage, &ids[0], &ids).is_err());
+}
+
+#[test]
+fn test_get_or_create_author_dedups_last_first_names() {
+    let mut storage = Storage::new();
+    let first = get_or_create_author(&mut storage, "Rowling, J. K.");
+    let second = get_or_create_author(&mut storage, "J. K. Rowling");
+
+    assert_eq!(first, second);
+    assert_eq!(storage.authors.len(), 1);
+    assert_eq!(storage.get_author(&first).unwrap().name, "J. K. Rowling");
+}
@@ -0,0 +1,75 @@
+use bookmon::backend::{backend_for_path, JsonBackend, StorageBackend};
+use bookmon::storage::{
+    load_storage, Author, Book, Category, Reading, ReadingEvent, Review, Series, Storage,
+};
+
+/// Builds a small library touching every collection.
+fn sample_storage() -> Storage {
+    let mut storage = Storage::new();
+    let author = Author::new("Ursula K. Le Guin".to_string());
+    let category = Category::new("Fantasy".to_string(), None);
+    let series = Series::new("Earthsea".to_string());
+    let mut book = Book::new(
+        "A Wizard of Earthsea".to_string(),
+        "9780547773742".to_string(),
+        category.id.clone(),
+        author.id.clone(),
+        183,
+    );
+    book.series_id = Some(series.id.clone());
+    book.position_in_series = Some("1".to_string());
+
+    storage.add_reading(Reading::new(book.id.clone(), ReadingEvent::Finished));
+    storage.add_review(Review::new(book.id.clone(), "Lovely.".to_string()));
+    storage.add_author(author);
+    storage.add_category(category);
+    storage.add_series(series);
+    storage.add_book(book);
+    storage.goals.insert(2025, 20);
+    storage
+}
+
+/// Checks the behaviors every backend must have.
+fn assert_backend_round_trips(backend: &dyn StorageBackend) {
+    assert!(!backend.exists());
+    assert!(backend.load().is_err());
+
+    let storage = sample_storage();
+    backend.save(&storage).unwrap();
+    assert!(backend.exists());
+
+    let loaded = backend.load().unwrap();
+    assert_eq!(
+        loaded.to_sorted_json_string().unwrap(),
+        storage.to_sorted_json_string().unwrap()
+    );
+    let book = loaded.books.values().next().unwrap();
+    assert!(loaded.is_book_finished(&book.id));
+    assert_eq!(loaded.series_name_for_book(book), "Earthsea");
+    assert_eq!(loaded.get_goal(2025), Some(20));
+
+    // Saving again replaces the library rather than merging into it.
+    backend.save(&Storage::new()).unwrap();
+    assert!(backend.load().unwrap().books.is_empty());
+}
+
+#[test]
+fn test_json_backend_round_trips_storage() {
+    let dir = tempfile::tempdir().unwrap();
+    let backend = JsonBackend::new(dir.path().join("nested").join("storage.json"));
+    assert_backend_round_trips(&backend);
+}
+
+#[test]
+fn test_backend_for_path_uses_json_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("storage.json");
+    let path = path.to_str().unwrap();
+
+    assert_backend_round_trips(backend_for_path(path).as_ref());
+
+    backend_for_path(path).save(&sample_storage()).unwrap();
+    let contents = std::fs::read_to_string(path).unwrap();
+    assert!(contents.trim_start().starts_with('{'));
+    assert_eq!(load_storage(path).unwrap().books.len(), 1);
+}
@@ -1,7 +1,6 @@
-use bookmon::book::store_book;
-use bookmon::storage::{Author, Book, Category, Reading, ReadingEvent, Storage};
+use bookmon::book::{parse_initial_status, store_book, store_book_with_isbn_check, InitialStatus};
+use bookmon::storage::{Author, Book, Category, Reading, ReadingEvent, Storage, StorageError};
 use chrono::{DateTime, Utc};
-use serde_json;
 
 #[test]
 fn test_store_book_with_valid_category_and_author() {
@@ -56,6 +55,7 @@ fn test_store_book_with_invalid_category() {
     assert!(result.is_err());
     assert!(result
         .unwrap_err()
+        .to_string()
         .contains("Category with ID invalid-category-id does not exist"));
     assert_eq!(storage.books.len(), 0);
 }
@@ -86,6 +86,7 @@ fn test_store_book_with_invalid_author() {
     assert!(result.is_err());
     assert!(result
         .unwrap_err()
+        .to_string()
         .contains("Author with ID invalid-author-id does not exist"));
     assert_eq!(storage.books.len(), 0);
 }
@@ -287,3 +288,451 @@ fn test_book_with_no_status() {
         "Should have no want to read readings"
     );
 }
+
+fn lookup_result(title: &str) -> bookmon::lookup::book_lookup_dto::BookLookupDTO {
+    bookmon::lookup::book_lookup_dto::BookLookupDTO {
+        title: title.to_string(),
+        subtitle: None,
+        authors: vec![],
+        description: Some("A boy discovers he is a wizard.".to_string()),
+        isbn: "9780747532699".to_string(),
+        publish_date: Some("1997".to_string()),
+        publisher: None,
+        cover_url: Some("https://covers.openlibrary.org/b/id/1-L.jpg".to_string()),
+        total_pages: Some(223),
+        series_name: Some("Harry Potter".to_string()),
+        series_position: Some("1".to_string()),
+        source: None,
+    }
+}
+
+#[test]
+fn test_enrich_book_fills_blank_fields_and_preserves_title() {
+    use bookmon::book::enrich_book;
+
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "The Philosopher's Stone (my edition)".to_string(),
+        "9780747532699".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        0,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    let filled = enrich_book(
+        &mut storage,
+        &book_id,
+        &lookup_result("Harry Potter and the Philosopher's Stone"),
+    )
+    .unwrap();
+
+    let book = storage.get_book(&book_id).unwrap();
+    assert_eq!(book.title, "The Philosopher's Stone (my edition)");
+    assert_eq!(
+        book.description.as_deref(),
+        Some("A boy discovers he is a wizard.")
+    );
+    assert_eq!(
+        book.cover_url.as_deref(),
+        Some("https://covers.openlibrary.org/b/id/1-L.jpg")
+    );
+    assert_eq!(book.total_pages, 223);
+    assert_eq!(book.position_in_series.as_deref(), Some("1"));
+    let series_id = book.series_id.clone().expect("series should be filled in");
+    assert_eq!(storage.get_series(&series_id).unwrap().name, "Harry Potter");
+
+    assert!(filled.contains(&"description"));
+    assert!(filled.contains(&"total pages"));
+    assert!(!filled.contains(&"title"));
+}
+
+#[test]
+fn test_enrich_book_does_not_overwrite_existing_values() {
+    use bookmon::book::enrich_book;
+
+    let mut storage = Storage::new();
+    let mut book = Book::new(
+        "My Title".to_string(),
+        "9780747532699".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        300,
+    );
+    book.description = Some("My own notes".to_string());
+    book.cover_url = Some("file:///covers/mine.jpg".to_string());
+    book.publish_year = Some(2014);
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    // Running twice should be a no-op the second time
+    let first = enrich_book(&mut storage, &book_id, &lookup_result("Other")).unwrap();
+    assert_eq!(first, vec!["series", "position in series"]);
+    let second = enrich_book(&mut storage, &book_id, &lookup_result("Other")).unwrap();
+    assert!(second.is_empty(), "Nothing left to fill, got: {:?}", second);
+
+    let book = storage.get_book(&book_id).unwrap();
+    assert_eq!(book.title, "My Title");
+    assert_eq!(book.publish_year, Some(2014));
+    assert_eq!(book.description.as_deref(), Some("My own notes"));
+    assert_eq!(book.cover_url.as_deref(), Some("file:///covers/mine.jpg"));
+    assert_eq!(book.total_pages, 300);
+}
+
+#[test]
+fn test_book_description_backward_compatibility() {
+    let json = r#"{
+        "id": "book-1",
+        "title": "Old Book",
+        "added_on": "2024-01-01T00:00:00Z",
+        "isbn": "123",
+        "category_id": "cat",
+        "author_id": "author",
+        "total_pages": 100
+    }"#;
+    let book: Book = serde_json::from_str(json).unwrap();
+    assert_eq!(book.description, None);
+    assert_eq!(book.cover_url, None);
+
+    // None values are not written back out
+    let serialized = serde_json::to_string(&book).unwrap();
+    assert!(!serialized.contains("description"));
+    assert!(!serialized.contains("cover_url"));
+}
+
+#[test]
+fn test_enrich_book_fills_subtitle_from_lookup() {
+    use bookmon::book::enrich_book;
+
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Sapiens".to_string(),
+        "9780099590088".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        498,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    let mut info = lookup_result("Sapiens");
+    info.subtitle = Some("A Brief History of Humankind".to_string());
+
+    let filled = enrich_book(&mut storage, &book_id, &info).unwrap();
+    assert!(filled.contains(&"subtitle"));
+    let book = storage.get_book(&book_id).unwrap();
+    assert_eq!(book.title, "Sapiens");
+    assert_eq!(
+        book.subtitle.as_deref(),
+        Some("A Brief History of Humankind")
+    );
+}
+
+#[test]
+fn test_book_subtitle_backward_compatibility() {
+    let json = r#"{
+        "id": "b1",
+        "title": "Sapiens",
+        "added_on": "2024-01-01T00:00:00Z",
+        "isbn": "123",
+        "category_id": "c1",
+        "author_id": "a1",
+        "total_pages": 498
+    }"#;
+    let book: Book = serde_json::from_str(json).unwrap();
+    assert_eq!(book.subtitle, None);
+    assert_eq!(book.display_title(true), "Sapiens");
+
+    let serialized = serde_json::to_string(&book).unwrap();
+    assert!(!serialized.contains("subtitle"));
+}
+
+#[test]
+fn test_parse_initial_status_accepts_names_and_labels() {
+    assert_eq!(parse_initial_status("bought"), Ok(InitialStatus::Bought));
+    assert_eq!(
+        parse_initial_status("Already bought"),
+        Ok(InitialStatus::Bought)
+    );
+    assert_eq!(
+        parse_initial_status("want-to-read"),
+        Ok(InitialStatus::WantToRead)
+    );
+    assert_eq!(parse_initial_status("BOTH"), Ok(InitialStatus::Both));
+    assert_eq!(parse_initial_status("neither"), Ok(InitialStatus::Neither));
+    assert!(parse_initial_status("borrowed").is_err());
+}
+
+#[test]
+fn test_initial_status_events() {
+    assert_eq!(InitialStatus::Bought.events(), vec![ReadingEvent::Bought]);
+    assert_eq!(
+        InitialStatus::WantToRead.events(),
+        vec![ReadingEvent::WantToRead]
+    );
+    assert_eq!(
+        InitialStatus::Both.events(),
+        vec![ReadingEvent::Bought, ReadingEvent::WantToRead]
+    );
+    assert!(InitialStatus::Neither.events().is_empty());
+}
+
+fn storage_and_book_with_isbn(isbn: &str) -> (Storage, Book) {
+    let mut storage = Storage::new();
+    let category = Category::new("Fiction".to_string(), None);
+    let author = Author::new("Test Author".to_string());
+    let book = Book::new(
+        "Test Book".to_string(),
+        isbn.to_string(),
+        category.id.clone(),
+        author.id.clone(),
+        300,
+    );
+    storage.categories.insert(category.id.clone(), category);
+    storage.authors.insert(author.id.clone(), author);
+    (storage, book)
+}
+
+#[test]
+fn test_strict_isbn_rejects_missing_and_invalid_isbns() {
+    let (mut storage, book) = storage_and_book_with_isbn("");
+    let err = store_book_with_isbn_check(&mut storage, book, true)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("An ISBN is required"), "got: {}", err);
+
+    let (mut storage, book) = storage_and_book_with_isbn("9780142410348");
+    let err = store_book_with_isbn_check(&mut storage, book, true)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("'9780142410348' is not a valid ISBN"),
+        "got: {}",
+        err
+    );
+    assert!(storage.books.is_empty());
+}
+
+#[test]
+fn test_strict_isbn_accepts_valid_isbns() {
+    for isbn in ["978-0-14-241034-9", "080442957X"] {
+        let (mut storage, book) = storage_and_book_with_isbn(isbn);
+        assert!(store_book_with_isbn_check(&mut storage, book, true).is_ok());
+        assert_eq!(storage.books.len(), 1);
+    }
+}
+
+#[test]
+fn test_invalid_isbn_accepted_when_not_strict() {
+    let (mut storage, book) = storage_and_book_with_isbn("");
+    assert!(store_book(&mut storage, book).is_ok());
+
+    let (mut storage, book) = storage_and_book_with_isbn("9780142410348");
+    assert!(store_book_with_isbn_check(&mut storage, book, false).is_ok());
+    assert_eq!(storage.books.len(), 1);
+}
+
+#[test]
+fn test_enrich_book_records_source_only_when_missing() {
+    use bookmon::book::enrich_book;
+
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Title".to_string(),
+        "9780747532699".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        0,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    let mut info = lookup_result("Title");
+    info.source = Some("openlibrary".to_string());
+    enrich_book(&mut storage, &book_id, &info).unwrap();
+    assert_eq!(
+        storage.get_book(&book_id).unwrap().source.as_deref(),
+        Some("openlibrary")
+    );
+
+    // A later lookup from another provider keeps the original source
+    info.source = Some("bibsok".to_string());
+    storage.books.get_mut(&book_id).unwrap().description = None;
+    enrich_book(&mut storage, &book_id, &info).unwrap();
+    assert_eq!(
+        storage.get_book(&book_id).unwrap().source.as_deref(),
+        Some("openlibrary")
+    );
+}
+
+#[test]
+fn test_book_details_show_source_and_history() {
+    use bookmon::book::format_book_details;
+    use chrono::TimeZone;
+
+    let mut storage = Storage::new();
+    let category = Category::new("Fiction".to_string(), None);
+    let author = Author::new("Roald Dahl".to_string());
+    let mut book = Book::new(
+        "Matilda".to_string(),
+        "9780142410370".to_string(),
+        category.id.clone(),
+        author.id.clone(),
+        240,
+    );
+    book.added_on = Utc.with_ymd_and_hms(2025, 1, 2, 12, 0, 0).unwrap();
+    book.source = Some("openlibrary".to_string());
+    let mut started = Reading::new(book.id.clone(), ReadingEvent::Started);
+    started.created_on = Utc.with_ymd_and_hms(2025, 1, 5, 12, 0, 0).unwrap();
+    let mut update = Reading::with_metadata(book.id.clone(), ReadingEvent::Update, 120);
+    update.created_on = Utc.with_ymd_and_hms(2025, 1, 7, 12, 0, 0).unwrap();
+    storage.add_reading(update);
+    storage.add_reading(started);
+    storage.add_category(category);
+    storage.add_author(author);
+
+    assert_eq!(
+        format_book_details(&storage, &book, "%Y-%m-%d"),
+        "Title: Matilda\nAuthor: Roald Dahl\nISBN: 9780142410370\nCategory: Fiction\n\
+         Pages: 240\nAdded: 2025-01-02\nSource: openlibrary\nHistory:\n\
+         \x20 2025-01-05 \u{2014} Started\n\x20 2025-01-07 \u{2014} Progress update (page 120)"
+    );
+
+    book.source = None;
+    assert!(!format_book_details(&storage, &book, "%Y-%m-%d").contains("Source:"));
+
+    book.publisher = Some("Puffin".to_string());
+    book.publish_year = Some(1988);
+    let details = format_book_details(&storage, &book, "%Y-%m-%d");
+    assert!(details.contains("Pages: 240\nPublisher: Puffin\nPublished: 1988\nAdded:"));
+}
+
+#[test]
+fn test_parse_publish_year() {
+    use bookmon::lookup::book_lookup_dto::parse_publish_year;
+
+    assert_eq!(parse_publish_year("2001"), Some(2001));
+    assert_eq!(parse_publish_year("January 1, 2001"), Some(2001));
+    assert_eq!(parse_publish_year("2001-05-03"), Some(2001));
+    assert_eq!(parse_publish_year("Mar 5 1999"), Some(1999));
+    assert_eq!(parse_publish_year("unknown"), None);
+    assert_eq!(parse_publish_year("12/05/01"), None);
+    assert_eq!(parse_publish_year(""), None);
+}
+
+#[test]
+fn test_book_publisher_and_year_serialization() {
+    let mut book = Book::new(
+        "Matilda".to_string(),
+        "9780142410370".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        240,
+    );
+    let json = serde_json::to_string(&book).unwrap();
+    assert!(!json.contains("publisher") && !json.contains("publish_year"));
+
+    book.publisher = Some("Puffin".to_string());
+    book.publish_year = Some(1988);
+    let parsed: Book = serde_json::from_str(&serde_json::to_string(&book).unwrap()).unwrap();
+    assert_eq!(parsed.publisher.as_deref(), Some("Puffin"));
+    assert_eq!(parsed.publish_year, Some(1988));
+}
+
+#[test]
+fn test_book_from_lookup_maps_every_field() {
+    let mut info = lookup_result("  Harry Potter and the Philosopher's Stone ");
+    info.subtitle = Some("Illustrated edition".to_string());
+    info.source = Some("openlibrary".to_string());
+    info.publisher = Some("Bloomsbury".to_string());
+
+    let book = Book::from_lookup(&info, "cat-id".to_string(), "author-id".to_string());
+    assert_eq!(book.title, "Harry Potter and the Philosopher's Stone");
+    assert_eq!(book.subtitle.as_deref(), Some("Illustrated edition"));
+    assert_eq!(book.isbn, "9780747532699");
+    assert_eq!(book.category_id, "cat-id");
+    assert_eq!(book.author_id, "author-id");
+    assert_eq!(book.total_pages, 223);
+    assert_eq!(
+        book.description.as_deref(),
+        Some("A boy discovers he is a wizard.")
+    );
+    assert_eq!(
+        book.cover_url.as_deref(),
+        Some("https://covers.openlibrary.org/b/id/1-L.jpg")
+    );
+    assert_eq!(book.source.as_deref(), Some("openlibrary"));
+    assert_eq!(book.publisher.as_deref(), Some("Bloomsbury"));
+    assert_eq!(book.publish_year, Some(1997));
+    assert_eq!(book.position_in_series.as_deref(), Some("1"));
+    // The series is linked by the caller
+    assert_eq!(book.series_id, None);
+}
+
+#[test]
+fn test_book_from_lookup_without_series_or_pages() {
+    let mut info = lookup_result("Matilda");
+    info.series_name = None;
+    info.total_pages = Some(0);
+
+    let book = Book::from_lookup(&info, "cat".to_string(), "author".to_string());
+    assert_eq!(book.position_in_series, None);
+    assert_eq!(book.total_pages, 0);
+}
+
+#[test]
+fn test_store_book_errors_name_the_missing_record() {
+    let (mut storage, mut book) = storage_and_book_with_isbn("9780142410349");
+    book.category_id = "no-such-category".to_string();
+    assert_eq!(
+        store_book(&mut storage, book),
+        Err(StorageError::MissingCategory(
+            "no-such-category".to_string()
+        ))
+    );
+
+    let (mut storage, mut book) = storage_and_book_with_isbn("9780142410349");
+    book.author_id = "no-such-author".to_string();
+    assert_eq!(
+        store_book(&mut storage, book),
+        Err(StorageError::MissingAuthor("no-such-author".to_string()))
+    );
+
+    let (mut storage, mut book) = storage_and_book_with_isbn("9780142410349");
+    book.series_id = Some("no-such-series".to_string());
+    assert_eq!(
+        store_book(&mut storage, book),
+        Err(StorageError::MissingSeries("no-such-series".to_string()))
+    );
+
+    let (mut storage, book) = storage_and_book_with_isbn("");
+    assert!(matches!(
+        store_book_with_isbn_check(&mut storage, book, true),
+        Err(StorageError::InvalidIsbn(_))
+    ));
+}
+
+#[test]
+fn test_books_without_isbn_are_stored_and_never_match_each_other() {
+    let (mut storage, first) = storage_and_book_with_isbn("");
+    let mut second = first.clone();
+    second.id = "second-book".to_string();
+    second.title = "Another Self-Published Book".to_string();
+
+    store_book(&mut storage, first).unwrap();
+    store_book(&mut storage, second).unwrap();
+
+    assert_eq!(storage.books.len(), 2);
+    assert!(storage.books.values().all(|b| b.isbn.is_empty()));
+    // An empty ISBN must not look like a duplicate of another ISBN-less book
+    assert!(storage.get_book_by_isbn("").is_none());
+    assert!(storage.get_book_by_isbn("  ").is_none());
+
+    // Strict ISBN mode still refuses them
+    let (mut storage, book) = storage_and_book_with_isbn("");
+    assert!(matches!(
+        store_book_with_isbn_check(&mut storage, book, true),
+        Err(StorageError::InvalidIsbn(_))
+    ));
+}
@@ -0,0 +1,152 @@
+use bookmon::bulk::{bulk_add, parse_isbn_list, BulkAddSummary};
+use bookmon::category::get_or_create_category;
+use bookmon::lookup::book_lookup_dto::{AuthorDTO, BookLookupDTO};
+use bookmon::storage::{Author, Book, ReadingEvent, Storage};
+
+fn lookup_result(isbn: &str, title: &str, author: &str) -> BookLookupDTO {
+    BookLookupDTO {
+        title: title.to_string(),
+        subtitle: None,
+        authors: vec![AuthorDTO {
+            name: author.to_string(),
+            personal_name: None,
+            birth_date: None,
+            death_date: None,
+            bio: None,
+        }],
+        description: None,
+        isbn: isbn.to_string(),
+        publish_date: None,
+        publisher: None,
+        cover_url: None,
+        total_pages: Some(320),
+        series_name: None,
+        series_position: None,
+        source: None,
+    }
+}
+
+#[test]
+fn test_parse_isbn_list_skips_blank_comment_and_duplicate_lines() {
+    let contents =
+        "9780142410349\n\n  9780008164966  \n# shelf two\n9780142410349\n9780241970775\n";
+
+    assert_eq!(
+        parse_isbn_list(contents),
+        vec!["9780142410349", "9780008164966", "9780241970775"]
+    );
+}
+
+#[test]
+fn test_parse_isbn_list_empty_file() {
+    assert!(parse_isbn_list("").is_empty());
+    assert!(parse_isbn_list("\n  \n# nothing here\n").is_empty());
+}
+
+#[test]
+fn test_bulk_add_aggregates_added_skipped_and_failed() {
+    let mut storage = Storage::new();
+    let category_id = get_or_create_category(&mut storage, "Uncategorized");
+
+    // Already in the library
+    let author = Author::new("Roald Dahl".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    storage.add_book(Book::new(
+        "Matilda".to_string(),
+        "111".to_string(),
+        category_id.clone(),
+        author_id.clone(),
+        240,
+    ));
+
+    let isbns: Vec<String> = ["111", "222", "333", "444", "555"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+    let summary = bulk_add(
+        &mut storage,
+        &isbns,
+        &category_id,
+        |isbn| match isbn {
+            "222" => Ok(Some(lookup_result(isbn, "Fantastic Mr Fox", "Roald Dahl"))),
+            "333" => Ok(Some(lookup_result(
+                isbn,
+                "The Big Sleep",
+                "Raymond Chandler",
+            ))),
+            "444" => Ok(None),
+            _ => Err("connection reset".to_string()),
+        },
+        |_, _| None,
+    );
+
+    assert_eq!(
+        summary,
+        BulkAddSummary {
+            added: vec!["Fantastic Mr Fox".to_string(), "The Big Sleep".to_string()],
+            skipped: vec![
+                ("111".to_string(), "already in library".to_string()),
+                ("444".to_string(), "no book details found".to_string()),
+            ],
+            failed: vec![("555".to_string(), "connection reset".to_string())],
+        }
+    );
+    assert_eq!(
+        summary.format(),
+        "Added 2, skipped 2, failed 1.\n  skipped 111: already in library\n  skipped 444: no book details found\n  failed 555: connection reset"
+    );
+
+    // Existing author is reused, new author is created
+    assert_eq!(storage.authors.len(), 2);
+    let mr_fox = storage.get_book_by_isbn("222").unwrap();
+    assert_eq!(mr_fox.author_id, author_id);
+    assert_eq!(mr_fox.total_pages, 320);
+    assert_eq!(mr_fox.category_id, category_id);
+
+    // Added books are recorded as bought
+    let mr_fox_id = mr_fox.id.clone();
+    assert!(storage
+        .get_readings_by_event(ReadingEvent::Bought)
+        .iter()
+        .any(|r| r.book_id == mr_fox_id));
+}
+
+#[test]
+fn test_bulk_add_resolves_incomplete_lookup_results() {
+    let mut storage = Storage::new();
+    let category_id = get_or_create_category(&mut storage, "Uncategorized");
+    let isbns = vec!["222".to_string(), "333".to_string()];
+
+    let mut asked = Vec::new();
+    let summary = bulk_add(
+        &mut storage,
+        &isbns,
+        &category_id,
+        |isbn| match isbn {
+            // Title found but no author: ambiguous, so the resolver is asked
+            "222" => Ok(Some(lookup_result(isbn, "Sad Cypress", ""))),
+            _ => Ok(None),
+        },
+        |isbn, partial| {
+            asked.push((isbn.to_string(), partial.map(|p| p.title.clone())));
+            if isbn == "222" {
+                Some(lookup_result(isbn, "Sad Cypress", "Agatha Christie"))
+            } else {
+                None
+            }
+        },
+    );
+
+    assert_eq!(
+        asked,
+        vec![
+            ("222".to_string(), Some("Sad Cypress".to_string())),
+            ("333".to_string(), None),
+        ]
+    );
+    assert_eq!(summary.added, vec!["Sad Cypress".to_string()]);
+    assert_eq!(summary.skipped.len(), 1);
+    assert!(summary.failed.is_empty());
+}
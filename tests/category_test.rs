@@ -1,7 +1,8 @@
-use bookmon::category::store_category;
-use bookmon::storage::{Category, Storage};
+use bookmon::category::{
+    format_category_list, get_or_create_category, set_category_description, store_category,
+};
+use bookmon::storage::{Book, Category, Storage};
 use chrono::{DateTime, Utc};
-use serde_json;
 
 #[test]
 fn test_category_creation() {
@@ -116,3 +117,144 @@ fn test_category_timestamp_format() {
         serde_json::from_str(&json).expect("Failed to deserialize category");
     assert_eq!(deserialized.created_on, category.created_on);
 }
+
+#[test]
+fn test_set_and_clear_category_description() {
+    let mut storage = Storage::new();
+    let category = Category::new("Fiction".to_string(), None);
+    let id = category.id.clone();
+    store_category(&mut storage, category).unwrap();
+
+    set_category_description(&mut storage, &id, Some("  Novels and stories ".to_string())).unwrap();
+    assert_eq!(
+        storage.get_category(&id).unwrap().description.as_deref(),
+        Some("Novels and stories")
+    );
+
+    set_category_description(&mut storage, &id, Some("   ".to_string())).unwrap();
+    assert!(storage.get_category(&id).unwrap().description.is_none());
+
+    set_category_description(&mut storage, &id, Some("Again".to_string())).unwrap();
+    set_category_description(&mut storage, &id, None).unwrap();
+    assert!(storage.get_category(&id).unwrap().description.is_none());
+
+    assert!(set_category_description(&mut storage, "missing", None).is_err());
+}
+
+#[test]
+fn test_category_description_survives_serialization() {
+    let mut storage = Storage::new();
+    let category = Category::new("Poetry".to_string(), None);
+    let id = category.id.clone();
+    store_category(&mut storage, category).unwrap();
+    set_category_description(&mut storage, &id, Some("Verse, mostly".to_string())).unwrap();
+
+    let json = serde_json::to_string(&storage).unwrap();
+    let loaded: Storage = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        loaded.get_category(&id).unwrap().description.as_deref(),
+        Some("Verse, mostly")
+    );
+}
+
+#[test]
+fn test_format_category_list_shows_descriptions() {
+    let mut storage = Storage::new();
+    store_category(
+        &mut storage,
+        Category::new("Poetry".to_string(), Some("Verse, mostly".to_string())),
+    )
+    .unwrap();
+    store_category(&mut storage, Category::new("Fiction".to_string(), None)).unwrap();
+
+    assert_eq!(
+        format_category_list(&storage),
+        "Fiction (0 books)\nPoetry (0 books) \u{2014} Verse, mostly"
+    );
+}
+
+/// Creates a storage with a "Sci-Fi" category holding two books and a
+/// "Fantasy" category holding one. Returns the storage and the Sci-Fi id.
+fn storage_with_sci_fi() -> (Storage, String) {
+    let mut storage = Storage::new();
+    let sci_fi = Category::new("Sci-Fi".to_string(), None);
+    let sci_fi_id = sci_fi.id.clone();
+    let fantasy = Category::new("Fantasy".to_string(), None);
+    let fantasy_id = fantasy.id.clone();
+    storage.add_category(sci_fi);
+    storage.add_category(fantasy);
+
+    for (title, category_id) in [
+        ("Dune", &sci_fi_id),
+        ("Hyperion", &sci_fi_id),
+        ("Earthsea", &fantasy_id),
+    ] {
+        storage.add_book(Book::new(
+            title.to_string(),
+            String::new(),
+            category_id.clone(),
+            "author".to_string(),
+            300,
+        ));
+    }
+    (storage, sci_fi_id)
+}
+
+fn category_of(storage: &Storage, title: &str) -> String {
+    let book = storage.books.values().find(|b| b.title == title).unwrap();
+    storage
+        .get_category(&book.category_id)
+        .unwrap()
+        .name
+        .clone()
+}
+
+#[test]
+fn test_recategorize_moves_books_into_new_category() {
+    let (mut storage, sci_fi_id) = storage_with_sci_fi();
+
+    let moved = storage
+        .recategorize("sci-fi", "Science Fiction", false)
+        .unwrap();
+
+    assert_eq!(moved, 2);
+    assert_eq!(category_of(&storage, "Dune"), "Science Fiction");
+    assert_eq!(category_of(&storage, "Hyperion"), "Science Fiction");
+    assert_eq!(category_of(&storage, "Earthsea"), "Fantasy");
+    // Without cleanup the empty source category is kept.
+    assert!(storage.get_category(&sci_fi_id).is_some());
+}
+
+#[test]
+fn test_recategorize_into_existing_category_and_delete_empty_source() {
+    let (mut storage, sci_fi_id) = storage_with_sci_fi();
+
+    let moved = storage.recategorize("Sci-Fi", "fantasy", true).unwrap();
+
+    assert_eq!(moved, 2);
+    assert_eq!(category_of(&storage, "Dune"), "Fantasy");
+    assert!(storage.get_category(&sci_fi_id).is_none());
+    assert_eq!(storage.categories.len(), 1);
+}
+
+#[test]
+fn test_recategorize_rejects_unknown_or_same_category() {
+    let (mut storage, _) = storage_with_sci_fi();
+
+    let err = storage
+        .recategorize("Horror", "Fantasy", false)
+        .unwrap_err();
+    assert!(err.contains("No category named 'Horror'"));
+
+    let err = storage.recategorize("Sci-Fi", "SCI-FI", true).unwrap_err();
+    assert!(err.contains("same category"));
+    assert_eq!(category_of(&storage, "Dune"), "Sci-Fi");
+}
+
+#[test]
+fn test_get_or_create_category_is_case_insensitive() {
+    let mut storage = Storage::new();
+    let id = get_or_create_category(&mut storage, "Fiction");
+    assert_eq!(get_or_create_category(&mut storage, "fiction"), id);
+    assert_eq!(storage.categories.len(), 1);
+}
@@ -0,0 +1,63 @@
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// Runs bookmon with its config kept under `home`, so the tests never touch the
+/// real config file.
+fn bookmon(home: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_bookmon"))
+        .args(args)
+        .env("HOME", home)
+        .env("XDG_CONFIG_HOME", home.join(".config"))
+        .output()
+        .expect("failed to run bookmon")
+}
+
+/// Points bookmon at a fresh storage file under `home`.
+fn with_storage(home: &Path) {
+    let storage = home.join("storage.json");
+    let output = bookmon(home, &["change-storage-path", storage.to_str().unwrap()]);
+    assert!(output.status.success());
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_grouped_and_legacy_goal_commands_are_interchangeable() {
+    let home = tempfile::tempdir().unwrap();
+    with_storage(home.path());
+
+    stdout(&bookmon(
+        home.path(),
+        &["goal", "set", "24", "--year", "2025"],
+    ));
+    stdout(&bookmon(home.path(), &["set-goal", "30", "--year", "2024"]));
+
+    let shown = stdout(&bookmon(home.path(), &["print-goal", "--year", "2025"]));
+    assert!(shown.contains("Reading goal 2025: 0/24 books"), "{}", shown);
+    let shown = stdout(&bookmon(home.path(), &["goal", "show", "--year", "2024"]));
+    assert!(shown.contains("Reading goal 2024: 0/30 books"), "{}", shown);
+}
+
+#[test]
+fn test_help_lists_groups_and_hides_legacy_commands() {
+    let home = tempfile::tempdir().unwrap();
+    let help = stdout(&bookmon(home.path(), &["--help"]));
+
+    for group in ["book", "series", "goal", "stats"] {
+        assert!(
+            help.lines()
+                .any(|line| line.trim_start().starts_with(&format!("{} ", group))),
+            "{}",
+            help
+        );
+    }
+    assert!(!help.contains("add-book"), "{}", help);
+    assert!(!help.contains("set-goal"), "{}", help);
+}
@@ -0,0 +1,14 @@
+use std::process::Command;
+
+#[test]
+fn test_bash_completions_mention_binary_name() {
+    let output = Command::new(env!("CARGO_BIN_EXE_bookmon"))
+        .args(["completions", "bash"])
+        .output()
+        .expect("failed to run bookmon");
+
+    assert!(output.status.success());
+    let script = String::from_utf8(output.stdout).unwrap();
+    assert!(!script.is_empty());
+    assert!(script.contains("bookmon"));
+}
@@ -43,7 +43,7 @@ debug: false
         let settings = create_test_settings(None);
 
         assert_eq!(settings.app_name, "BookMon");
-        assert_eq!(settings.debug, false);
+        assert!(!settings.debug);
         assert_eq!(settings.storage_file, "");
     }
 
@@ -58,7 +58,7 @@ storage_file: /custom/path/storage.json
         let settings = create_test_settings(Some(user_config));
 
         assert_eq!(settings.app_name, "CustomBookMon");
-        assert_eq!(settings.debug, true);
+        assert!(settings.debug);
         assert_eq!(settings.storage_file, "/custom/path/storage.json");
     }
 
@@ -74,7 +74,163 @@ storage_file: /custom/path/storage.json
         // app_name should remain default
         assert_eq!(settings.app_name, "BookMon");
         // other fields should be overridden
-        assert_eq!(settings.debug, true);
+        assert!(settings.debug);
         assert_eq!(settings.storage_file, "/custom/path/storage.json");
     }
+
+    fn writable_storage_config(extra: &str) -> (tempfile::TempDir, Settings) {
+        let dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let storage_file = dir.path().join("storage.json");
+        let user_config = format!("storage_file: {}\n{}", storage_file.display(), extra);
+        let settings = create_test_settings(Some(&user_config));
+        (dir, settings)
+    }
+
+    #[test]
+    fn test_validate_default_settings_pass() {
+        let (_dir, settings) = writable_storage_config("");
+
+        assert_eq!(settings.providers, vec!["openlibrary", "bibsok"]);
+        assert_eq!(settings.timezone, "UTC");
+        assert_eq!(settings.date_format, "%Y-%m-%d");
+        assert_eq!(settings.validate(), vec![]);
+    }
+
+    #[test]
+    fn test_validate_unknown_provider() {
+        let (_dir, settings) = writable_storage_config("providers:\n  - bibsok\n  - goodreads\n");
+
+        let issues = settings.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].setting, "providers");
+        assert!(issues[0].message.contains("goodreads"));
+    }
+
+    #[test]
+    fn test_validate_unparseable_date_format() {
+        let (_dir, settings) = writable_storage_config("date_format: \"%Y-%Q\"\n");
+
+        let issues = settings.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].setting, "date_format");
+    }
+
+    #[test]
+    fn test_date_fmt_returns_configured_format() {
+        let (_dir, settings) = writable_storage_config("date_format: \"%d.%m.%Y\"\n");
+        assert_eq!(settings.date_fmt(), Ok("%d.%m.%Y"));
+
+        let (_dir, settings) = writable_storage_config("date_format: \"%Y-%Q\"\n");
+        assert!(settings.date_fmt().is_err());
+    }
+
+    #[test]
+    fn test_validate_timezone_and_missing_storage_path() {
+        let settings = create_test_settings(Some("timezone: Mars/Olympus\n"));
+
+        let settings_with_issues: Vec<&str> =
+            settings.validate().iter().map(|i| i.setting).collect();
+        assert_eq!(settings_with_issues, vec!["storage_file", "timezone"]);
+    }
+
+    #[test]
+    fn test_settings_tz_resolves_configured_timezone() {
+        let settings = create_test_settings(Some("timezone: Europe/Oslo\n"));
+        assert_eq!(
+            settings.tz().unwrap(),
+            bookmon::time::Timezone::Named(chrono_tz::Europe::Oslo)
+        );
+
+        let settings = create_test_settings(Some("timezone: Mars/Olympus\n"));
+        assert!(settings.tz().is_err());
+    }
+
+    #[test]
+    fn test_default_book_status_maps_to_reading_events() {
+        use bookmon::storage::ReadingEvent;
+
+        let (_dir, settings) = writable_storage_config("default_book_status: both\n");
+        let status = settings.default_book_status().unwrap().unwrap();
+        assert_eq!(
+            status.events(),
+            vec![ReadingEvent::Bought, ReadingEvent::WantToRead]
+        );
+        assert!(settings.validate().is_empty());
+    }
+
+    #[test]
+    fn test_default_book_status_unset_by_default() {
+        let settings = create_test_settings(None);
+        assert_eq!(settings.default_book_status(), Ok(None));
+    }
+
+    #[test]
+    fn test_validate_unknown_default_book_status() {
+        let (_dir, settings) = writable_storage_config("default_book_status: borrowed\n");
+
+        assert!(settings.default_book_status().is_err());
+        let issues = settings.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].setting, "default_book_status");
+    }
+
+    #[test]
+    fn test_settings_to_json_lists_effective_settings() {
+        let settings = create_test_settings(Some(
+            "storage_file: /books/storage.json\ntimezone: Europe/Oslo\n",
+        ));
+
+        let json: serde_json::Value = serde_json::from_str(&settings.to_json()).unwrap();
+        let mut keys: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec![
+                "app_name",
+                "ascii_symbols",
+                "date_format",
+                "debug",
+                "default_book_status",
+                "finished_symbol",
+                "lookup_timeout_secs",
+                "providers",
+                "reading_symbol",
+                "repair_summary",
+                "stale_days",
+                "storage_file",
+                "strict_isbn",
+                "timezone",
+            ]
+        );
+        assert_eq!(json["storage_file"], "/books/storage.json");
+        assert_eq!(json["timezone"], "Europe/Oslo");
+        assert_eq!(json["date_format"], "%Y-%m-%d");
+        assert!(json["default_book_status"].is_null());
+    }
+
+    #[test]
+    fn test_status_symbols_from_settings() {
+        use bookmon::config::StatusSymbols;
+
+        assert_eq!(
+            create_test_settings(None).status_symbols(),
+            StatusSymbols::default()
+        );
+        assert_eq!(
+            create_test_settings(Some("ascii_symbols: true\n")).status_symbols(),
+            StatusSymbols::ascii()
+        );
+
+        let symbols = create_test_settings(Some(
+            "ascii_symbols: true\nfinished_symbol: \"*\"\nreading_symbol: \"\"\n",
+        ))
+        .status_symbols();
+        assert_eq!(symbols.finished, "*");
+        assert_eq!(symbols.reading, ">");
+    }
 }
@@ -0,0 +1,68 @@
+use bookmon::cover::{select_cover_source, terminal_supports_inline_images, CoverSource};
+
+#[test]
+fn test_select_cover_source_prefers_existing_local_file() {
+    let file = tempfile::NamedTempFile::new().unwrap();
+    let path = file.path().to_str().unwrap();
+
+    assert_eq!(
+        select_cover_source(Some(path), Some("https://covers.example.com/1.jpg")),
+        Some(CoverSource::Path(path))
+    );
+}
+
+#[test]
+fn test_select_cover_source_falls_back_to_url() {
+    assert_eq!(
+        select_cover_source(
+            Some("/no/such/cover.jpg"),
+            Some("https://covers.example.com/1.jpg")
+        ),
+        Some(CoverSource::Url("https://covers.example.com/1.jpg"))
+    );
+    assert_eq!(
+        select_cover_source(None, Some("http://covers.example.com/1.jpg")),
+        Some(CoverSource::Url("http://covers.example.com/1.jpg"))
+    );
+}
+
+#[test]
+fn test_select_cover_source_ignores_unusable_values() {
+    assert_eq!(select_cover_source(None, None), None);
+    assert_eq!(select_cover_source(Some(""), Some("  ")), None);
+    assert_eq!(
+        select_cover_source(None, Some("ftp://example.com/1.jpg")),
+        None
+    );
+}
+
+#[test]
+fn test_terminal_supports_inline_images() {
+    assert!(terminal_supports_inline_images(Some("iTerm.app"), None));
+    assert!(terminal_supports_inline_images(Some("WezTerm"), None));
+    assert!(terminal_supports_inline_images(
+        Some("tmux"),
+        Some("iTerm2")
+    ));
+    assert!(!terminal_supports_inline_images(
+        Some("Apple_Terminal"),
+        None
+    ));
+    assert!(!terminal_supports_inline_images(None, None));
+}
+
+#[test]
+fn test_is_enabled_matches_feature() {
+    assert_eq!(bookmon::cover::is_enabled(), cfg!(feature = "cover-art"));
+}
+
+#[cfg(feature = "cover-art")]
+#[test]
+fn test_inline_image_sequence_format() {
+    let sequence = bookmon::cover::inline_image_sequence(b"abc", 20);
+
+    assert_eq!(
+        sequence,
+        "\x1b]1337;File=inline=1;size=3;width=20;preserveAspectRatio=1:YWJj\x07"
+    );
+}
@@ -0,0 +1,130 @@
+use bookmon::id::SequentialIdGen;
+use bookmon::storage::{
+    Book, Edition, EditionFormat, Reading, ReadingEvent, ReadingMetadata, Storage,
+};
+use chrono::{Duration, Utc};
+
+/// Creates a storage holding a 400-page book with a paperback and a
+/// 600-minute audiobook edition. Returns the storage and the book's id.
+fn storage_with_editions() -> (Storage, String) {
+    let ids = SequentialIdGen::new("edition-");
+    let mut paperback = Edition::new_with_id_gen(EditionFormat::Paperback, &ids);
+    paperback.total_pages = Some(320);
+    let mut audiobook = Edition::new_with_id_gen(EditionFormat::Audiobook, &ids);
+    audiobook.duration_minutes = Some(600);
+
+    let mut book = Book::new(
+        "The Left Hand of Darkness".to_string(),
+        "9780441478125".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        400,
+    );
+    book.editions = vec![paperback, audiobook];
+    let book_id = book.id.clone();
+
+    let mut storage = Storage::new();
+    storage.add_book(book);
+    (storage, book_id)
+}
+
+fn add_update(storage: &mut Storage, reading: Reading, days_ago: i64) {
+    let mut reading = reading;
+    reading.created_on = Utc::now() - Duration::days(days_ago);
+    storage.add_reading(reading);
+}
+
+#[test]
+fn test_editions_round_trip_through_json() {
+    let (storage, book_id) = storage_with_editions();
+    let book = storage.get_book(&book_id).unwrap();
+
+    let json = serde_json::to_string(book).unwrap();
+    let parsed: Book = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed.editions, book.editions);
+    assert_eq!(parsed.editions[1].format, EditionFormat::Audiobook);
+    assert_eq!(parsed.editions[1].duration_minutes, Some(600));
+    assert_eq!(parsed.editions[1].total_pages, None);
+}
+
+#[test]
+fn test_books_and_readings_without_editions_stay_backward_compatible() {
+    let book = Book::new(
+        "Dune".to_string(),
+        "9780441013593".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        412,
+    );
+    let reading = Reading::new(book.id.clone(), ReadingEvent::Started);
+
+    let book_json = serde_json::to_string(&book).unwrap();
+    let reading_json = serde_json::to_string(&reading).unwrap();
+    assert!(!book_json.contains("editions"));
+    assert!(!reading_json.contains("edition_id"));
+
+    let old_reading = r#"{
+        "id": "r1",
+        "created_on": "2024-01-01T00:00:00Z",
+        "book_id": "b1",
+        "event": "Started"
+    }"#;
+    let parsed: Reading = serde_json::from_str(old_reading).unwrap();
+    assert_eq!(parsed.edition_id, None);
+}
+
+#[test]
+fn test_reading_edition_id_round_trips() {
+    let reading = Reading::new("b1".to_string(), ReadingEvent::Started).for_edition("edition-2");
+    let json = serde_json::to_string(&reading).unwrap();
+    let parsed: Reading = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.edition_id.as_deref(), Some("edition-2"));
+}
+
+#[test]
+fn test_progress_is_tracked_per_edition() {
+    let (mut storage, book_id) = storage_with_editions();
+
+    add_update(
+        &mut storage,
+        Reading::with_metadata(book_id.clone(), ReadingEvent::Update, 160).for_edition("edition-1"),
+        2,
+    );
+    let mut listened = Reading::new(book_id.clone(), ReadingEvent::Update).for_edition("edition-2");
+    listened.metadata = ReadingMetadata {
+        current_minute: Some(150),
+        ..ReadingMetadata::default()
+    };
+    add_update(&mut storage, listened, 1);
+    add_update(
+        &mut storage,
+        Reading::with_metadata(book_id.clone(), ReadingEvent::Update, 100),
+        0,
+    );
+
+    assert_eq!(
+        storage.edition_progress(&book_id, Some("edition-1")),
+        Some(50.0)
+    );
+    assert_eq!(
+        storage.edition_progress(&book_id, Some("edition-2")),
+        Some(25.0)
+    );
+    assert_eq!(storage.edition_progress(&book_id, None), Some(25.0));
+}
+
+#[test]
+fn test_edition_progress_is_none_without_updates_or_length() {
+    let (mut storage, book_id) = storage_with_editions();
+    assert_eq!(storage.edition_progress(&book_id, Some("edition-1")), None);
+    assert_eq!(storage.edition_progress(&book_id, Some("missing")), None);
+
+    // Audiobook updates need minutes listened, not a page number.
+    add_update(
+        &mut storage,
+        Reading::with_metadata(book_id.clone(), ReadingEvent::Update, 50).for_edition("edition-2"),
+        0,
+    );
+    assert_eq!(storage.edition_progress(&book_id, Some("edition-2")), None);
+}
@@ -0,0 +1,256 @@
+use bookmon::export::{
+    export_csv, export_ndjson_to_writer, export_openlibrary_json, export_reviews_csv,
+    write_csv_export, CSV_HEADER, REVIEWS_CSV_HEADER,
+};
+use bookmon::storage::{Author, Book, Reading, ReadingEvent, Review, Storage};
+use chrono::{Duration, TimeZone, Utc};
+use serde_json::Value;
+
+fn add_book(storage: &mut Storage, title: &str, author_id: &str, events: &[ReadingEvent]) {
+    let book = Book::new(
+        title.to_string(),
+        format!("isbn-{}", title),
+        "cat".to_string(),
+        author_id.to_string(),
+        100,
+    );
+    let start = Utc::now() - Duration::days(30);
+    for (i, event) in events.iter().enumerate() {
+        let mut reading = Reading::new(book.id.clone(), *event);
+        reading.created_on = start + Duration::days(i as i64);
+        storage.add_reading(reading);
+    }
+    storage.add_book(book);
+}
+
+#[test]
+fn test_export_openlibrary_json_shape_and_status_mapping() {
+    let mut storage = Storage::new();
+    let author = Author::new("Octavia E. Butler".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    add_book(
+        &mut storage,
+        "A",
+        &author_id,
+        &[ReadingEvent::Started, ReadingEvent::Finished],
+    );
+    add_book(
+        &mut storage,
+        "B",
+        &author_id,
+        &[ReadingEvent::Started, ReadingEvent::Update],
+    );
+    add_book(&mut storage, "C", &author_id, &[ReadingEvent::WantToRead]);
+    add_book(
+        &mut storage,
+        "D",
+        &author_id,
+        &[ReadingEvent::Finished, ReadingEvent::Bought],
+    );
+    add_book(
+        &mut storage,
+        "E",
+        &author_id,
+        &[ReadingEvent::WantToRead, ReadingEvent::UnmarkedAsWantToRead],
+    );
+    add_book(&mut storage, "F", &author_id, &[ReadingEvent::Bought]);
+    add_book(
+        &mut storage,
+        "G",
+        "missing-author",
+        &[ReadingEvent::Started],
+    );
+
+    let json: Value = serde_json::from_str(&export_openlibrary_json(&storage)).unwrap();
+    let records = json.as_array().unwrap();
+
+    let statuses: Vec<(&str, &str)> = records
+        .iter()
+        .map(|r| (r["title"].as_str().unwrap(), r["status"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            ("A", "already-read"),
+            ("B", "currently-reading"),
+            ("C", "want-to-read"),
+            ("D", "already-read"),
+            ("G", "currently-reading"),
+        ]
+    );
+
+    let first = records[0].as_object().unwrap();
+    let mut keys: Vec<&String> = first.keys().collect();
+    keys.sort();
+    assert_eq!(keys, vec!["authors", "isbn", "status", "title"]);
+    assert_eq!(first["isbn"], "isbn-A");
+    assert_eq!(first["authors"], serde_json::json!(["Octavia E. Butler"]));
+    assert_eq!(records[4]["authors"], serde_json::json!([]));
+}
+
+#[test]
+fn test_export_openlibrary_json_empty_library() {
+    assert_eq!(export_openlibrary_json(&Storage::new()), "[]");
+}
+
+/// Adds a book with the given title, added on the given day of January 2025.
+fn add_book_added_on(storage: &mut Storage, title: &str, day: u32) {
+    let mut book = Book::new(
+        title.to_string(),
+        format!("isbn-{}", title),
+        "cat".to_string(),
+        "author".to_string(),
+        100,
+    );
+    book.added_on = Utc.with_ymd_and_hms(2025, 1, day, 12, 0, 0).unwrap();
+    storage.add_book(book);
+}
+
+#[test]
+fn test_export_csv_since_filters_by_added_on() {
+    let mut storage = Storage::new();
+    add_book_added_on(&mut storage, "Old", 1);
+    add_book_added_on(&mut storage, "Boundary", 10);
+    add_book_added_on(&mut storage, "New", 20);
+
+    let since = Utc.with_ymd_and_hms(2025, 1, 10, 0, 0, 0).unwrap();
+    let csv = export_csv(&storage, Some(since), true, "%Y-%m-%d");
+    let lines: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(lines[0], CSV_HEADER);
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].starts_with("Boundary,"));
+    assert!(lines[2].starts_with("New,"));
+}
+
+#[test]
+fn test_export_csv_quotes_fields_with_commas_and_quotes() {
+    let mut storage = Storage::new();
+    add_book_added_on(&mut storage, "Hello, \"World\"", 1);
+
+    let csv = export_csv(&storage, None, false, "%Y-%m-%d");
+    assert!(csv.starts_with("\"Hello, \"\"World\"\"\","));
+}
+
+#[test]
+fn test_write_csv_export_append_skips_header_for_existing_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("books.csv");
+
+    let mut storage = Storage::new();
+    add_book_added_on(&mut storage, "First", 1);
+    assert_eq!(
+        write_csv_export(&storage, &path, None, true, "%Y-%m-%d").unwrap(),
+        1
+    );
+
+    add_book_added_on(&mut storage, "Second", 15);
+    let since = Utc.with_ymd_and_hms(2025, 1, 10, 0, 0, 0).unwrap();
+    assert_eq!(
+        write_csv_export(&storage, &path, Some(since), true, "%Y-%m-%d").unwrap(),
+        1
+    );
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], CSV_HEADER);
+    assert!(lines[1].starts_with("First,"));
+    assert!(lines[2].starts_with("Second,"));
+    assert_eq!(contents.matches(CSV_HEADER).count(), 1);
+}
+
+#[test]
+fn test_write_csv_export_without_append_replaces_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("books.csv");
+    std::fs::write(&path, "stale contents\n").unwrap();
+
+    let mut storage = Storage::new();
+    add_book_added_on(&mut storage, "Only", 1);
+    write_csv_export(&storage, &path, None, false, "%Y-%m-%d").unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(contents.starts_with(CSV_HEADER));
+    assert!(!contents.contains("stale"));
+}
+
+#[test]
+fn test_export_ndjson_to_writer_writes_one_valid_record_per_line() {
+    let mut storage = Storage::new();
+    let author = Author::new("Ursula K. Le Guin".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    add_book(
+        &mut storage,
+        "The Dispossessed",
+        &author_id,
+        &[ReadingEvent::Finished],
+    );
+    add_book(&mut storage, "Lavinia", &author_id, &[]);
+    add_book(&mut storage, "Tehanu", &author_id, &[ReadingEvent::Started]);
+
+    let mut out: Vec<u8> = Vec::new();
+    export_ndjson_to_writer(&storage, &mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+
+    assert!(text.ends_with('\n'));
+    let records: Vec<Value> = text
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is valid JSON"))
+        .collect();
+    assert_eq!(records.len(), 3);
+    for record in &records {
+        assert_eq!(record["author"], "Ursula K. Le Guin");
+        assert_eq!(record["total_pages"], 100);
+    }
+    let finished = records
+        .iter()
+        .find(|r| r["title"] == "The Dispossessed")
+        .unwrap();
+    assert_eq!(finished["status"], "finished");
+    let unread = records.iter().find(|r| r["title"] == "Lavinia").unwrap();
+    assert!(unread.get("status").is_none());
+}
+
+#[test]
+fn test_export_ndjson_to_writer_empty_library_writes_nothing() {
+    let mut out: Vec<u8> = Vec::new();
+    export_ndjson_to_writer(&Storage::new(), &mut out).unwrap();
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_export_reviews_csv_quotes_multiline_text() {
+    let mut storage = Storage::new();
+    let author = Author::new("Jane Austen".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    add_book(&mut storage, "Emma", &author_id, &[ReadingEvent::Finished]);
+    let book_id = storage.books.values().next().unwrap().id.clone();
+
+    let mut review = Review::new(
+        book_id.clone(),
+        "Loved it.\nEmma is \"handsome, clever, and rich\".".to_string(),
+    );
+    review.created_on = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+    review.rating = Some(5);
+    storage.add_review(review);
+
+    let mut unrated = Review::new(book_id, "Second read".to_string());
+    unrated.created_on = Utc.with_ymd_and_hms(2025, 4, 1, 12, 0, 0).unwrap();
+    storage.add_review(unrated);
+
+    let csv = export_reviews_csv(&storage, "%Y-%m-%d");
+    assert_eq!(
+        csv,
+        format!(
+            "{}\n\
+             Emma,Jane Austen,2025-03-01,5,\"Loved it.\nEmma is \"\"handsome, clever, and rich\"\".\"\n\
+             Emma,Jane Austen,2025-04-01,,Second read\n",
+            REVIEWS_CSV_HEADER
+        )
+    );
+}
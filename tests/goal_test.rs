@@ -1,4 +1,10 @@
-use bookmon::goal::motivational_pace_text;
+use bookmon::goal::{
+    format_challenge_status, format_goal_footer, format_implied_pages, format_pace_comparison,
+    goal_history_rows, motivational_pace_text, render_progress_bar, same_day_of_year,
+    PROGRESS_BAR_WIDTH,
+};
+use bookmon::storage::{Book, Category, Reading, ReadingEvent, Storage, DEFAULT_GOAL_SUGGESTION};
+use bookmon::time::Timezone;
 use chrono::TimeZone;
 use chrono::Utc;
 
@@ -253,3 +259,361 @@ fn test_december_singular_remaining() {
         text
     );
 }
+
+// ── Pace comparison between years ───────────────────────────────
+
+#[test]
+fn test_same_day_of_year_matches_calendar_date() {
+    let mar_1 = chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    assert_eq!(same_day_of_year(mar_1, 2024), 61);
+    assert_eq!(same_day_of_year(mar_1, 2023), 60);
+}
+
+#[test]
+fn test_same_day_of_year_maps_feb_29_to_feb_28() {
+    let feb_29 = chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    assert_eq!(same_day_of_year(feb_29, 2023), 59);
+    assert_eq!(same_day_of_year(feb_29, 2020), 60);
+}
+
+#[test]
+fn test_format_pace_comparison() {
+    let line = format_pace_comparison(2025, 12, &[(2024, 9), (2023, 14), (2022, 12)]).unwrap();
+    assert_eq!(
+        line,
+        "So far in 2025: 12 books \u{2014} ahead of 2024 (9), behind 2023 (14), level with 2022 (12)"
+    );
+}
+
+#[test]
+fn test_format_pace_comparison_without_previous_years() {
+    assert_eq!(format_pace_comparison(2025, 3, &[]), None);
+}
+
+// ── Default-run footer ───────────────────────────────────────────
+
+fn storage_with_finished_in_2026(count: u32) -> Storage {
+    let mut storage = Storage::new();
+    for i in 0..count {
+        let book = Book::new(
+            format!("Book {}", i),
+            format!("isbn-{}", i),
+            "category".to_string(),
+            "author".to_string(),
+            200,
+        );
+        let mut reading = Reading::new(book.id.clone(), ReadingEvent::Finished);
+        reading.created_on = utc(2026, 1, 10 + i);
+        storage.add_reading(reading);
+        storage.add_book(book);
+    }
+    storage
+}
+
+#[test]
+fn test_goal_footer_shows_progress_and_pace() {
+    let mut storage = storage_with_finished_in_2026(6);
+    storage.set_goal(2026, 24);
+    let now = utc(2026, 7, 1);
+
+    // 18 books left over 6 months
+    assert_eq!(
+        format_goal_footer(&storage, &Timezone::Utc, now).unwrap(),
+        format!(
+            "Reading goal 2026: 6/24 books \u{2014} {}",
+            motivational_pace_text(6, 24, 2026, now).unwrap()
+        )
+    );
+    assert_eq!(
+        format_goal_footer(&storage, &Timezone::Utc, now).unwrap(),
+        "Reading goal 2026: 6/24 books \u{2014} That's about 3 books per month \u{2014} time to pick up the pace!"
+    );
+}
+
+// ── Goal suggestion ──────────────────────────────────────────────
+
+fn add_finished_in(storage: &mut Storage, year: i32, count: u32) {
+    for i in 0..count {
+        let book = Book::new(
+            format!("Book {} from {}", i, year),
+            format!("isbn-{}-{}", year, i),
+            "category".to_string(),
+            "author".to_string(),
+            200,
+        );
+        let mut reading = Reading::new(book.id.clone(), ReadingEvent::Finished);
+        reading.created_on = utc(year, 3, 1 + i);
+        storage.add_reading(reading);
+        storage.add_book(book);
+    }
+}
+
+#[test]
+fn test_suggested_goal_averages_the_last_three_years() {
+    let mut storage = Storage::new();
+    add_finished_in(&mut storage, 2022, 25);
+    add_finished_in(&mut storage, 2023, 10);
+    add_finished_in(&mut storage, 2024, 14);
+    add_finished_in(&mut storage, 2025, 20);
+    add_finished_in(&mut storage, 2026, 3);
+
+    // (10 + 14 + 20) / 3 = 14.67, ignoring 2022 and the current year
+    assert_eq!(storage.suggested_goal(2026, &Timezone::Utc), 15);
+}
+
+#[test]
+fn test_suggested_goal_only_counts_years_since_the_first_finished_book() {
+    let mut storage = Storage::new();
+    add_finished_in(&mut storage, 2025, 8);
+
+    assert_eq!(storage.suggested_goal(2026, &Timezone::Utc), 8);
+    // A year without finished books still counts once reading has started
+    assert_eq!(storage.suggested_goal(2027, &Timezone::Utc), 4);
+}
+
+#[test]
+fn test_suggested_goal_defaults_without_history() {
+    let mut storage = Storage::new();
+    assert_eq!(
+        storage.suggested_goal(2026, &Timezone::Utc),
+        DEFAULT_GOAL_SUGGESTION
+    );
+
+    // Books finished this year aren't history yet
+    add_finished_in(&mut storage, 2026, 5);
+    assert_eq!(
+        storage.suggested_goal(2026, &Timezone::Utc),
+        DEFAULT_GOAL_SUGGESTION
+    );
+}
+
+// ── Implied pages ────────────────────────────────────────────────
+
+#[test]
+fn test_average_finished_pages_skips_books_without_pages() {
+    let mut storage = Storage::new();
+    assert_eq!(storage.average_finished_pages(), None);
+
+    add_finished_in(&mut storage, 2025, 2);
+    let long = Book::new(
+        "Long".to_string(),
+        "isbn-long".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        500,
+    );
+    let unknown = Book::new(
+        "Unknown Length".to_string(),
+        "isbn-unknown".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        0,
+    );
+    let unread = Book::new(
+        "Unread".to_string(),
+        "isbn-unread".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        1000,
+    );
+    for book in [&long, &unknown] {
+        let mut reading = Reading::new(book.id.clone(), ReadingEvent::Finished);
+        reading.created_on = utc(2025, 5, 1);
+        storage.add_reading(reading);
+    }
+    storage.add_book(long);
+    storage.add_book(unknown);
+    storage.add_book(unread);
+
+    // (200 + 200 + 500) / 3
+    assert_eq!(storage.average_finished_pages(), Some(300.0));
+}
+
+#[test]
+fn test_format_implied_pages() {
+    assert_eq!(
+        format_implied_pages(24, Some(312.4)),
+        "That's about 7498 pages at your average of 312 pages per book."
+    );
+    assert_eq!(
+        format_implied_pages(24, None),
+        "Finish a book with a page count to estimate how many pages your goal is."
+    );
+}
+
+#[test]
+fn test_goal_footer_none_without_goal_this_year() {
+    let mut storage = storage_with_finished_in_2026(2);
+    storage.set_goal(2025, 10);
+    assert_eq!(
+        format_goal_footer(&storage, &Timezone::Utc, utc(2026, 3, 1)),
+        None
+    );
+}
+
+// ── Progress bar ─────────────────────────────────────────────────
+
+#[test]
+fn test_progress_bar_reflects_goal_ratio() {
+    // 6 of 24 books is a quarter of the bar
+    let bar = render_progress_bar(6, 24, PROGRESS_BAR_WIDTH);
+    assert_eq!(bar.chars().count(), PROGRESS_BAR_WIDTH);
+    assert_eq!(
+        bar,
+        format!("{}{}", "\u{2588}".repeat(5), "\u{2591}".repeat(15))
+    );
+
+    assert_eq!(render_progress_bar(0, 24, 10), "\u{2591}".repeat(10));
+    assert_eq!(
+        render_progress_bar(12, 24, 10),
+        format!("{}{}", "\u{2588}".repeat(5), "\u{2591}".repeat(5))
+    );
+}
+
+#[test]
+fn test_progress_bar_full_when_goal_reached_or_zero() {
+    assert_eq!(render_progress_bar(30, 24, 10), "\u{2588}".repeat(10));
+    assert_eq!(render_progress_bar(0, 0, 10), "\u{2588}".repeat(10));
+    assert!(motivational_pace_text(0, 0, 2026, utc(2026, 5, 1))
+        .unwrap()
+        .contains("reached"));
+}
+
+// ── Goal history ─────────────────────────────────────────────────
+
+fn add_finished_book(storage: &mut Storage, title: &str, finished_on: chrono::DateTime<Utc>) {
+    let book = Book::new(
+        title.to_string(),
+        format!("isbn-{}", title),
+        "category".to_string(),
+        "author".to_string(),
+        200,
+    );
+    let mut reading = Reading::new(book.id.clone(), ReadingEvent::Finished);
+    reading.created_on = finished_on;
+    storage.add_reading(reading);
+    storage.add_book(book);
+}
+
+fn storage_with_goal_history() -> Storage {
+    let mut storage = Storage::new();
+    // 2024: goal 2, finished 3 (met). 2025: goal 4, finished 1 (unmet).
+    add_finished_book(&mut storage, "A", utc(2024, 2, 1));
+    add_finished_book(&mut storage, "B", utc(2024, 6, 1));
+    add_finished_book(&mut storage, "C", utc(2024, 11, 1));
+    add_finished_book(&mut storage, "D", utc(2025, 3, 1));
+    storage.set_goal(2025, 4);
+    storage.set_goal(2024, 2);
+    storage
+}
+
+#[test]
+fn test_goal_vs_actual_lists_goal_years_oldest_first() {
+    let storage = storage_with_goal_history();
+    assert_eq!(
+        storage.goal_vs_actual(&Timezone::Utc),
+        vec![(2024, 2, 3), (2025, 4, 1)]
+    );
+}
+
+#[test]
+fn test_goal_history_marks_met_past_year_as_hit() {
+    let storage = storage_with_goal_history();
+    let rows = goal_history_rows(&storage.goal_vs_actual(&Timezone::Utc), utc(2026, 3, 1));
+    assert_eq!(rows[0][0], "Year");
+    assert_eq!(rows[1][..4], ["2024", "2", "3", "\u{2713} hit"]);
+    assert_eq!(
+        rows[1][4],
+        motivational_pace_text(3, 2, 2024, utc(2026, 3, 1)).unwrap()
+    );
+}
+
+#[test]
+fn test_goal_history_marks_unmet_past_year_as_missed() {
+    let storage = storage_with_goal_history();
+    let rows = goal_history_rows(&storage.goal_vs_actual(&Timezone::Utc), utc(2026, 3, 1));
+    assert_eq!(rows[2][..4], ["2025", "4", "1", "\u{2717} missed"]);
+    // No pace text for a past year that wasn't reached
+    assert_eq!(rows[2][4], "");
+}
+
+#[test]
+fn test_goal_history_current_year_is_in_progress() {
+    let mut storage = storage_with_goal_history();
+    storage.set_goal(2026, 10);
+    let now = utc(2026, 3, 1);
+    let rows = goal_history_rows(&storage.goal_vs_actual(&Timezone::Utc), now);
+    assert_eq!(rows[3][..4], ["2026", "10", "0", "in progress"]);
+    assert_eq!(
+        rows[3][4],
+        motivational_pace_text(0, 10, 2026, now).unwrap()
+    );
+}
+
+// ── Category reading challenge ───────────────────────────────────
+
+fn storage_with_challenge() -> Storage {
+    let mut storage = Storage::new();
+    let fantasy = Category::new("Fantasy".to_string(), None);
+    let poetry = Category::new("Poetry".to_string(), None);
+    for (i, category_id) in [&fantasy.id, &fantasy.id, &fantasy.id, &poetry.id]
+        .into_iter()
+        .enumerate()
+    {
+        let book = Book::new(
+            format!("Book {}", i),
+            format!("isbn-{}", i),
+            category_id.clone(),
+            "author".to_string(),
+            200,
+        );
+        let mut reading = Reading::new(book.id.clone(), ReadingEvent::Finished);
+        reading.created_on = utc(2026, 2, 1 + i as u32);
+        storage.add_reading(reading);
+        storage.add_book(book);
+    }
+    storage.add_category(fantasy);
+    storage.add_category(poetry);
+    storage
+}
+
+#[test]
+fn test_challenge_status_met_and_unmet_quotas() {
+    let mut storage = storage_with_challenge();
+    storage.set_category_goal(2026, "fantasy", 3).unwrap();
+    storage.set_category_goal(2026, "Poetry", 3).unwrap();
+
+    let status = storage.challenge_status(2026, &Timezone::Utc);
+    assert_eq!(
+        status,
+        vec![("Fantasy".to_string(), 3, 3), ("Poetry".to_string(), 3, 1)]
+    );
+    assert_eq!(
+        format_challenge_status(&status),
+        vec![
+            "  \u{2713} Fantasy: 3/3 books".to_string(),
+            "  \u{2717} Poetry: 1/3 books".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_challenge_status_only_counts_the_requested_year() {
+    let mut storage = storage_with_challenge();
+    storage.set_category_goal(2025, "Fantasy", 1).unwrap();
+    assert_eq!(
+        storage.challenge_status(2025, &Timezone::Utc),
+        vec![("Fantasy".to_string(), 1, 0)]
+    );
+    assert!(storage.challenge_status(2026, &Timezone::Utc).is_empty());
+}
+
+#[test]
+fn test_category_goal_requires_existing_category() {
+    let mut storage = storage_with_challenge();
+    assert_eq!(
+        storage.set_category_goal(2026, "Horror", 2),
+        Err("No category named 'Horror'".to_string())
+    );
+    assert!(storage.category_goals.is_empty());
+}
@@ -1,4 +1,7 @@
-use bookmon::lookup::http_client::HttpClient;
+use bookmon::lookup::book_lookup_dto::BookLookupDTO;
+use bookmon::lookup::http_client::{HttpClient, LOOKUP_TIMED_OUT, NETWORK_DISABLED};
+use bookmon::lookup::providers::BookProvider;
+use std::error::Error;
 use std::time::Duration;
 use tokio::time::sleep;
 
@@ -82,3 +85,143 @@ struct AuthorDetails {
     birth_date: &'static str,
     death_date: &'static str,
 }
+
+/// A provider that fails the test if it is ever asked to look anything up.
+struct PanickingProvider;
+
+#[async_trait::async_trait]
+impl BookProvider for PanickingProvider {
+    fn name(&self) -> &'static str {
+        "panicking"
+    }
+
+    async fn get_book_by_isbn(&self, _isbn: &str) -> Result<Option<BookLookupDTO>, Box<dyn Error>> {
+        panic!("no request should be made with network disabled");
+    }
+}
+
+#[tokio::test]
+async fn test_no_network_client_never_calls_providers() {
+    let client = HttpClient::from_providers(vec![Box::new(PanickingProvider)]).with_network(false);
+
+    assert!(!client.is_network_enabled());
+    let err = client.get_book_by_isbn("9780142410349").await.unwrap_err();
+    assert_eq!(err.to_string(), NETWORK_DISABLED);
+}
+
+/// A provider that finds nothing.
+struct EmptyProvider;
+
+#[async_trait::async_trait]
+impl BookProvider for EmptyProvider {
+    fn name(&self) -> &'static str {
+        "empty"
+    }
+
+    async fn get_book_by_isbn(&self, _isbn: &str) -> Result<Option<BookLookupDTO>, Box<dyn Error>> {
+        Ok(None)
+    }
+}
+
+/// A provider that finds every ISBN.
+struct FindingProvider;
+
+#[async_trait::async_trait]
+impl BookProvider for FindingProvider {
+    fn name(&self) -> &'static str {
+        "finding"
+    }
+
+    async fn get_book_by_isbn(&self, isbn: &str) -> Result<Option<BookLookupDTO>, Box<dyn Error>> {
+        Ok(Some(BookLookupDTO {
+            title: "Found".to_string(),
+            subtitle: None,
+            authors: vec![],
+            description: None,
+            isbn: isbn.to_string(),
+            publish_date: None,
+            publisher: None,
+            cover_url: None,
+            total_pages: None,
+            series_name: None,
+            series_position: None,
+            source: None,
+        }))
+    }
+}
+
+#[tokio::test]
+async fn test_lookup_records_the_provider_that_found_the_book() {
+    let client =
+        HttpClient::from_providers(vec![Box::new(EmptyProvider), Box::new(FindingProvider)]);
+    let book = client
+        .get_book_by_isbn("9780142410349")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(book.source.as_deref(), Some("finding"));
+}
+
+/// A provider that takes far longer than any test timeout to answer.
+struct SlowProvider;
+
+#[async_trait::async_trait]
+impl BookProvider for SlowProvider {
+    fn name(&self) -> &'static str {
+        "slow"
+    }
+
+    async fn get_book_by_isbn(&self, isbn: &str) -> Result<Option<BookLookupDTO>, Box<dyn Error>> {
+        sleep(Duration::from_secs(30)).await;
+        FindingProvider.get_book_by_isbn(isbn).await
+    }
+}
+
+#[tokio::test]
+async fn test_slow_lookup_times_out() {
+    let client = HttpClient::from_providers(vec![Box::new(SlowProvider)])
+        .with_timeout(Duration::from_millis(50));
+    let err = client.get_book_by_isbn("9780142410349").await.unwrap_err();
+    assert_eq!(err.to_string(), LOOKUP_TIMED_OUT);
+}
+
+#[test]
+fn test_add_flow_lookup_falls_back_to_manual_entry_after_timeout() {
+    let client = HttpClient::from_providers(vec![Box::new(SlowProvider)])
+        .with_timeout(Duration::from_millis(50));
+    let started = std::time::Instant::now();
+    let result = bookmon::book::lookup_with_spinner(&client, "9780142410349").unwrap();
+    assert!(
+        result.is_none(),
+        "a timed-out lookup leaves the details to the user"
+    );
+    assert!(started.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn test_get_isbn_with_no_network_does_not_look_up() {
+    let home = tempfile::tempdir().unwrap();
+    let config_dir = home.path().join(".config").join("bookmon");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    std::fs::write(
+        config_dir.join("config.yml"),
+        format!(
+            "storage_file: {}\n",
+            home.path().join("storage.json").display()
+        ),
+    )
+    .unwrap();
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_bookmon"))
+        .args(["--no-network", "get-isbn", "9780142410349"])
+        .env("HOME", home.path())
+        .env_remove("XDG_CONFIG_HOME")
+        .output()
+        .expect("failed to run bookmon");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(NETWORK_DISABLED),
+        "unexpected stderr: {}",
+        stderr
+    );
+}
@@ -0,0 +1,67 @@
+use bookmon::id::{IdGen, SequentialIdGen, UuidGen};
+use bookmon::storage::{Author, Book, Category, Reading, ReadingEvent, Review, Series};
+use std::cell::RefCell;
+
+#[test]
+fn test_sequential_id_gen_counts_up_from_one() {
+    let ids = SequentialIdGen::new("book-");
+    assert_eq!(ids.new_id(), "book-1");
+    assert_eq!(ids.new_id(), "book-2");
+    assert_eq!(ids.new_id(), "book-3");
+}
+
+#[test]
+fn test_constructors_take_ids_from_the_generator() {
+    let ids = SequentialIdGen::new("id-");
+
+    let author = Author::new_with_id_gen("Ursula K. Le Guin".to_string(), &ids);
+    let category = Category::new_with_id_gen("Fiction".to_string(), None, &ids);
+    let series = Series::new_with_id_gen("Earthsea".to_string(), &ids);
+    let book = Book::new_with_id_gen(
+        "A Wizard of Earthsea".to_string(),
+        "9780547773742".to_string(),
+        category.id.clone(),
+        author.id.clone(),
+        183,
+        &ids,
+    );
+    let reading = Reading::new_with_id_gen(book.id.clone(), ReadingEvent::Started, &ids);
+    let review = Review::new_with_id_gen(book.id.clone(), "Lovely.".to_string(), &ids);
+
+    assert_eq!(
+        [
+            author.id,
+            category.id,
+            series.id,
+            book.id,
+            reading.id,
+            review.id
+        ],
+        ["id-1", "id-2", "id-3", "id-4", "id-5", "id-6"]
+    );
+    assert_eq!(book.author_id, "id-1");
+    assert_eq!(reading.book_id, "id-4");
+}
+
+/// A generator handing out IDs from a fixed list, to show any `IdGen` works.
+struct ListIdGen(RefCell<Vec<&'static str>>);
+
+impl IdGen for ListIdGen {
+    fn new_id(&self) -> String {
+        self.0.borrow_mut().remove(0).to_string()
+    }
+}
+
+#[test]
+fn test_custom_id_gen_is_used() {
+    let ids = ListIdGen(RefCell::new(vec!["first", "second"]));
+    assert_eq!(Author::new_with_id_gen("A".to_string(), &ids).id, "first");
+    assert_eq!(Series::new_with_id_gen("S".to_string(), &ids).id, "second");
+}
+
+#[test]
+fn test_default_constructors_still_use_uuids() {
+    let author = Author::new("Anyone".to_string());
+    assert_eq!(author.id.len(), 36);
+    assert_ne!(UuidGen.new_id(), UuidGen.new_id());
+}
@@ -1,3 +1,5 @@
+use bookmon::book::book_choices;
+use bookmon::reading::record_reading_event;
 use bookmon::storage::{Author, Book, Category, Reading, ReadingEvent, Storage};
 
 #[test]
@@ -19,12 +21,77 @@ fn test_to_display_string_with_missing_author_returns_error() {
 }
 
 #[test]
-fn test_title_from_display_string_with_title_containing_by() {
-    // A title containing " by " should be parsed correctly
-    let display = "[Started] \"Stand by Me\" by Stephen King";
-    let title = Book::title_from_display_string(display);
-    assert!(title.is_ok());
-    assert_eq!(title.unwrap(), "Stand by Me");
+fn test_book_titled_with_by_can_be_selected_and_acted_upon() {
+    let mut storage = Storage::new();
+    let author = Author::new("Anne Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let mut ids = Vec::new();
+    for title in ["Death by Chocolate", "Death"] {
+        let book = Book::new(
+            title.to_string(),
+            "isbn".to_string(),
+            "category".to_string(),
+            author_id.clone(),
+            200,
+        );
+        ids.push(book.id.clone());
+        storage.add_book(book);
+    }
+
+    let books: Vec<&Book> = storage.books.values().collect();
+    let choices = book_choices(&storage, &books).unwrap();
+    let choice = choices
+        .iter()
+        .find(|c| c.to_string() == "[Not Started] \"Death by Chocolate\" by Anne Author")
+        .expect("the book should be offered")
+        .clone();
+    assert_eq!(choice.book_id, ids[0]);
+
+    record_reading_event(&mut storage, &choice.book_id, ReadingEvent::Started, None).unwrap();
+    assert!(storage.is_book_started(&ids[0]));
+    assert!(!storage.is_book_started(&ids[1]));
+}
+
+#[test]
+fn test_book_choices_put_started_books_first_then_sort_by_author_and_title() {
+    let mut storage = Storage::new();
+    let mut add = |title: &str, author_name: &str, started: bool| {
+        let author = Author::new(author_name.to_string());
+        let book = Book::new(
+            title.to_string(),
+            "isbn".to_string(),
+            "category".to_string(),
+            author.id.clone(),
+            200,
+        );
+        if started {
+            storage.add_reading(Reading::new(book.id.clone(), ReadingEvent::Started));
+        }
+        storage.add_author(author);
+        storage.add_book(book);
+    };
+    add("Zebra", "Alice", false);
+    add("Apple", "Bob", false);
+    add("Middle", "Zed", true);
+    add("Aardvark", "Alice", false);
+
+    let books: Vec<&Book> = storage.books.values().collect();
+    let labels: Vec<String> = book_choices(&storage, &books)
+        .unwrap()
+        .iter()
+        .map(|c| c.to_string())
+        .collect();
+    assert_eq!(
+        labels,
+        vec![
+            "[Started] \"Middle\" by Zed",
+            "[Not Started] \"Aardvark\" by Alice",
+            "[Not Started] \"Zebra\" by Alice",
+            "[Not Started] \"Apple\" by Bob",
+        ]
+    );
 }
 
 #[test]
@@ -128,27 +195,16 @@ fn test_book_selection_from_display_string() {
     // Add a reading event to mark it as started
     storage.add_reading(Reading::new(book_id.clone(), ReadingEvent::Started));
 
-    // Create the display string using the new method
-    let display = storage
-        .books
-        .get(&book_id)
-        .unwrap()
-        .to_display_string(&storage, "Started")
-        .unwrap();
-
-    // Extract the title using the new method
-    let title = Book::title_from_display_string(&display).unwrap();
-
-    // Find the book by title
-    let selected_book = storage
-        .books
-        .values()
-        .find(|b| b.title == title)
-        .expect("Selected book not found");
-
+    // The selected option carries the book's ID
+    let books: Vec<&Book> = storage.books.values().collect();
+    let choices = book_choices(&storage, &books).unwrap();
     assert_eq!(
-        selected_book.id, book_id,
-        "Should find the correct book by title"
+        choices[0].to_string(),
+        "[Started] \"Fourth Wing\" by Rebecca Yarros"
+    );
+    assert_eq!(
+        choices[0].book_id, book_id,
+        "Should find the correct book by ID"
     );
 }
 
@@ -176,26 +232,15 @@ fn test_book_selection_with_quoted_titles() {
     let book_id = book.id.clone();
     storage.books.insert(book_id.clone(), book);
 
-    // Create the display string using the new method
-    let display = storage
-        .books
-        .get(&book_id)
-        .unwrap()
-        .to_display_string(&storage, "Not Started")
-        .unwrap();
-
-    // Extract the title using the new method
-    let title = Book::title_from_display_string(&display).unwrap();
-
-    // Find the book by title
-    let selected_book = storage
-        .books
-        .values()
-        .find(|b| b.title == title)
-        .expect("Selected book not found");
-
+    // The selected option carries the book's ID
+    let books: Vec<&Book> = storage.books.values().collect();
+    let choices = book_choices(&storage, &books).unwrap();
+    assert_eq!(
+        choices[0].to_string(),
+        "[Not Started] \"The \"Great\" Gatsby\" by Test Author"
+    );
     assert_eq!(
-        selected_book.id, book_id,
-        "Should find the correct book by title"
+        choices[0].book_id, book_id,
+        "Should find the correct book by ID"
     );
 }
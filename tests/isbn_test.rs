@@ -0,0 +1,75 @@
+use bookmon::isbn::{
+    format_isbn_suggestions, is_valid_isbn13, suggest_isbn_corrections, to_isbn10, to_isbn13,
+};
+
+#[test]
+fn test_is_valid_isbn13() {
+    assert!(is_valid_isbn13("9780142410349"));
+    assert!(is_valid_isbn13("978-0-14-241034-9"));
+    assert!(!is_valid_isbn13("9780142410348"));
+    assert!(!is_valid_isbn13("014241034X"));
+    assert!(!is_valid_isbn13("not an isbn"));
+}
+
+#[test]
+fn test_suggest_corrected_check_digit() {
+    let suggestions = suggest_isbn_corrections("9780142410348");
+    assert_eq!(
+        suggestions.first().map(String::as_str),
+        Some("9780142410349")
+    );
+}
+
+#[test]
+fn test_suggest_adjacent_transposition() {
+    // "01" typed as "10"
+    let suggestions = suggest_isbn_corrections("978-1-04-241034-9");
+    assert!(
+        suggestions.contains(&"9780142410349".to_string()),
+        "Expected the transposition fix in {:?}",
+        suggestions
+    );
+    // Every suggestion is itself a valid ISBN
+    assert!(suggestions.iter().all(|s| is_valid_isbn13(s)));
+}
+
+#[test]
+fn test_no_suggestions_for_valid_or_malformed_isbns() {
+    assert!(suggest_isbn_corrections("9780142410349").is_empty());
+    assert!(suggest_isbn_corrections("12345").is_empty());
+    assert!(suggest_isbn_corrections("97801424103AB").is_empty());
+    assert_eq!(format_isbn_suggestions("9780142410349"), None);
+}
+
+#[test]
+fn test_format_isbn_suggestions() {
+    let hint = format_isbn_suggestions("9780142410348").unwrap();
+    assert!(hint.starts_with("Did you mean 9780142410349"));
+    assert!(hint.ends_with('?'));
+}
+
+#[test]
+fn test_978_isbn_has_both_forms() {
+    assert_eq!(
+        to_isbn13("978-0-14-241034-9").as_deref(),
+        Some("9780142410349")
+    );
+    assert_eq!(to_isbn10("9780142410349").as_deref(), Some("0142410349"));
+    // ISBN-10 input converts both ways, including an 'X' check character
+    assert_eq!(to_isbn13("0142410349").as_deref(), Some("9780142410349"));
+    assert_eq!(to_isbn10("080442957X").as_deref(), Some("080442957X"));
+    assert_eq!(to_isbn13("080442957x").as_deref(), Some("9780804429573"));
+}
+
+#[test]
+fn test_979_isbn_has_no_isbn10() {
+    assert_eq!(to_isbn13("9791032305690").as_deref(), Some("9791032305690"));
+    assert_eq!(to_isbn10("9791032305690"), None);
+}
+
+#[test]
+fn test_invalid_isbn_has_no_normalized_forms() {
+    assert_eq!(to_isbn13("9780142410348"), None);
+    assert_eq!(to_isbn10("0142410348"), None);
+    assert_eq!(to_isbn13("not an isbn"), None);
+}
@@ -0,0 +1,126 @@
+use bookmon::merge::merge_storage;
+use bookmon::storage::{
+    Author, Book, Category, Quote, Reading, ReadingEvent, Review, Series, Storage,
+};
+
+/// Creates a storage with one author, one category and one book by that author.
+fn create_storage(author_name: &str, title: &str, isbn: &str) -> (Storage, String) {
+    let mut storage = Storage::new();
+    let author = Author::new(author_name.to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    let book = Book::new(
+        title.to_string(),
+        isbn.to_string(),
+        category_id,
+        author_id,
+        200,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+    (storage, book_id)
+}
+
+#[test]
+fn test_merge_dedups_authors_and_repoints_books() {
+    let (mut into, _) = create_storage("Ursula K. Le Guin", "The Dispossessed", "111");
+    let (other, other_book_id) = create_storage("ursula k. le guin", "The Lathe of Heaven", "222");
+
+    let summary = merge_storage(&mut into, &other);
+
+    assert_eq!(into.authors.len(), 1);
+    assert_eq!(into.categories.len(), 1);
+    assert_eq!(summary.authors_added, 0);
+    assert_eq!(summary.categories_added, 0);
+    assert_eq!(summary.books_added, 1);
+
+    let author_id = into.authors.keys().next().unwrap();
+    let category_id = into.categories.keys().next().unwrap();
+    let merged = into.books.get(&other_book_id).unwrap();
+    assert_eq!(&merged.author_id, author_id);
+    assert_eq!(&merged.category_id, category_id);
+}
+
+#[test]
+fn test_merge_duplicate_isbn_keeps_both_sets_of_readings() {
+    let (mut into, book_id) = create_storage("Iain M. Banks", "Excession", "978-1-85723-394-4");
+    into.add_reading(Reading::new(book_id.clone(), ReadingEvent::Bought));
+
+    let (mut other, other_book_id) = create_storage("Iain M. Banks", "Excession", "9781857233944");
+    other.add_reading(Reading::new(other_book_id.clone(), ReadingEvent::Started));
+    other.add_reading(Reading::new(other_book_id.clone(), ReadingEvent::Finished));
+    other.add_review(Review::new(other_book_id.clone(), "Great.".to_string()));
+    other.add_quote(Quote::new(
+        other_book_id.clone(),
+        "Outside Context Problem".to_string(),
+        Some(72),
+    ));
+
+    let summary = merge_storage(&mut into, &other);
+
+    assert_eq!(summary.books_merged, 1);
+    assert_eq!(summary.books_added, 0);
+    assert_eq!(into.books.len(), 1);
+    assert!(!into.books.contains_key(&other_book_id));
+
+    assert_eq!(into.readings.len(), 3);
+    assert!(into.readings.values().all(|r| r.book_id == book_id));
+    assert!(into.is_book_finished(&book_id));
+    assert_eq!(into.get_reviews_for_book(&book_id).len(), 1);
+    assert_eq!(summary.quotes_added, 1);
+    assert_eq!(into.get_quotes_for_book(&book_id).len(), 1);
+}
+
+#[test]
+fn test_merge_dedups_series_by_name() {
+    let (mut into, _) = create_storage("Ann Leckie", "Ancillary Justice", "111");
+    let series = Series::new("Imperial Radch".to_string());
+    let series_id = series.id.clone();
+    into.add_series(series);
+
+    let (mut other, other_book_id) = create_storage("Ann Leckie", "Ancillary Sword", "222");
+    let other_series = Series::new("imperial radch".to_string());
+    let other_series_id = other_series.id.clone();
+    other.add_series(other_series);
+    other.books.get_mut(&other_book_id).unwrap().series_id = Some(other_series_id);
+
+    merge_storage(&mut into, &other);
+
+    assert_eq!(into.series.len(), 1);
+    assert_eq!(
+        into.books.get(&other_book_id).unwrap().series_id,
+        Some(series_id)
+    );
+}
+
+#[test]
+fn test_merge_same_file_twice_adds_nothing() {
+    let (mut into, book_id) = create_storage("N. K. Jemisin", "The Fifth Season", "111");
+    into.add_reading(Reading::new(book_id, ReadingEvent::Started));
+    let copy = into.clone();
+
+    let summary = merge_storage(&mut into, &copy);
+
+    assert_eq!(summary.books_added, 0);
+    assert_eq!(summary.readings_added, 0);
+    assert_eq!(into.books.len(), 1);
+    assert_eq!(into.readings.len(), 1);
+}
+
+#[test]
+fn test_merge_keeps_renamed_author_with_the_same_id() {
+    let (mut into, book_id) = create_storage("Ann Leckie", "Ancillary Justice", "111");
+    let mut other = into.clone();
+    let author_id = into.books[&book_id].author_id.clone();
+    other.authors.get_mut(&author_id).unwrap().name = "A. Leckie".to_string();
+
+    let summary = merge_storage(&mut into, &other);
+
+    assert_eq!(summary.authors_added, 0);
+    assert_eq!(into.authors.len(), 1);
+    assert_eq!(into.authors[&author_id].name, "Ann Leckie");
+}
@@ -0,0 +1,180 @@
+use bookmon::id::SequentialIdGen;
+use bookmon::quote::{format_quotes, store_quote};
+use bookmon::storage::{Book, IntegrityIssueKind, Quote, Storage, StorageError};
+use chrono::{Duration, Utc};
+
+// --- Helper to create a storage with one book ---
+
+fn create_storage_with_book() -> (Storage, String) {
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "The Fellowship of the Ring".to_string(),
+        "9780261103573".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        423,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+    (storage, book_id)
+}
+
+// --- store_quote tests ---
+
+#[test]
+fn test_store_quote_with_valid_book() {
+    let (mut storage, book_id) = create_storage_with_book();
+
+    let quote = Quote::new(
+        book_id,
+        "Not all those who wander are lost.".to_string(),
+        None,
+    );
+    assert!(store_quote(&mut storage, quote).is_ok());
+    assert_eq!(storage.quotes.len(), 1);
+}
+
+#[test]
+fn test_store_quote_with_invalid_book_is_missing_book_error() {
+    let mut storage = Storage::new();
+    let quote = Quote::new("missing".to_string(), "Text".to_string(), Some(1));
+
+    assert_eq!(
+        store_quote(&mut storage, quote),
+        Err(StorageError::MissingBook("missing".to_string()))
+    );
+    assert!(storage.quotes.is_empty());
+}
+
+// --- Serialization ---
+
+#[test]
+fn test_quote_serialization_roundtrip() {
+    let quote = Quote::new(
+        "book-id".to_string(),
+        "Contains \"quotes\" and a newline\nlike this".to_string(),
+        Some(50),
+    );
+
+    let json = serde_json::to_string(&quote).expect("Failed to serialize");
+    let deserialized: Quote = serde_json::from_str(&json).expect("Failed to deserialize");
+
+    assert_eq!(deserialized.id, quote.id);
+    assert_eq!(deserialized.book_id, quote.book_id);
+    assert_eq!(deserialized.text, quote.text);
+    assert_eq!(deserialized.page, Some(50));
+    assert_eq!(deserialized.created_on, quote.created_on);
+}
+
+#[test]
+fn test_quote_without_page_leaves_out_the_field() {
+    let quote = Quote::new("book-id".to_string(), "Text".to_string(), None);
+    let json = serde_json::to_string(&quote).unwrap();
+    assert!(!json.contains("page"));
+
+    let deserialized: Quote = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.page, None);
+}
+
+#[test]
+fn test_storage_without_quotes_field_loads_with_empty_quotes() {
+    // Simulate old JSON without "quotes" key
+    let json = r#"{
+        "books": {},
+        "readings": {},
+        "authors": {},
+        "categories": {}
+    }"#;
+    let storage: Storage = serde_json::from_str(json).expect("Should deserialize");
+    assert!(storage.quotes.is_empty());
+}
+
+#[test]
+fn test_write_and_load_storage_with_quotes() {
+    use bookmon::storage::{load_storage, write_storage};
+
+    let (mut storage, book_id) = create_storage_with_book();
+    storage.add_quote(Quote::new(
+        book_id.clone(),
+        "Stored quote.".to_string(),
+        Some(7),
+    ));
+
+    let tmp = tempfile::NamedTempFile::new().expect("Failed to create temp file");
+    let path = tmp.path().to_str().expect("Invalid path");
+
+    write_storage(path, &storage).expect("Failed to write");
+    let loaded = load_storage(path).expect("Failed to load");
+
+    let quotes = loaded.get_quotes_for_book(&book_id);
+    assert_eq!(quotes.len(), 1);
+    assert_eq!(quotes[0].text, "Stored quote.");
+    assert_eq!(quotes[0].page, Some(7));
+}
+
+// --- Listing ---
+
+#[test]
+fn test_get_quotes_for_book_sorts_by_page_then_added() {
+    let (mut storage, book_id) = create_storage_with_book();
+    let ids = SequentialIdGen::new("quote-");
+    for (text, page, minutes_ago) in [
+        ("No page", None, 30),
+        ("Page 90", Some(90), 20),
+        ("Page 12, later", Some(12), 5),
+        ("Page 12, first", Some(12), 10),
+    ] {
+        let mut quote = Quote::new_with_id_gen(book_id.clone(), text.to_string(), page, &ids);
+        quote.created_on = Utc::now() - Duration::minutes(minutes_ago);
+        storage.add_quote(quote);
+    }
+    storage.add_quote(Quote::new(
+        "other-book".to_string(),
+        "Other".to_string(),
+        Some(1),
+    ));
+
+    let texts: Vec<&str> = storage
+        .get_quotes_for_book(&book_id)
+        .iter()
+        .map(|q| q.text.as_str())
+        .collect();
+    assert_eq!(
+        texts,
+        vec!["Page 12, first", "Page 12, later", "Page 90", "No page"]
+    );
+}
+
+#[test]
+fn test_format_quotes_shows_pages_when_known() {
+    let (mut storage, book_id) = create_storage_with_book();
+    assert!(format_quotes(&storage, &book_id).is_empty());
+
+    storage.add_quote(Quote::new(book_id.clone(), "Unpaged".to_string(), None));
+    storage.add_quote(Quote::new(
+        book_id.clone(),
+        "All we have to decide is what to do with the time that is given us.".to_string(),
+        Some(50),
+    ));
+
+    assert_eq!(
+        format_quotes(&storage, &book_id),
+        vec![
+            "p. 50: \"All we have to decide is what to do with the time that is given us.\"",
+            "\"Unpaged\"",
+        ]
+    );
+}
+
+#[test]
+fn test_quote_of_missing_book_is_an_integrity_issue() {
+    let mut storage = Storage::new();
+    let quote = Quote::new("missing".to_string(), "Orphan".to_string(), None);
+    let quote_id = quote.id.clone();
+    storage.add_quote(quote);
+
+    let issues = storage.integrity_issues();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, IntegrityIssueKind::DanglingQuote);
+    assert_eq!(issues[0].entity_id, quote_id);
+}
@@ -1,7 +1,8 @@
 use bookmon::reading::{group_books_by_series, show_started_books, store_reading, BookEntry};
-use bookmon::storage::{Author, Book, Category, Reading, ReadingEvent, Series, Storage};
+use bookmon::storage::{
+    Author, Book, Category, Reading, ReadingEvent, Series, Storage, StorageError,
+};
 use chrono::{DateTime, Utc};
-use serde_json;
 
 #[test]
 fn test_store_reading_with_valid_book() {
@@ -51,10 +52,22 @@ fn test_store_reading_with_invalid_book() {
     assert!(result.is_err());
     assert!(result
         .unwrap_err()
+        .to_string()
         .contains("Book with ID invalid-book-id does not exist"));
     assert_eq!(storage.readings.len(), 0);
 }
 
+#[test]
+fn test_store_reading_with_invalid_book_is_missing_book_error() {
+    let mut storage = Storage::new();
+    let reading = Reading::new("invalid-book-id".to_string(), ReadingEvent::Started);
+
+    assert_eq!(
+        store_reading(&mut storage, reading),
+        Err(StorageError::MissingBook("invalid-book-id".to_string()))
+    );
+}
+
 #[test]
 fn test_reading_id_matches_storage_key() {
     let mut storage = Storage::new();
@@ -316,9 +329,6 @@ fn test_show_started_books_table_format() {
     // Test showing started books
     let result = show_started_books(&storage);
     assert!(result.is_ok());
-
-    // Verify table formatting
-    assert!(true, "Table formatting looks good!");
 }
 
 #[test]
@@ -714,3 +724,1173 @@ fn test_group_books_by_series_book_without_position_sorts_last_in_group() {
         _ => panic!("Should be a SeriesGroup"),
     }
 }
+
+#[test]
+fn test_update_progress_by_isbn_stores_update_reading() {
+    use bookmon::reading::update_progress_by_isbn;
+
+    let mut storage = Storage::new();
+
+    let author = Author::new("Test Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    let book = Book::new(
+        "Test Book".to_string(),
+        "9780142410349".to_string(),
+        category_id,
+        author_id,
+        300,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    // Hyphenated ISBN should match the stored one
+    let result = update_progress_by_isbn(&mut storage, "978-0-14-241034-9", 120);
+    assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
+
+    assert_eq!(storage.readings.len(), 1);
+    let reading = storage.readings.values().next().unwrap();
+    assert_eq!(reading.book_id, book_id);
+    assert_eq!(reading.event, ReadingEvent::Update);
+    assert_eq!(reading.metadata.current_page, Some(120));
+}
+
+#[test]
+fn test_update_progress_by_isbn_rejects_page_beyond_total() {
+    use bookmon::reading::update_progress_by_isbn;
+
+    let mut storage = Storage::new();
+
+    let author = Author::new("Test Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    let book = Book::new(
+        "Test Book".to_string(),
+        "9780142410349".to_string(),
+        category_id,
+        author_id,
+        300,
+    );
+    storage.add_book(book);
+
+    let result = update_progress_by_isbn(&mut storage, "9780142410349", 301);
+    assert!(result.is_err());
+    assert!(
+        result.unwrap_err().contains("exceeds total pages (300)"),
+        "Error should mention the total page count"
+    );
+    assert!(storage.readings.is_empty(), "No reading should be stored");
+
+    // The last page itself is fine
+    assert!(update_progress_by_isbn(&mut storage, "9780142410349", 300).is_ok());
+}
+
+#[test]
+fn test_update_progress_by_isbn_unknown_isbn() {
+    use bookmon::reading::update_progress_by_isbn;
+
+    let mut storage = Storage::new();
+    let result = update_progress_by_isbn(&mut storage, "0000000000", 10);
+    assert!(result.is_err());
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("No book found with ISBN 0000000000"));
+}
+
+#[test]
+fn test_book_list_table_shows_series_progress_column() {
+    use bookmon::reading::build_book_list_table;
+    use bookmon::table::TableRow;
+
+    let mut storage = Storage::new();
+
+    let author = Author::new("Robert Jordan".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let category = Category::new("Fantasy".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    let mut series = Series::new("The Wheel of Time".to_string());
+    series.total_books = Some(14);
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    let mut finished_book = Book::new(
+        "The Eye of the World".to_string(),
+        "111".to_string(),
+        category_id.clone(),
+        author_id.clone(),
+        800,
+    );
+    finished_book.series_id = Some(series_id.clone());
+    finished_book.position_in_series = Some("1".to_string());
+    let finished_id = finished_book.id.clone();
+    storage.add_book(finished_book);
+    storage.add_reading(Reading::new(finished_id.clone(), ReadingEvent::Started));
+    let mut finished = Reading::new(finished_id, ReadingEvent::Finished);
+    finished.created_on = Utc::now() + chrono::Duration::seconds(1);
+    storage.add_reading(finished);
+
+    let mut next_book = Book::new(
+        "The Great Hunt".to_string(),
+        "222".to_string(),
+        category_id.clone(),
+        author_id.clone(),
+        700,
+    );
+    next_book.series_id = Some(series_id.clone());
+    next_book.position_in_series = Some("2".to_string());
+    let next_id = next_book.id.clone();
+    storage.add_book(next_book);
+
+    let standalone = Book::new(
+        "Warriors of the Altaii".to_string(),
+        "333".to_string(),
+        category_id,
+        author_id,
+        300,
+    );
+    let standalone_id = standalone.id.clone();
+    storage.add_book(standalone);
+
+    assert_eq!(storage.series_progress(&series_id), (1, Some(14)));
+
+    let books = vec![
+        storage.get_book(&next_id).unwrap(),
+        storage.get_book(&standalone_id).unwrap(),
+    ];
+    let table = build_book_list_table(&storage, books, false, "%Y-%m-%d").unwrap();
+
+    match &table[0] {
+        TableRow::Header(h) => assert_eq!(h.last().unwrap(), "Series progress"),
+        other => panic!("Expected header row, got {:?}", other),
+    }
+
+    let data_rows: Vec<&Vec<String>> = table
+        .iter()
+        .filter_map(|r| match r {
+            TableRow::Data(cells) => Some(cells),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(data_rows.len(), 2);
+
+    let series_row = data_rows
+        .iter()
+        .find(|r| r[0].contains("The Great Hunt"))
+        .unwrap();
+    assert_eq!(series_row.last().unwrap(), "1/14 read");
+
+    let standalone_row = data_rows
+        .iter()
+        .find(|r| r[0] == "Warriors of the Altaii")
+        .unwrap();
+    assert_eq!(standalone_row.last().unwrap(), "");
+}
+
+#[test]
+fn test_book_list_table_omits_series_progress_without_series() {
+    use bookmon::reading::build_book_list_table;
+    use bookmon::table::TableRow;
+
+    let mut storage = Storage::new();
+
+    let author = Author::new("Test Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    let book = Book::new(
+        "Standalone".to_string(),
+        "111".to_string(),
+        category_id,
+        author_id,
+        200,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    let table = build_book_list_table(
+        &storage,
+        vec![storage.get_book(&book_id).unwrap()],
+        false,
+        "%Y-%m-%d",
+    )
+    .unwrap();
+    match &table[0] {
+        TableRow::Header(h) => {
+            assert_eq!(h.len(), 6);
+            assert!(!h.contains(&"Series progress".to_string()));
+        }
+        other => panic!("Expected header row, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_format_reading_event_line() {
+    use bookmon::reading::format_reading_event_line;
+    use chrono::TimeZone;
+
+    let mut storage = Storage::new();
+
+    let author = Author::new("Jane Austen".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let book = Book::new(
+        "Emma".to_string(),
+        "111".to_string(),
+        "cat".to_string(),
+        author_id,
+        400,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    let mut update = Reading::with_metadata(book_id, ReadingEvent::Update, 120);
+    update.created_on = Utc.with_ymd_and_hms(2025, 4, 2, 8, 0, 0).unwrap();
+    assert_eq!(
+        format_reading_event_line(&storage, &update, "%Y-%m-%d"),
+        "2025-04-02 \u{2014} Progress update (page 120) \u{2014} Emma by Jane Austen"
+    );
+    assert_eq!(
+        format_reading_event_line(&storage, &update, "%d.%m.%Y"),
+        "02.04.2025 \u{2014} Progress update (page 120) \u{2014} Emma by Jane Austen"
+    );
+
+    // Missing book resolves gracefully
+    let mut orphan = Reading::new("deleted-book".to_string(), ReadingEvent::Started);
+    orphan.created_on = Utc.with_ymd_and_hms(2025, 4, 3, 8, 0, 0).unwrap();
+    assert_eq!(
+        format_reading_event_line(&storage, &orphan, "%Y-%m-%d"),
+        "2025-04-03 \u{2014} Started \u{2014} Unknown Book by Unknown Author"
+    );
+}
+
+#[test]
+fn test_filter_readings_by_event_within_window() {
+    use bookmon::reading::filter_readings;
+    use chrono::TimeZone;
+
+    let mut storage = Storage::new();
+    for (month, day, event) in [
+        (5, 20, ReadingEvent::Started),
+        (6, 1, ReadingEvent::Started),
+        (6, 15, ReadingEvent::Finished),
+        (6, 20, ReadingEvent::Started),
+        (7, 1, ReadingEvent::Started),
+    ] {
+        let mut reading = Reading::new("book".to_string(), event);
+        reading.created_on = Utc.with_ymd_and_hms(2024, month, day, 0, 0, 0).unwrap();
+        storage.add_reading(reading);
+    }
+
+    let readings = filter_readings(
+        &storage,
+        Some("2024-06-01"),
+        Some("2024-07-01"),
+        Some("started"),
+        0,
+        10,
+    )
+    .unwrap();
+    let dates: Vec<String> = readings
+        .iter()
+        .map(|r| r.created_on.format("%Y-%m-%d").to_string())
+        .collect();
+    // Newest first; the lower bound is inclusive, the upper bound exclusive
+    assert_eq!(dates, vec!["2024-06-20", "2024-06-01"]);
+
+    // Only a lower bound
+    let readings = filter_readings(&storage, Some("2024-06-01"), None, None, 0, 10).unwrap();
+    assert_eq!(readings.len(), 4);
+
+    // The limit still applies
+    let readings = filter_readings(&storage, None, None, Some("Started"), 0, 1).unwrap();
+    assert_eq!(readings.len(), 1);
+    assert_eq!(readings[0].created_on.format("%m-%d").to_string(), "07-01");
+}
+
+#[test]
+fn test_filter_readings_rejects_invalid_input() {
+    use bookmon::reading::filter_readings;
+
+    let storage = Storage::new();
+
+    let err = filter_readings(&storage, Some("01/06/2024"), None, None, 0, 10).unwrap_err();
+    assert_eq!(err, "Invalid date '01/06/2024': expected YYYY-MM-DD");
+
+    let err = filter_readings(&storage, None, None, Some("reading"), 0, 10).unwrap_err();
+    assert!(err.starts_with("Unknown event type 'reading'"));
+
+    let err = filter_readings(
+        &storage,
+        Some("2024-07-01"),
+        Some("2024-06-01"),
+        None,
+        0,
+        10,
+    )
+    .unwrap_err();
+    assert_eq!(err, "--after must be earlier than --before");
+}
+
+#[test]
+fn test_record_reading_event_mutates_storage_in_place() {
+    use bookmon::reading::record_reading_event;
+
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Dune".to_string(),
+        "111".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        600,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    record_reading_event(&mut storage, &book_id, ReadingEvent::Started, None).unwrap();
+    record_reading_event(&mut storage, &book_id, ReadingEvent::Update, Some(42)).unwrap();
+
+    assert_eq!(storage.readings.len(), 2);
+    assert!(storage.is_book_started(&book_id));
+    let update = storage
+        .get_readings_by_event(ReadingEvent::Update)
+        .into_iter()
+        .next()
+        .unwrap();
+    assert_eq!(update.metadata.current_page, Some(42));
+
+    let err =
+        record_reading_event(&mut storage, "missing", ReadingEvent::Started, None).unwrap_err();
+    assert_eq!(err, "Book with ID missing does not exist");
+    assert_eq!(storage.readings.len(), 2);
+}
+
+#[test]
+fn test_paginate_finished_books() {
+    use bookmon::reading::{paginate, sort_finished_books};
+
+    let mut storage = Storage::new();
+    let author = Author::new("Agatha Christie".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    for title in ["E", "C", "A", "D", "B"] {
+        let book = Book::new(
+            title.to_string(),
+            "isbn".to_string(),
+            "cat".to_string(),
+            author_id.clone(),
+            200,
+        );
+        storage.add_reading(Reading::new(book.id.clone(), ReadingEvent::Finished));
+        storage.add_book(book);
+    }
+
+    let sorted = sort_finished_books(&storage, storage.get_finished_books());
+
+    // A normal page
+    let page = paginate(sorted.clone(), 1, Some(2));
+    let titles: Vec<&str> = page.iter().map(|b| b.title.as_str()).collect();
+    assert_eq!(titles, vec!["B", "C"]);
+
+    // Last, partial page
+    assert_eq!(paginate(sorted.clone(), 4, Some(2)).len(), 1);
+
+    // No limit shows everything after the offset
+    assert_eq!(paginate(sorted.clone(), 2, None).len(), 3);
+
+    // Offset past the end
+    assert!(paginate(sorted, 5, Some(2)).is_empty());
+}
+
+#[test]
+fn test_book_list_table_shows_subtitles_only_with_full_titles() {
+    use bookmon::reading::build_book_list_table;
+    use bookmon::table::TableRow;
+
+    let mut storage = Storage::new();
+
+    let author = Author::new("Yuval Noah Harari".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let category = Category::new("History".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    let mut book = Book::new(
+        "Sapiens".to_string(),
+        "111".to_string(),
+        category_id,
+        author_id,
+        498,
+    );
+    book.subtitle = Some("A Brief History of Humankind".to_string());
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    let title_cell = |full_titles: bool| -> String {
+        let books = vec![storage.get_book(&book_id).unwrap()];
+        let table = build_book_list_table(&storage, books, full_titles, "%Y-%m-%d").unwrap();
+        match &table[1] {
+            TableRow::Data(cells) => cells[0].clone(),
+            other => panic!("Expected data row, got {:?}", other),
+        }
+    };
+
+    assert_eq!(title_cell(false), "Sapiens");
+    assert_eq!(title_cell(true), "Sapiens: A Brief History of Humankind");
+}
+
+#[test]
+fn test_started_books_table_shows_subtitles_only_with_full_titles() {
+    use bookmon::clock::SystemClock;
+    use bookmon::reading::build_started_books_table_with_clock;
+    use bookmon::table::TableRow;
+
+    let mut storage = Storage::new();
+    let author = Author::new("Yuval Noah Harari".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let mut book = Book::new(
+        "Sapiens".to_string(),
+        "111".to_string(),
+        "cat".to_string(),
+        author_id,
+        498,
+    );
+    book.subtitle = Some("A Brief History of Humankind".to_string());
+    storage.add_reading(Reading::new(book.id.clone(), ReadingEvent::Started));
+    storage.add_book(book);
+
+    let title_cell = |full_titles: bool| -> String {
+        let table =
+            build_started_books_table_with_clock(&storage, &SystemClock, "%Y-%m-%d", full_titles)
+                .unwrap();
+        match &table[1] {
+            TableRow::Data(cells) => cells[0].clone(),
+            other => panic!("Expected data row, got {:?}", other),
+        }
+    };
+
+    assert_eq!(title_cell(false), "Sapiens");
+    assert_eq!(title_cell(true), "Sapiens: A Brief History of Humankind");
+}
+
+#[test]
+fn test_update_progress_by_ref_accepts_title() {
+    use bookmon::reading::update_progress_by_ref;
+
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Fantastic Mr Fox".to_string(),
+        "9780142410349".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        96,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    update_progress_by_ref(&mut storage, "fantastic mr fox", 40).unwrap();
+    let reading = storage.readings.values().next().unwrap();
+    assert_eq!(reading.book_id, book_id);
+    assert_eq!(reading.metadata.current_page, Some(40));
+
+    assert!(update_progress_by_ref(&mut storage, "Unknown", 1).is_err());
+}
+
+#[test]
+fn test_sort_finished_books_by_finish_date() {
+    use bookmon::reading::{sort_finished_books, sort_finished_books_by, FinishedOrder};
+    use chrono::TimeZone;
+
+    let mut storage = Storage::new();
+    let author = Author::new("Agatha Christie".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let series = Series::new("Poirot".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    // (title, finish day in March 2025); "A" was re-read, so its last finish counts
+    let finishes = [
+        ("C", vec![1]),
+        ("A", vec![2, 20]),
+        ("B", vec![10]),
+        ("D", vec![10]),
+    ];
+    for (title, days) in finishes {
+        let mut book = Book::new(
+            title.to_string(),
+            "isbn".to_string(),
+            "cat".to_string(),
+            author_id.clone(),
+            200,
+        );
+        if title == "D" {
+            book.series_id = Some(series_id.clone());
+        }
+        for day in days {
+            let mut reading = Reading::new(book.id.clone(), ReadingEvent::Finished);
+            reading.created_on = Utc.with_ymd_and_hms(2025, 3, day, 12, 0, 0).unwrap();
+            storage.add_reading(reading);
+        }
+        storage.add_book(book);
+    }
+
+    let titles =
+        |books: Vec<&Book>| -> Vec<String> { books.iter().map(|b| b.title.clone()).collect() };
+
+    assert_eq!(
+        titles(sort_finished_books_by(
+            &storage,
+            storage.get_finished_books(),
+            FinishedOrder::OldestFirst
+        )),
+        vec!["C", "B", "D", "A"]
+    );
+    assert_eq!(
+        titles(sort_finished_books_by(
+            &storage,
+            storage.get_finished_books(),
+            FinishedOrder::NewestFirst
+        )),
+        vec!["A", "B", "D", "C"]
+    );
+    // The default still groups series and sorts by author/title
+    assert_eq!(
+        titles(sort_finished_books_by(
+            &storage,
+            storage.get_finished_books(),
+            FinishedOrder::AuthorTitle
+        )),
+        titles(sort_finished_books(&storage, storage.get_finished_books()))
+    );
+}
+
+#[test]
+fn test_started_books_table_days_since_started_with_fixed_clock() {
+    use bookmon::clock::FixedClock;
+    use bookmon::reading::build_started_books_table_with_clock;
+    use bookmon::table::TableRow;
+    use chrono::TimeZone;
+
+    let mut storage = Storage::new();
+    let author = Author::new("Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let book = Book::new(
+        "Middlemarch".to_string(),
+        "123".to_string(),
+        "cat".to_string(),
+        author_id,
+        800,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    let mut started = Reading::new(book_id.clone(), ReadingEvent::Started);
+    started.created_on = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+    storage.add_reading(started);
+    let mut update = Reading::with_metadata(book_id, ReadingEvent::Update, 200);
+    update.created_on = Utc.with_ymd_and_hms(2025, 3, 11, 9, 0, 0).unwrap();
+    storage.add_reading(update);
+
+    let clock = FixedClock(Utc.with_ymd_and_hms(2025, 3, 15, 8, 0, 0).unwrap());
+    let table = build_started_books_table_with_clock(&storage, &clock, "%Y-%m-%d", false).unwrap();
+
+    let row = table
+        .iter()
+        .find_map(|row| match row {
+            TableRow::Data(cells) => Some(cells.clone()),
+            _ => None,
+        })
+        .unwrap();
+    // 13 full days between Mar 1 09:00 and Mar 15 08:00
+    assert_eq!(row[2], "13");
+    assert_eq!(row[3], "25.0%");
+    // 20 pages a day from Mar 1, 600 pages left: 30 more days from the clock's "now"
+    assert_eq!(row[4], "2025-04-14");
+}
+
+#[test]
+fn test_started_books_table_counts_days_from_first_page_update() {
+    use bookmon::clock::FixedClock;
+    use bookmon::reading::build_started_books_table_with_clock;
+    use bookmon::table::TableRow;
+    use chrono::TimeZone;
+
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Middlemarch".to_string(),
+        "123".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        800,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    // Never explicitly started, only progress logged
+    for (day, page) in [(5, 80), (10, 200)] {
+        let mut update = Reading::with_metadata(book_id.clone(), ReadingEvent::Update, page);
+        update.created_on = Utc.with_ymd_and_hms(2025, 3, day, 9, 0, 0).unwrap();
+        storage.add_reading(update);
+    }
+
+    let clock = FixedClock(Utc.with_ymd_and_hms(2025, 3, 15, 9, 0, 0).unwrap());
+    let table = build_started_books_table_with_clock(&storage, &clock, "%Y-%m-%d", false).unwrap();
+
+    let row = table
+        .iter()
+        .find_map(|row| match row {
+            TableRow::Data(cells) => Some(cells.clone()),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(row[2], "10");
+    assert_eq!(row[3], "25.0%");
+}
+
+#[test]
+fn test_finished_by_month_table_groups_by_month() {
+    use bookmon::reading::{build_finished_by_month_table, FinishedOrder};
+    use bookmon::table::TableRow;
+    use bookmon::time::Timezone;
+    use chrono::TimeZone;
+
+    let mut storage = Storage::new();
+    let author = Author::new("Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let finishes = [
+        ("May Book", (2025, 5, 2)),
+        ("Late April", (2025, 4, 28)),
+        ("Early April", (2025, 4, 3)),
+    ];
+    for (title, (year, month, day)) in finishes {
+        let book = Book::new(
+            title.to_string(),
+            "isbn".to_string(),
+            "cat".to_string(),
+            author_id.clone(),
+            200,
+        );
+        let mut reading = Reading::new(book.id.clone(), ReadingEvent::Finished);
+        reading.created_on = Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap();
+        storage.add_reading(reading);
+        storage.add_book(book);
+    }
+
+    let describe = |rows: Vec<TableRow>| -> Vec<String> {
+        rows.iter()
+            .map(|row| match row {
+                TableRow::Header(_) => "header".to_string(),
+                TableRow::GroupHeader(name, count) => format!("{} ({})", name, count),
+                TableRow::Data(cells) => format!("{} {}", cells[2], cells[0]),
+            })
+            .collect()
+    };
+
+    let table = build_finished_by_month_table(
+        &storage,
+        storage.get_finished_books(),
+        false,
+        FinishedOrder::AuthorTitle,
+        &Timezone::Utc,
+        "%Y-%m-%d",
+    );
+    assert_eq!(
+        describe(table),
+        vec![
+            "header",
+            "2025-04 (2)",
+            "2025-04-03 Early April",
+            "2025-04-28 Late April",
+            "2025-05 (1)",
+            "2025-05-02 May Book",
+        ]
+    );
+
+    let table = build_finished_by_month_table(
+        &storage,
+        storage.get_finished_books(),
+        false,
+        FinishedOrder::NewestFirst,
+        &Timezone::Utc,
+        "%Y-%m-%d",
+    );
+    assert_eq!(
+        describe(table),
+        vec![
+            "header",
+            "2025-05 (1)",
+            "2025-05-02 May Book",
+            "2025-04 (2)",
+            "2025-04-28 Late April",
+            "2025-04-03 Early April",
+        ]
+    );
+
+    assert!(build_finished_by_month_table(
+        &storage,
+        vec![],
+        false,
+        FinishedOrder::OldestFirst,
+        &Timezone::Utc,
+        "%Y-%m-%d"
+    )
+    .is_empty());
+}
+
+#[test]
+fn test_all_books_table_marks_status_with_symbols() {
+    use bookmon::config::StatusSymbols;
+    use bookmon::reading::build_all_books_table;
+    use bookmon::table::TableRow;
+
+    let mut storage = Storage::new();
+    let author = Author::new("Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    for (title, event) in [
+        ("Finished Book", Some(ReadingEvent::Finished)),
+        ("Reading Book", Some(ReadingEvent::Started)),
+        ("Unread Book", None),
+    ] {
+        let book = Book::new(
+            title.to_string(),
+            "isbn".to_string(),
+            category_id.clone(),
+            author_id.clone(),
+            200,
+        );
+        if let Some(event) = event {
+            storage.add_reading(Reading::new(book.id.clone(), event));
+        }
+        storage.add_book(book);
+    }
+
+    let books: Vec<&Book> = storage.books.values().collect();
+    let table =
+        build_all_books_table(&storage, books, false, "%Y-%m-%d", &StatusSymbols::ascii()).unwrap();
+
+    match &table[0] {
+        TableRow::Header(cells) => assert_eq!(cells[0], "Status"),
+        _ => panic!("First row should be a Header"),
+    }
+    let rows: Vec<(String, String)> = table
+        .iter()
+        .filter_map(|row| match row {
+            TableRow::Data(cells) => Some((cells[0].clone(), cells[1].clone())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            ("x".to_string(), "Finished Book".to_string()),
+            (">".to_string(), "Reading Book".to_string()),
+            (" ".to_string(), "Unread Book".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_format_library_counts_for_mixed_library() {
+    use bookmon::reading::format_library_counts;
+
+    let mut storage = Storage::new();
+    let events = [
+        (
+            "Finished",
+            vec![ReadingEvent::Started, ReadingEvent::Finished],
+        ),
+        ("Finished Again", vec![ReadingEvent::Finished]),
+        ("Reading", vec![ReadingEvent::Started]),
+        ("Bought", vec![ReadingEvent::Bought]),
+        ("Untouched", vec![]),
+    ];
+    for (title, events) in events {
+        let book = Book::new(
+            title.to_string(),
+            "isbn".to_string(),
+            "category".to_string(),
+            "author".to_string(),
+            200,
+        );
+        for (i, event) in events.into_iter().enumerate() {
+            let mut reading = Reading::new(book.id.clone(), event);
+            reading.created_on = Utc::now() - chrono::Duration::days(10 - i as i64);
+            storage.add_reading(reading);
+        }
+        storage.add_book(book);
+    }
+
+    let books: Vec<&Book> = storage.books.values().collect();
+    assert_eq!(
+        format_library_counts(&storage, &books),
+        "5 books: 2 finished, 1 reading, 2 not started."
+    );
+    assert_eq!(
+        format_library_counts(&storage, &[]),
+        "0 books: 0 finished, 0 reading, 0 not started."
+    );
+}
+
+#[test]
+fn test_reversed_book_list_table_inverts_author_title_order() {
+    use bookmon::reading::build_book_list_table;
+    use bookmon::table::{reverse_table_rows, TableRow};
+
+    let mut storage = Storage::new();
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+    for (author_name, title) in [("Zadie", "Alpha"), ("Anne", "Beta"), ("Anne", "Alpha")] {
+        let author = Author::new(author_name.to_string());
+        let author_id = author.id.clone();
+        storage.add_author(author);
+        storage.add_book(Book::new(
+            title.to_string(),
+            "isbn".to_string(),
+            category_id.clone(),
+            author_id,
+            200,
+        ));
+    }
+
+    let titles = |rows: &[TableRow]| -> Vec<(String, String)> {
+        rows.iter()
+            .filter_map(|row| match row {
+                TableRow::Data(cells) => Some((cells[1].clone(), cells[0].clone())),
+                _ => None,
+            })
+            .collect()
+    };
+    let books: Vec<&Book> = storage.books.values().collect();
+    let table = build_book_list_table(&storage, books, false, "%Y-%m-%d").unwrap();
+    let mut forward = titles(&table);
+    let reversed = titles(&reverse_table_rows(table));
+
+    assert_eq!(forward[0].0, "Anne");
+    assert_eq!(reversed[0].0, "Zadie");
+    forward.reverse();
+    assert_eq!(reversed, forward);
+}
+
+#[test]
+fn test_parse_price_input() {
+    use bookmon::reading::parse_price_input;
+
+    assert_eq!(parse_price_input("  "), Ok(None));
+    assert_eq!(parse_price_input("249"), Ok(Some((249.0, None))));
+    assert_eq!(
+        parse_price_input("99,90 nok"),
+        Ok(Some((99.9, Some("NOK".to_string()))))
+    );
+    assert_eq!(
+        parse_price_input("12.50 USD"),
+        Ok(Some((12.5, Some("USD".to_string()))))
+    );
+    assert!(parse_price_input("free").is_err());
+    assert!(parse_price_input("-5").is_err());
+    assert!(parse_price_input("5 USD extra").is_err());
+}
+
+#[test]
+fn test_format_spending_lists_each_currency() {
+    use bookmon::reading::format_spending;
+    use std::collections::BTreeMap;
+
+    let spending: BTreeMap<String, f64> = [
+        ("NOK".to_string(), 249.0),
+        ("USD".to_string(), 12.5),
+        ("".to_string(), 10.0),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(format_spending(&spending), "10.00, 249.00 NOK, 12.50 USD");
+}
+
+#[test]
+fn test_stale_reading_warnings_flag_only_untouched_books() {
+    use bookmon::clock::FixedClock;
+    use bookmon::reading::stale_reading_warnings;
+    use chrono::TimeZone;
+
+    let mut storage = Storage::new();
+    let now = Utc.with_ymd_and_hms(2025, 3, 15, 8, 0, 0).unwrap();
+    for (title, days_ago) in [("Stale Book", 30), ("Fresh Book", 3)] {
+        let book = Book::new(
+            title.to_string(),
+            "isbn".to_string(),
+            "category".to_string(),
+            "author".to_string(),
+            300,
+        );
+        let mut started = Reading::new(book.id.clone(), ReadingEvent::Started);
+        started.created_on = now - chrono::Duration::days(days_ago);
+        storage.add_reading(started);
+        storage.add_book(book);
+    }
+
+    let warnings = stale_reading_warnings(&storage, 21, &FixedClock(now));
+    assert_eq!(
+        warnings,
+        vec!["You haven't touched \"Stale Book\" in 30 days. Still reading it?".to_string()]
+    );
+    assert!(stale_reading_warnings(&storage, 0, &FixedClock(now)).is_empty());
+}
+
+#[test]
+fn test_reload_and_render_picks_up_changes_to_the_storage_file() {
+    use bookmon::clock::FixedClock;
+    use bookmon::reading::reload_and_render;
+    use bookmon::storage::write_storage;
+    use bookmon::time::Timezone;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("storage.json");
+    let path = path.to_str().unwrap();
+    let clock = FixedClock(Utc::now());
+
+    let mut storage = Storage::new();
+    write_storage(path, &storage).unwrap();
+    let view =
+        reload_and_render(path, &Timezone::Utc, &clock, "%Y-%m-%d", false, 21, true).unwrap();
+    assert!(view.contains("No books currently being read."));
+
+    // Another process starts a book; the next render shows it
+    let book = Book::new(
+        "Piranesi".to_string(),
+        "isbn".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        272,
+    );
+    storage.add_reading(Reading::new(book.id.clone(), ReadingEvent::Started));
+    storage.add_book(book);
+    write_storage(path, &storage).unwrap();
+    let view =
+        reload_and_render(path, &Timezone::Utc, &clock, "%Y-%m-%d", false, 21, true).unwrap();
+    assert!(view.contains("Piranesi"));
+    assert!(!view.contains("No books currently being read."));
+
+    assert!(reload_and_render(
+        "/nonexistent/storage.json",
+        &Timezone::Utc,
+        &clock,
+        "%Y-%m-%d",
+        false,
+        21,
+        true
+    )
+    .is_err());
+}
+
+#[test]
+fn test_show_finished_books_list_writes_table_to_writer() {
+    use bookmon::reading::{show_finished_books_list, FinishedOrder};
+    use bookmon::table::{TableOutput, TableStyle};
+
+    let mut storage = Storage::new();
+    let author = Author::new("Ursula K. Le Guin".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    let book = Book::new(
+        "The Dispossessed".to_string(),
+        "9780061054884".to_string(),
+        "category".to_string(),
+        author_id,
+        387,
+    );
+    let mut finished = Reading::new(book.id.clone(), ReadingEvent::Finished);
+    finished.created_on = DateTime::parse_from_rfc3339("2025-04-02T10:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    storage.add_reading(finished);
+    storage.add_book(book);
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut out = TableOutput::new(&mut buffer, TableStyle::Plain);
+    show_finished_books_list(
+        &mut out,
+        &storage,
+        storage.get_finished_books(),
+        "No finished books found.",
+        false,
+        FinishedOrder::AuthorTitle,
+        false,
+    )
+    .unwrap();
+
+    let output = String::from_utf8(buffer).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("Title"));
+    assert!(lines[0].contains("Finished on"));
+    assert!(lines[1].contains("The Dispossessed"));
+    assert!(lines[1].contains("Ursula K. Le Guin"));
+    assert!(lines[1].contains("2025-04-02"));
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut out = TableOutput::new(&mut buffer, TableStyle::Plain).with_date_format("%d/%m/%Y");
+    show_finished_books_list(
+        &mut out,
+        &storage,
+        storage.get_finished_books(),
+        "No finished books found.",
+        false,
+        FinishedOrder::AuthorTitle,
+        false,
+    )
+    .unwrap();
+    assert!(String::from_utf8(buffer).unwrap().contains("02/04/2025"));
+}
+
+#[test]
+fn test_show_finished_books_list_writes_empty_message_to_writer() {
+    use bookmon::reading::{show_finished_books_list, FinishedOrder};
+    use bookmon::table::{TableOutput, TableStyle};
+
+    let storage = Storage::new();
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut out = TableOutput::new(&mut buffer, TableStyle::Boxed);
+    show_finished_books_list(
+        &mut out,
+        &storage,
+        Vec::new(),
+        "No finished books found.",
+        false,
+        FinishedOrder::AuthorTitle,
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "No finished books found.\n"
+    );
+}
+
+/// Asserts every line of a boxed table has the same display width.
+fn assert_lines_aligned(output: &str) {
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines.len() > 2, "expected a table, got {:?}", output);
+    let expected = unicode_width::UnicodeWidthStr::width(lines[0]);
+    for (i, line) in lines.iter().enumerate() {
+        assert_eq!(
+            unicode_width::UnicodeWidthStr::width(*line),
+            expected,
+            "Line {} is misaligned: {:?}",
+            i,
+            line
+        );
+    }
+}
+
+/// A library with a started and a finished book by authors with Norwegian names.
+fn storage_with_norwegian_authors() -> Storage {
+    let mut storage = Storage::new();
+    let category = Category::new("Krim".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    for (author, title, event) in [
+        ("Jørn Lier Horst", "Vinterstengt", ReadingEvent::Started),
+        ("Åsne Seierstad", "Én av oss", ReadingEvent::Finished),
+    ] {
+        let author = Author::new(author.to_string());
+        let book = Book::new(
+            title.to_string(),
+            "isbn".to_string(),
+            category_id.clone(),
+            author.id.clone(),
+            300,
+        );
+        storage.add_reading(Reading::new(book.id.clone(), event));
+        storage.add_author(author);
+        storage.add_book(book);
+    }
+    storage
+}
+
+#[test]
+fn test_started_view_aligns_multibyte_author_names() {
+    use bookmon::clock::SystemClock;
+    use bookmon::reading::format_started_books_with_clock;
+
+    let storage = storage_with_norwegian_authors();
+    let output =
+        format_started_books_with_clock(&storage, &SystemClock, "%Y-%m-%d", false, None).unwrap();
+    assert!(output.contains("Jørn Lier Horst"));
+    assert_lines_aligned(&output);
+}
+
+#[test]
+fn test_finished_and_all_books_views_align_multibyte_author_names() {
+    use bookmon::config::StatusSymbols;
+    use bookmon::reading::{print_all_books_table, show_finished_books_list, FinishedOrder};
+    use bookmon::table::{TableOutput, TableStyle};
+
+    let storage = storage_with_norwegian_authors();
+
+    let mut finished: Vec<u8> = Vec::new();
+    show_finished_books_list(
+        &mut TableOutput::new(&mut finished, TableStyle::Boxed),
+        &storage,
+        storage.get_finished_books(),
+        "",
+        false,
+        FinishedOrder::AuthorTitle,
+        false,
+    )
+    .unwrap();
+    let finished = String::from_utf8(finished).unwrap();
+    assert!(finished.contains("Åsne Seierstad"));
+    assert_lines_aligned(&finished);
+
+    let mut all: Vec<u8> = Vec::new();
+    print_all_books_table(
+        &mut TableOutput::new(&mut all, TableStyle::Boxed),
+        &storage,
+        storage.books.values().collect(),
+        "",
+        false,
+        &StatusSymbols::default(),
+        false,
+    )
+    .unwrap();
+    let all = String::from_utf8(all).unwrap();
+    assert!(all.contains("Jørn Lier Horst"));
+    assert_lines_aligned(&all);
+}
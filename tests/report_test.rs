@@ -0,0 +1,122 @@
+use bookmon::report::{markdown_escape, reading_journal_markdown, year_report_markdown};
+use bookmon::storage::{Author, Book, Reading, ReadingEvent, Review, Storage};
+use bookmon::time::Timezone;
+use chrono::{TimeZone, Utc};
+
+fn add_finished_book(storage: &mut Storage, title: &str, author: &str, month: u32) -> String {
+    let author = Author::new(author.to_string());
+    let book = Book::new(
+        title.to_string(),
+        format!("isbn-{}", title),
+        "category".to_string(),
+        author.id.clone(),
+        250,
+    );
+    let mut reading = Reading::new(book.id.clone(), ReadingEvent::Finished);
+    reading.created_on = Utc.with_ymd_and_hms(2025, month, 10, 12, 0, 0).unwrap();
+    storage.add_reading(reading);
+    storage.add_author(author);
+    let book_id = book.id.clone();
+    storage.add_book(book);
+    book_id
+}
+
+#[test]
+fn test_markdown_escape() {
+    assert_eq!(
+        markdown_escape("The *Best* Book [Ever]"),
+        "The \\*Best\\* Book \\[Ever\\]"
+    );
+    assert_eq!(
+        markdown_escape("snake_case | a\\b `code`"),
+        "snake\\_case \\| a\\\\b \\`code\\`"
+    );
+    assert_eq!(markdown_escape("Plain title"), "Plain title");
+}
+
+#[test]
+fn test_year_report_escapes_titles_and_authors() {
+    let mut storage = Storage::new();
+    add_finished_book(&mut storage, "The *Best* Book [Ever]", "A_Writer", 3);
+    add_finished_book(&mut storage, "Pipes | Tables", "Someone", 5);
+
+    let report = year_report_markdown(&storage, 2025, &Timezone::Utc, "%Y-%m-%d");
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines[0], "# Reading report 2025");
+    assert_eq!(lines[2], "2 books finished, 500 pages.");
+    assert_eq!(lines[4], "| Finished | Title | Author | Rating |");
+    assert_eq!(lines[5], "| --- | --- | --- | ---: |");
+    assert_eq!(
+        lines[6],
+        "| 2025-03-10 | The \\*Best\\* Book \\[Ever\\] | A\\_Writer |  |"
+    );
+    // The escaped pipe doesn't add a column
+    assert_eq!(lines[7], "| 2025-05-10 | Pipes \\| Tables | Someone |  |");
+}
+
+#[test]
+fn test_year_report_without_finished_books() {
+    let mut storage = Storage::new();
+    storage.set_goal(2025, 12);
+    assert_eq!(
+        year_report_markdown(&storage, 2025, &Timezone::Utc, "%Y-%m-%d"),
+        "# Reading report 2025\n\n0 books finished, 0 pages. Goal: 12 books."
+    );
+}
+
+#[test]
+fn test_reading_journal_orders_by_finish_date_and_includes_unreviewed_books() {
+    let mut storage = Storage::new();
+    let late = add_finished_book(&mut storage, "Late *Book*", "Author B", 9);
+    add_finished_book(&mut storage, "Unreviewed", "Author C", 6);
+    let early = add_finished_book(&mut storage, "Early Book", "Author A", 2);
+    add_finished_book(&mut storage, "Last Year", "Author D", 1);
+    // Move the last book's finish into 2024 so it's left out
+    let reading = storage
+        .readings
+        .values_mut()
+        .find(|r| r.created_on.format("%m").to_string() == "01")
+        .unwrap();
+    reading.created_on = Utc.with_ymd_and_hms(2024, 12, 31, 12, 0, 0).unwrap();
+
+    let mut first = Review::new(early.clone(), "Loved it.".to_string());
+    first.rating = Some(5);
+    first.created_on = Utc.with_ymd_and_hms(2025, 2, 11, 0, 0, 0).unwrap();
+    storage.add_review(first);
+    let mut second = Review::new(early, "Still *thinking* about it.".to_string());
+    second.rating = Some(4);
+    second.created_on = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+    storage.add_review(second);
+    storage.add_review(Review::new(late, "  Fine.\n".to_string()));
+
+    assert_eq!(
+        reading_journal_markdown(&storage, 2025, &Timezone::Utc, "%Y-%m-%d"),
+        "# Reading journal 2025\n\
+         \n\
+         ## Early Book\n\
+         \n\
+         By Author A \u{00b7} Finished 2025-02-10 \u{00b7} Rating 4.5/5\n\
+         \n\
+         Loved it.\n\
+         \n\
+         Still \\*thinking\\* about it.\n\
+         \n\
+         ## Unreviewed\n\
+         \n\
+         By Author C \u{00b7} Finished 2025-06-10\n\
+         \n\
+         ## Late \\*Book\\*\n\
+         \n\
+         By Author B \u{00b7} Finished 2025-09-10\n\
+         \n\
+         Fine."
+    );
+}
+
+#[test]
+fn test_reading_journal_without_finished_books() {
+    assert_eq!(
+        reading_journal_markdown(&Storage::new(), 2025, &Timezone::Utc, "%Y-%m-%d"),
+        "# Reading journal 2025\n\nNo books finished in 2025."
+    );
+}
@@ -1,5 +1,8 @@
-use bookmon::review::{show_review_detail, show_reviews, store_review, strip_editor_text};
-use bookmon::storage::{Author, Book, Category, Review, Storage};
+use bookmon::review::{
+    parse_rating_input, show_review_detail, show_reviews, store_review, strip_editor_text,
+    truncate_text,
+};
+use bookmon::storage::{Author, Book, Category, Review, Storage, StorageError};
 use chrono::DateTime;
 
 // --- Helper to create a storage with one book ---
@@ -51,10 +54,22 @@ fn test_store_review_with_invalid_book() {
     assert!(result.is_err());
     assert!(result
         .unwrap_err()
+        .to_string()
         .contains("Book with ID nonexistent-book-id does not exist"));
     assert_eq!(storage.reviews.len(), 0);
 }
 
+#[test]
+fn test_store_review_with_invalid_book_is_missing_book_error() {
+    let mut storage = Storage::new();
+    let review = Review::new("missing".to_string(), "Text".to_string());
+
+    assert_eq!(
+        store_review(&mut storage, review),
+        Err(StorageError::MissingBook("missing".to_string()))
+    );
+}
+
 #[test]
 fn test_multiple_reviews_for_same_book() {
     let (mut storage, book_id) = create_storage_with_book();
@@ -276,7 +291,7 @@ fn test_show_reviews_with_unicode_text_does_not_panic() {
     let review = Review::new(book_id, text.to_string());
     storage.add_review(review);
     // This should not panic due to byte-index slicing on multi-byte chars
-    assert!(show_reviews(&storage).is_ok());
+    assert!(show_reviews(&storage, "%Y-%m-%d").is_ok());
 }
 
 #[test]
@@ -286,7 +301,31 @@ fn test_show_reviews_with_emoji_text_does_not_panic() {
     let text = "\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}";
     let review = Review::new(book_id, text.to_string());
     storage.add_review(review);
-    assert!(show_reviews(&storage).is_ok());
+    assert!(show_reviews(&storage, "%Y-%m-%d").is_ok());
+}
+
+#[test]
+fn test_truncate_text_cuts_on_char_boundary() {
+    // "é" and "☕" are multi-byte; the cut falls right after them
+    assert_eq!(
+        truncate_text("Caf\u{e9} \u{2615} and more", 9),
+        "Caf\u{e9} \u{2615}..."
+    );
+    assert_eq!(
+        truncate_text("\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}\u{1f4da}", 4),
+        "\u{1f4da}..."
+    );
+    assert_eq!(
+        truncate_text("\u{1f4da}\u{1f4da}\u{1f4da}", 3),
+        "\u{1f4da}\u{1f4da}\u{1f4da}"
+    );
+}
+
+#[test]
+fn test_truncate_text_keeps_short_text_and_flattens_newlines() {
+    assert_eq!(truncate_text("Short", 500), "Short");
+    assert_eq!(truncate_text("First\nSecond", 500), "First Second");
+    assert_eq!(truncate_text(&"x".repeat(600), 500).chars().count(), 500);
 }
 
 // --- Display function tests ---
@@ -294,7 +333,7 @@ fn test_show_reviews_with_emoji_text_does_not_panic() {
 #[test]
 fn test_show_reviews_empty() {
     let storage = Storage::new();
-    assert!(show_reviews(&storage).is_ok());
+    assert!(show_reviews(&storage, "%Y-%m-%d").is_ok());
 }
 
 #[test]
@@ -302,7 +341,7 @@ fn test_show_reviews_with_data() {
     let (mut storage, book_id) = create_storage_with_book();
     let review = Review::new(book_id, "A fascinating read.".to_string());
     storage.add_review(review);
-    assert!(show_reviews(&storage).is_ok());
+    assert!(show_reviews(&storage, "%Y-%m-%d").is_ok());
 }
 
 #[test]
@@ -311,13 +350,13 @@ fn test_show_review_detail_valid() {
     let review = Review::new(book_id, "Detailed review text here.".to_string());
     let review_id = review.id.clone();
     storage.add_review(review);
-    assert!(show_review_detail(&storage, &review_id).is_ok());
+    assert!(show_review_detail(&storage, &review_id, "%Y-%m-%d").is_ok());
 }
 
 #[test]
 fn test_show_review_detail_not_found() {
     let storage = Storage::new();
-    let result = show_review_detail(&storage, "nonexistent-id");
+    let result = show_review_detail(&storage, "nonexistent-id", "%Y-%m-%d");
     assert!(result.is_err());
 }
 
@@ -345,3 +384,71 @@ fn test_write_and_load_storage_with_reviews() {
     assert!(loaded_review.text.contains("Multi-line review."));
     assert!(loaded_review.text.contains("\"quotes\""));
 }
+
+#[test]
+fn test_parse_rating_input_accepts_one_to_five() {
+    assert_eq!(parse_rating_input("1"), Ok(Some(1)));
+    assert_eq!(parse_rating_input(" 5 "), Ok(Some(5)));
+}
+
+#[test]
+fn test_parse_rating_input_blank_is_no_rating() {
+    assert_eq!(parse_rating_input(""), Ok(None));
+    assert_eq!(parse_rating_input("   "), Ok(None));
+}
+
+#[test]
+fn test_parse_rating_input_rejects_out_of_range() {
+    assert!(parse_rating_input("0").is_err());
+    assert!(parse_rating_input("6").is_err());
+    assert!(parse_rating_input("3.5").is_err());
+    assert!(parse_rating_input("great").is_err());
+}
+
+#[test]
+fn test_review_without_rating_field_loads() {
+    let json = r#"{"id":"r1","created_on":"2024-01-01T00:00:00Z","book_id":"b1","text":"Old."}"#;
+    let review: Review = serde_json::from_str(json).unwrap();
+    assert_eq!(review.rating, None);
+    assert!(!serde_json::to_string(&review).unwrap().contains("rating"));
+}
+
+#[test]
+fn test_books_with_min_rating_includes_boundary_and_skips_unrated() {
+    let mut storage = Storage::new();
+    for (title, ratings) in [
+        ("Four", vec![Some(4)]),
+        ("Just Under", vec![Some(4), Some(3)]),
+        ("Five", vec![Some(5), None]),
+        ("Unrated", vec![None]),
+        ("No Reviews", vec![]),
+    ] {
+        let book = Book::new(
+            title.to_string(),
+            String::new(),
+            "category".to_string(),
+            "author".to_string(),
+            200,
+        );
+        for rating in ratings {
+            let mut review = Review::new(book.id.clone(), "Thoughts.".to_string());
+            review.rating = rating;
+            storage.add_review(review);
+        }
+        storage.add_book(book);
+    }
+
+    let titles = |min: f64| {
+        let mut titles: Vec<String> = storage
+            .books_with_min_rating(min)
+            .iter()
+            .map(|b| b.title.clone())
+            .collect();
+        titles.sort();
+        titles
+    };
+    assert_eq!(titles(4.0), vec!["Five", "Four"]);
+    assert_eq!(titles(3.5), vec!["Five", "Four", "Just Under"]);
+    assert_eq!(titles(5.0), vec!["Five"]);
+    assert!(titles(5.5).is_empty());
+}
@@ -1,8 +1,12 @@
 use bookmon::series::{
-    delete_series, format_position_prefix, format_series_display, format_series_label,
-    get_or_create_series, is_position_occupied, parse_position_input, rename_series, store_series,
+    check_position_free, count_books_matching_series, delete_series, format_position_prefix,
+    format_series_display, format_series_display_with_limit, format_series_label,
+    format_series_summary, get_or_create_series, is_position_occupied, parse_position_input,
+    rename_series, store_series,
+};
+use bookmon::storage::{
+    Author, Book, Category, Reading, ReadingEvent, Review, Series, Storage, StorageError,
 };
-use bookmon::storage::{Author, Book, Category, Reading, ReadingEvent, Series, Storage};
 use chrono::Utc;
 
 #[test]
@@ -134,6 +138,60 @@ fn test_book_series_fields_backward_compatibility_with_integer_position() {
     assert_eq!(book.position_in_series, Some("3".to_string()));
 }
 
+/// Deserializes a book whose JSON has the given `position_in_series` value,
+/// or no such field when `None`.
+fn book_with_position_json(position: Option<&str>) -> Book {
+    let position_field = position
+        .map(|p| format!(r#","position_in_series": {}"#, p))
+        .unwrap_or_default();
+    let json = format!(
+        r#"{{
+            "id": "test-id",
+            "title": "Old Book",
+            "added_on": "2024-01-01T00:00:00Z",
+            "isbn": "1234567890",
+            "category_id": "cat-id",
+            "author_id": "author-id",
+            "total_pages": 200,
+            "series_id": "series-1"{}
+        }}"#,
+        position_field
+    );
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn test_position_in_series_accepts_numbers_strings_and_null() {
+    let position = |json: Option<&str>| book_with_position_json(json).position_in_series;
+
+    assert_eq!(position(Some("3")), Some("3".to_string()));
+    assert_eq!(position(Some(r#""3""#)), Some("3".to_string()));
+    assert_eq!(position(Some("2.5")), Some("2.5".to_string()));
+    assert_eq!(position(Some("3.0")), Some("3".to_string()));
+    assert_eq!(position(Some(r#"" 2a ""#)), Some("2a".to_string()));
+    assert_eq!(position(Some("null")), None);
+    assert_eq!(position(Some(r#""""#)), None);
+    assert_eq!(position(Some(r#""   ""#)), None);
+    assert_eq!(position(None), None);
+}
+
+#[test]
+fn test_position_in_series_rejects_other_types() {
+    let json = r#"{
+        "id": "test-id",
+        "title": "Old Book",
+        "added_on": "2024-01-01T00:00:00Z",
+        "isbn": "1234567890",
+        "category_id": "cat-id",
+        "author_id": "author-id",
+        "total_pages": 200,
+        "position_in_series": [3]
+    }"#;
+
+    let err = serde_json::from_str::<Book>(json).unwrap_err();
+    assert!(err.to_string().contains("position_in_series"), "{}", err);
+}
+
 #[test]
 fn test_series_round_trip() {
     let tmp = tempfile::NamedTempFile::new().unwrap();
@@ -485,6 +543,94 @@ fn test_format_series_display_empty_series() {
     assert!(output.contains("(no books)"));
 }
 
+/// Builds a Discworld series with `count` books: the first finished, the
+/// second started and the rest unread.
+fn discworld_storage(count: usize, total_books: Option<u32>) -> (Storage, String) {
+    let mut storage = Storage::new();
+
+    let author = Author::new("Terry Pratchett".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let category = Category::new("Fantasy".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    let mut series = Series::new("Discworld".to_string());
+    series.total_books = total_books;
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    for i in 1..=count {
+        let mut book = Book::new(
+            format!("Discworld {}", i),
+            format!("{}", i),
+            category_id.clone(),
+            author_id.clone(),
+            300,
+        );
+        book.series_id = Some(series_id.clone());
+        book.position_in_series = Some(i.to_string());
+        let book_id = book.id.clone();
+        storage.add_book(book);
+        match i {
+            1 => {
+                storage.add_reading(Reading::new(book_id, ReadingEvent::Finished));
+            }
+            2 => {
+                storage.add_reading(Reading::new(book_id, ReadingEvent::Started));
+            }
+            _ => {}
+        }
+    }
+
+    (storage, series_id)
+}
+
+#[test]
+fn test_format_series_summary_with_total_books() {
+    let (storage, series_id) = discworld_storage(3, Some(41));
+    assert_eq!(
+        format_series_summary(&storage, &series_id),
+        "Discworld \u{2014} 1/41 read, 1 reading"
+    );
+}
+
+#[test]
+fn test_format_series_summary_without_total_books() {
+    let (storage, series_id) = discworld_storage(3, None);
+    assert_eq!(
+        format_series_summary(&storage, &series_id),
+        "Discworld \u{2014} 1 read, 1 reading"
+    );
+}
+
+#[test]
+fn test_format_series_summary_empty_and_missing_series() {
+    let (storage, series_id) = discworld_storage(0, None);
+    assert_eq!(
+        format_series_summary(&storage, &series_id),
+        "Discworld \u{2014} no books"
+    );
+    assert_eq!(format_series_summary(&storage, "missing"), "");
+}
+
+#[test]
+fn test_format_series_display_with_limit_truncates_books() {
+    let (storage, series_id) = discworld_storage(5, Some(41));
+    let output =
+        format_series_display_with_limit(&storage, &series_id, &Default::default(), Some(2));
+
+    assert!(output.contains("Discworld 2"));
+    assert!(!output.contains("Discworld 3"));
+    assert!(output.contains("\u{2026} and 3 more"));
+
+    let output =
+        format_series_display_with_limit(&storage, &series_id, &Default::default(), Some(10));
+    assert!(output.contains("Discworld 5"));
+    assert!(!output.contains("more"));
+}
+
 // --- Duplicate position detection tests ---
 
 #[test]
@@ -674,6 +820,7 @@ fn test_store_book_validates_series_id() {
     assert!(result.is_err());
     assert!(result
         .unwrap_err()
+        .to_string()
         .contains("Series with ID nonexistent-series-id does not exist"));
 
     // Book with valid series_id should succeed
@@ -904,7 +1051,7 @@ fn test_delete_series_removes_series_and_unlinks_books() {
     assert!(result.is_ok());
 
     // Series should be gone
-    assert!(storage.series.get(&series_id).is_none());
+    assert!(!storage.series.contains_key(&series_id));
 
     // Books should still exist but have no series
     let b1 = storage.books.get(&book1_id).unwrap();
@@ -921,7 +1068,7 @@ fn test_delete_series_nonexistent_returns_error() {
     let mut storage = Storage::new();
     let result = delete_series(&mut storage, "nonexistent-id");
     assert!(result.is_err());
-    let err_msg = result.unwrap_err();
+    let err_msg = result.unwrap_err().to_string();
     assert!(err_msg.contains("not found"));
     // Error message should not leak internal IDs
     assert!(
@@ -951,7 +1098,7 @@ fn test_rename_series_nonexistent_returns_error() {
     let mut storage = Storage::new();
     let result = rename_series(&mut storage, "nonexistent-id", "New Name");
     assert!(result.is_err());
-    let err_msg = result.unwrap_err();
+    let err_msg = result.unwrap_err().to_string();
     assert!(err_msg.contains("not found"));
     assert!(
         !err_msg.contains("nonexistent-id"),
@@ -972,7 +1119,7 @@ fn test_rename_series_to_duplicate_name_returns_error() {
     // Renaming series1 to match series2's name (case-insensitive) should fail
     let result = rename_series(&mut storage, &series1_id, "lord of the rings");
     assert!(result.is_err());
-    assert!(result.unwrap_err().contains("already exists"));
+    assert!(result.unwrap_err().to_string().contains("already exists"));
 }
 
 #[test]
@@ -984,12 +1131,12 @@ fn test_rename_series_empty_name_returns_error() {
 
     let result = rename_series(&mut storage, &series_id, "");
     assert!(result.is_err());
-    assert!(result.unwrap_err().contains("cannot be empty"));
+    assert!(result.unwrap_err().to_string().contains("cannot be empty"));
 
     // Whitespace-only should also fail
     let result = rename_series(&mut storage, &series_id, "   ");
     assert!(result.is_err());
-    assert!(result.unwrap_err().contains("cannot be empty"));
+    assert!(result.unwrap_err().to_string().contains("cannot be empty"));
 
     // Original name should be unchanged
     assert_eq!(storage.series.get(&series_id).unwrap().name, "Harry Potter");
@@ -1151,6 +1298,44 @@ fn test_find_matching_series_names() {
     assert!(matches.len() >= 2); // "Harry P-or-tter", "L-or-d of the Rings", "Discw-or-ld"
 }
 
+#[test]
+fn test_find_matching_series_ranked_puts_exact_and_prefix_matches_first() {
+    use bookmon::series::find_matching_series_ranked;
+
+    let mut storage = Storage::new();
+    for name in [
+        "The Dark Tower",
+        "Dune",
+        "Dune Chronicles",
+        "Duncan and Dunk",
+        "Children of Dune",
+        "Heritage of Dune",
+    ] {
+        storage.add_series(Series::new(name.to_string()));
+    }
+
+    assert_eq!(
+        find_matching_series_ranked(&storage, "dune"),
+        vec![
+            "Dune",
+            "Dune Chronicles",
+            "Children of Dune",
+            "Heritage of Dune",
+        ]
+    );
+    assert_eq!(
+        find_matching_series_ranked(&storage, "dun"),
+        vec![
+            "Duncan and Dunk",
+            "Dune",
+            "Dune Chronicles",
+            "Children of Dune",
+            "Heritage of Dune",
+        ]
+    );
+    assert!(find_matching_series_ranked(&storage, "potter").is_empty());
+}
+
 #[test]
 fn test_series_filter_empty_message() {
     use bookmon::series::format_series_filter_empty_message;
@@ -1205,3 +1390,615 @@ fn test_format_position_prefix_with_zero() {
 fn test_format_position_prefix_none() {
     assert_eq!(format_position_prefix(None), "");
 }
+
+#[test]
+fn test_series_progress_counts_finished_books() {
+    let mut storage = Storage::new();
+
+    let series = Series::new("Discworld".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    let mut ids = Vec::new();
+    for (i, title) in ["The Colour of Magic", "The Light Fantastic", "Equal Rites"]
+        .iter()
+        .enumerate()
+    {
+        let mut book = Book::new(
+            title.to_string(),
+            format!("isbn-{}", i),
+            "cat".to_string(),
+            "author".to_string(),
+            250,
+        );
+        book.series_id = Some(series_id.clone());
+        book.position_in_series = Some((i + 1).to_string());
+        ids.push(book.id.clone());
+        storage.add_book(book);
+    }
+
+    storage.add_reading(Reading::new(ids[0].clone(), ReadingEvent::Finished));
+    storage.add_reading(Reading::new(ids[1].clone(), ReadingEvent::Started));
+
+    // Total unknown
+    assert_eq!(storage.series_progress(&series_id), (1, None));
+
+    // Total known
+    storage.series.get_mut(&series_id).unwrap().total_books = Some(41);
+    assert_eq!(storage.series_progress(&series_id), (1, Some(41)));
+
+    // Unknown series
+    assert_eq!(storage.series_progress("missing"), (0, None));
+}
+
+#[test]
+fn test_count_books_matching_series_for_finished_books() {
+    let mut storage = Storage::new();
+
+    let series = Series::new("Earthsea".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    for (i, in_series) in [true, true, false].iter().enumerate() {
+        let mut book = Book::new(
+            format!("Book {}", i),
+            format!("isbn-{}", i),
+            "cat".to_string(),
+            "author".to_string(),
+            200,
+        );
+        if *in_series {
+            book.series_id = Some(series_id.clone());
+        }
+        let book_id = book.id.clone();
+        storage.add_book(book);
+        storage.add_reading(Reading::new(book_id, ReadingEvent::Finished));
+    }
+
+    let finished = storage.get_finished_books();
+    assert_eq!(count_books_matching_series(&storage, &finished, None), 3);
+    assert_eq!(
+        count_books_matching_series(&storage, &finished, Some("earth")),
+        2
+    );
+    assert_eq!(
+        count_books_matching_series(&storage, &finished, Some("Dune")),
+        0
+    );
+}
+
+#[test]
+fn test_parse_position_input_alphanumeric() {
+    assert_eq!(parse_position_input("2a"), Some("2a".to_string()));
+    assert_eq!(parse_position_input(" 1B "), Some("1B".to_string()));
+    assert_eq!(parse_position_input("IV"), None);
+    assert_eq!(parse_position_input("c"), None);
+    assert_eq!(parse_position_input("Book X"), None);
+    assert_eq!(parse_position_input("a2"), None);
+    assert_eq!(parse_position_input("-2a"), None);
+}
+
+#[test]
+fn test_books_in_series_sorted_with_mixed_positions() {
+    let mut storage = Storage::new();
+
+    let series = Series::new("Mixed".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    for position in ["2b", "3", "0", "2a", "1.5", "1"] {
+        let mut book = Book::new(
+            format!("Book {}", position),
+            "isbn".to_string(),
+            "cat".to_string(),
+            "author".to_string(),
+            100,
+        );
+        book.series_id = Some(series_id.clone());
+        book.position_in_series = Some(position.to_string());
+        storage.add_book(book);
+    }
+
+    let positions: Vec<&str> = storage
+        .get_books_in_series(&series_id)
+        .iter()
+        .map(|b| b.position_in_series.as_deref().unwrap())
+        .collect();
+    assert_eq!(positions, vec!["0", "1", "1.5", "2a", "2b", "3"]);
+}
+
+#[test]
+fn test_mark_series_want_to_read_skips_started_and_finished_books() {
+    let mut storage = Storage::new();
+
+    let series = Series::new("The Expanse".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    let mut ids = Vec::new();
+    for i in 1..=5 {
+        let mut book = Book::new(
+            format!("Book {}", i),
+            format!("isbn-{}", i),
+            "cat".to_string(),
+            "author".to_string(),
+            500,
+        );
+        book.series_id = Some(series_id.clone());
+        book.position_in_series = Some(i.to_string());
+        ids.push(book.id.clone());
+        storage.add_book(book);
+    }
+
+    // Book 1 finished, book 2 being read, book 3 already wanted, book 4 bought, book 5 untouched
+    storage.add_reading(Reading::new(ids[0].clone(), ReadingEvent::Finished));
+    storage.add_reading(Reading::new(ids[1].clone(), ReadingEvent::Started));
+    storage.add_reading(Reading::new(ids[2].clone(), ReadingEvent::WantToRead));
+    let mut bought = Reading::new(ids[3].clone(), ReadingEvent::Bought);
+    bought.created_on = Utc::now() - chrono::Duration::days(1);
+    storage.add_reading(bought);
+
+    // A book outside the series is left alone
+    let standalone = Book::new(
+        "Standalone".to_string(),
+        "isbn-x".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        100,
+    );
+    let standalone_id = standalone.id.clone();
+    storage.add_book(standalone);
+
+    assert_eq!(storage.mark_series_want_to_read(&series_id), 2);
+
+    let wanted: Vec<String> = storage
+        .get_want_to_read_books()
+        .iter()
+        .map(|b| b.id.clone())
+        .collect();
+    assert!(wanted.contains(&ids[2]));
+    assert!(wanted.contains(&ids[3]));
+    assert!(wanted.contains(&ids[4]));
+    assert!(!wanted.contains(&ids[0]));
+    assert!(!wanted.contains(&ids[1]));
+    assert!(!wanted.contains(&standalone_id));
+
+    // Running again marks nothing new
+    assert_eq!(storage.mark_series_want_to_read(&series_id), 0);
+}
+
+#[test]
+fn test_mark_series_want_to_read_skips_books_started_by_a_page_update() {
+    let mut storage = Storage::new();
+
+    let series = Series::new("Earthsea".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    let mut book = Book::new(
+        "A Wizard of Earthsea".to_string(),
+        "isbn-1".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        200,
+    );
+    book.series_id = Some(series_id.clone());
+    book.position_in_series = Some("1".to_string());
+    let book_id = book.id.clone();
+    storage.add_book(book);
+    storage.add_reading(Reading::with_metadata(
+        book_id.clone(),
+        ReadingEvent::Update,
+        40,
+    ));
+
+    assert_eq!(storage.mark_series_want_to_read(&series_id), 0);
+    assert!(storage.is_book_started(&book_id));
+    assert!(storage.get_want_to_read_books().is_empty());
+}
+
+/// Builds a three-book series and returns the storage, series ID and book IDs.
+fn create_rated_series() -> (Storage, String, Vec<String>) {
+    let mut storage = Storage::new();
+    let author = Author::new("Ursula K. Le Guin".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    let category = Category::new("Fantasy".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+    let series = Series::new("Earthsea".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    let mut book_ids = Vec::new();
+    for (i, title) in [
+        "A Wizard of Earthsea",
+        "The Tombs of Atuan",
+        "The Farthest Shore",
+    ]
+    .iter()
+    .enumerate()
+    {
+        let mut book = Book::new(
+            title.to_string(),
+            format!("{}", i),
+            category_id.clone(),
+            author_id.clone(),
+            200,
+        );
+        book.series_id = Some(series_id.clone());
+        book.position_in_series = Some((i + 1).to_string());
+        book_ids.push(book.id.clone());
+        storage.add_book(book);
+    }
+    (storage, series_id, book_ids)
+}
+
+fn add_rated_review(storage: &mut Storage, book_id: &str, rating: Option<u8>) {
+    let mut review = Review::new(book_id.to_string(), "Review.".to_string());
+    review.rating = rating;
+    storage.add_review(review);
+}
+
+#[test]
+fn test_series_rating_summary_averages_rated_books() {
+    let (mut storage, series_id, ids) = create_rated_series();
+    add_rated_review(&mut storage, &ids[0], Some(4));
+    add_rated_review(&mut storage, &ids[1], Some(2));
+    add_rated_review(&mut storage, &ids[2], Some(5));
+    add_rated_review(&mut storage, &ids[2], Some(3));
+
+    let (average, best) = storage.series_rating_summary(&series_id);
+    // Per-book averages are 4, 2 and 4; the tie goes to the earlier book.
+    assert!((average.unwrap() - 10.0 / 3.0).abs() < 1e-9);
+    assert_eq!(best.unwrap().title, "A Wizard of Earthsea");
+}
+
+#[test]
+fn test_series_rating_summary_ignores_unrated_books_and_reviews() {
+    let (mut storage, series_id, ids) = create_rated_series();
+    add_rated_review(&mut storage, &ids[0], None);
+    add_rated_review(&mut storage, &ids[1], Some(3));
+    add_rated_review(&mut storage, &ids[1], None);
+    add_rated_review(&mut storage, &ids[2], Some(5));
+
+    let (average, best) = storage.series_rating_summary(&series_id);
+    assert_eq!(average, Some(4.0));
+    assert_eq!(best.unwrap().title, "The Farthest Shore");
+}
+
+#[test]
+fn test_series_rating_summary_without_ratings() {
+    let (mut storage, series_id, ids) = create_rated_series();
+    add_rated_review(&mut storage, &ids[0], None);
+
+    let (average, best) = storage.series_rating_summary(&series_id);
+    assert_eq!(average, None);
+    assert!(best.is_none());
+    let output = format_series_display(&storage, &series_id);
+    assert!(!output.contains("Average rating"));
+}
+
+#[test]
+fn test_format_series_display_shows_rating_summary() {
+    let (mut storage, series_id, ids) = create_rated_series();
+    add_rated_review(&mut storage, &ids[1], Some(5));
+    add_rated_review(&mut storage, &ids[2], Some(2));
+
+    let output = format_series_display(&storage, &series_id);
+    assert!(
+        output.contains("Average rating 3.5/5, best: \"The Tombs of Atuan\" (5.0/5)"),
+        "unexpected output: {}",
+        output
+    );
+}
+
+#[test]
+fn test_find_book_in_series_by_title() {
+    let mut storage = Storage::new();
+    let series = Series::new("The Expanse".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+    let other_series = Series::new("Dune".to_string());
+    let other_series_id = other_series.id.clone();
+    storage.add_series(other_series);
+
+    let mut book = Book::new(
+        "Cibola Burn".to_string(),
+        "9780316217620".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        583,
+    );
+    book.series_id = Some(series_id.clone());
+    book.position_in_series = Some("4".to_string());
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    assert!(storage.books[&book_id].is_in_series(&series_id));
+    assert!(!storage.books[&book_id].is_in_series(&other_series_id));
+
+    let found = storage
+        .find_book_in_series_by_title(&series_id, "  cibola BURN ")
+        .unwrap();
+    assert_eq!(found.id, book_id);
+}
+
+#[test]
+fn test_find_book_in_series_by_title_no_match() {
+    let mut storage = Storage::new();
+    let series = Series::new("The Expanse".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    let mut in_series = Book::new(
+        "Cibola Burn".to_string(),
+        "9780316217620".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        583,
+    );
+    in_series.series_id = Some(series_id.clone());
+    storage.add_book(in_series);
+    // A book outside the series
+    storage.add_book(Book::new(
+        "Leviathan Wakes".to_string(),
+        "9780316129084".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        561,
+    ));
+
+    assert!(storage
+        .find_book_in_series_by_title(&series_id, "Leviathan Wakes")
+        .is_none());
+    assert!(storage
+        .find_book_in_series_by_title("no-such-series", "Cibola Burn")
+        .is_none());
+}
+
+#[test]
+fn test_format_series_display_with_custom_symbols() {
+    use bookmon::config::StatusSymbols;
+    use bookmon::series::format_series_display_with_symbols;
+
+    let mut storage = Storage::new();
+    let series = Series::new("Earthsea".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    let mut ids = Vec::new();
+    for (position, title) in [
+        "A Wizard of Earthsea",
+        "The Tombs of Atuan",
+        "The Farthest Shore",
+    ]
+    .iter()
+    .enumerate()
+    {
+        let mut book = Book::new(
+            title.to_string(),
+            format!("isbn-{}", position),
+            "cat".to_string(),
+            "author".to_string(),
+            200,
+        );
+        book.series_id = Some(series_id.clone());
+        book.position_in_series = Some((position + 1).to_string());
+        ids.push(book.id.clone());
+        storage.add_book(book);
+    }
+    storage.add_reading(Reading::new(ids[0].clone(), ReadingEvent::Finished));
+    storage.add_reading(Reading::new(ids[1].clone(), ReadingEvent::Started));
+
+    let ascii = format_series_display_with_symbols(&storage, &series_id, &StatusSymbols::ascii());
+    assert!(ascii.contains("#1 x \"A Wizard of Earthsea\""), "{}", ascii);
+    assert!(ascii.contains("#2 > \"The Tombs of Atuan\""), "{}", ascii);
+    assert!(ascii.contains("#3   \"The Farthest Shore\""), "{}", ascii);
+    assert!(!ascii.contains('\u{2713}') && !ascii.contains('\u{25b8}'));
+
+    let custom = StatusSymbols {
+        finished: "[done]".to_string(),
+        reading: "[now]".to_string(),
+        unread: "[ ]".to_string(),
+    };
+    let output = format_series_display_with_symbols(&storage, &series_id, &custom);
+    assert!(
+        output.contains("#1 [done] \"A Wizard of Earthsea\""),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("#2 [now] \"The Tombs of Atuan\""),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("#3 [ ] \"The Farthest Shore\""),
+        "{}",
+        output
+    );
+
+    // The default keeps the Unicode symbols
+    assert_eq!(
+        format_series_display(&storage, &series_id),
+        format_series_display_with_symbols(&storage, &series_id, &StatusSymbols::default())
+    );
+}
+
+#[test]
+fn test_standalone_books_excludes_books_in_a_series() {
+    let mut storage = Storage::new();
+    let series = Series::new("Dune".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+
+    let mut in_series = Book::new(
+        "Dune Messiah".to_string(),
+        "111".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        256,
+    );
+    in_series.series_id = Some(series_id);
+    storage.add_book(in_series);
+    for title in ["The Dispossessed", "Kindred"] {
+        storage.add_book(Book::new(
+            title.to_string(),
+            format!("isbn-{}", title),
+            "cat".to_string(),
+            "author".to_string(),
+            300,
+        ));
+    }
+
+    let titles: Vec<&str> = storage
+        .standalone_books()
+        .iter()
+        .map(|b| b.title.as_str())
+        .collect();
+    assert_eq!(titles, vec!["Kindred", "The Dispossessed"]);
+}
+
+#[test]
+fn test_standalone_books_empty_when_all_in_series() {
+    let mut storage = Storage::new();
+    let mut book = Book::new(
+        "Dune Messiah".to_string(),
+        "111".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        256,
+    );
+    book.series_id = Some("series".to_string());
+    storage.add_book(book);
+
+    assert!(storage.standalone_books().is_empty());
+}
+
+/// Builds a series with books at the given positions; returns the storage, the
+/// series ID and the book IDs in the same order as `positions`.
+fn series_with_positions(positions: &[&str]) -> (Storage, String, Vec<String>) {
+    let mut storage = Storage::new();
+    let series = Series::new("Discworld".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+    let mut ids = Vec::new();
+    for pos in positions {
+        let mut book = Book::new(
+            format!("Book {}", pos),
+            format!("isbn-{}", pos),
+            "cat".to_string(),
+            "author".to_string(),
+            300,
+        );
+        book.series_id = Some(series_id.clone());
+        book.position_in_series = Some(pos.to_string());
+        ids.push(book.id.clone());
+        storage.add_book(book);
+    }
+    (storage, series_id, ids)
+}
+
+fn finish(storage: &mut Storage, book_id: &str) {
+    storage.add_reading(Reading::new(book_id.to_string(), ReadingEvent::Finished));
+}
+
+fn next_title(storage: &Storage, series_id: &str) -> Option<String> {
+    storage.next_in_series(series_id).map(|b| b.title.clone())
+}
+
+#[test]
+fn test_next_in_series_follows_finished_books() {
+    let (mut storage, series_id, ids) = series_with_positions(&["1", "2", "3", "4"]);
+    assert_eq!(next_title(&storage, &series_id).as_deref(), Some("Book 1"));
+
+    finish(&mut storage, &ids[0]);
+    finish(&mut storage, &ids[1]);
+    assert_eq!(next_title(&storage, &series_id).as_deref(), Some("Book 3"));
+}
+
+#[test]
+fn test_next_in_series_continues_after_a_skipped_book() {
+    // #2 was skipped; the suggestion continues after the last finished book
+    let (mut storage, series_id, ids) = series_with_positions(&["1", "2", "3", "4"]);
+    finish(&mut storage, &ids[0]);
+    finish(&mut storage, &ids[2]);
+    assert_eq!(next_title(&storage, &series_id).as_deref(), Some("Book 4"));
+
+    // Once the end is reached, the skipped book is suggested
+    finish(&mut storage, &ids[3]);
+    assert_eq!(next_title(&storage, &series_id).as_deref(), Some("Book 2"));
+
+    finish(&mut storage, &ids[1]);
+    assert_eq!(next_title(&storage, &series_id), None);
+}
+
+#[test]
+fn test_next_in_series_skips_missing_positions_and_started_books() {
+    // No #2 in the library, and #3 is already being read
+    let (mut storage, series_id, ids) = series_with_positions(&["1", "3", "4"]);
+    finish(&mut storage, &ids[0]);
+    assert_eq!(next_title(&storage, &series_id).as_deref(), Some("Book 3"));
+
+    storage.add_reading(Reading::new(ids[1].clone(), ReadingEvent::Started));
+    assert_eq!(next_title(&storage, &series_id).as_deref(), Some("Book 4"));
+}
+
+#[test]
+fn test_series_errors_match_storage_error_variants() {
+    let mut storage = Storage::new();
+    let harry = Series::new("Harry Potter".to_string());
+    let harry_id = harry.id.clone();
+    storage.add_series(harry);
+    storage.add_series(Series::new("Discworld".to_string()));
+
+    assert_eq!(
+        rename_series(&mut storage, &harry_id, "  "),
+        Err(StorageError::EmptyName)
+    );
+    assert_eq!(
+        rename_series(&mut storage, &harry_id, "discworld"),
+        Err(StorageError::DuplicateName("discworld".to_string()))
+    );
+    assert_eq!(
+        rename_series(&mut storage, "missing", "Anything"),
+        Err(StorageError::SeriesNotFound)
+    );
+    assert_eq!(
+        delete_series(&mut storage, "missing"),
+        Err(StorageError::SeriesNotFound)
+    );
+}
+
+#[test]
+fn test_check_position_free_reports_occupying_book() {
+    let mut storage = Storage::new();
+    let series = Series::new("Harry Potter".to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+    let mut book = Book::new(
+        "Philosopher's Stone".to_string(),
+        "123".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        300,
+    );
+    book.series_id = Some(series_id.clone());
+    book.position_in_series = Some("1".to_string());
+    storage.add_book(book);
+
+    let err = check_position_free(&storage, &series_id, "1").unwrap_err();
+    assert_eq!(
+        err,
+        StorageError::PositionOccupied {
+            title: "Philosopher's Stone".to_string(),
+            position: "1".to_string(),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "'Philosopher's Stone' is already #1 in this series"
+    );
+    assert!(check_position_free(&storage, &series_id, "2").is_ok());
+}
@@ -1,9 +1,14 @@
 use bookmon::storage::{
-    handle_missing_fields, sort_json_value, write_storage, Author, Book, BookRepairInput, Category,
-    Reading, ReadingEvent, ReadingMetadata, RepairPrompter, Storage,
+    detect_missing_fields, handle_missing_fields, handle_missing_fields_with_mode,
+    load_and_repair_storage_with_mode, load_storage, sort_json_value, write_storage, Author, Book,
+    BookRepairInput, Category, IntegrityIssueKind, MissingField, Reading, ReadingEvent,
+    ReadingMetadata, RepairMode, RepairPrompter, Series, Storage,
 };
-use chrono::{Duration, TimeZone, Utc};
+use bookmon::time::Timezone;
+use chrono::{Datelike, Duration, TimeZone, Utc};
 use serde_json::value::Value;
+use std::cell::RefCell;
+use std::path::Path;
 use uuid::Uuid;
 
 /// A test prompter that returns predefined values
@@ -158,6 +163,13 @@ fn test_storage_save_and_load() {
         total_pages: 300,
         series_id: None,
         position_in_series: None,
+        description: None,
+        cover_url: None,
+        subtitle: None,
+        source: None,
+        publisher: None,
+        publish_year: None,
+        editions: Vec::new(),
     };
     storage.books.insert(book.isbn.clone(), book);
 
@@ -212,11 +224,18 @@ fn test_id_matches_hashmap_keys() {
         title: "Test Book".to_string(),
         added_on: Utc::now(),
         isbn: "1234567890".to_string(),
-        category_id: category_id,
+        category_id,
         author_id: "author1".to_string(),
         total_pages: 300,
         series_id: None,
         position_in_series: None,
+        description: None,
+        cover_url: None,
+        subtitle: None,
+        source: None,
+        publisher: None,
+        publish_year: None,
+        editions: Vec::new(),
     };
 
     let author = Author::new("Test Author".to_string());
@@ -227,6 +246,7 @@ fn test_id_matches_hashmap_keys() {
         book_id: "book1".to_string(),
         event: ReadingEvent::Started,
         metadata: ReadingMetadata::default(),
+        edition_id: None,
     };
 
     // Add items to storage
@@ -306,7 +326,7 @@ fn test_automatic_uuid_generation() {
         );
     }
 
-    for (key, _reading) in &storage.readings {
+    for key in storage.readings.keys() {
         assert!(!key.is_empty(), "Reading ID should not be empty");
     }
 }
@@ -1505,7 +1525,8 @@ fn test_get_read_books_by_time_period() {
         created_on: base_time + Duration::days(5),
         book_id: book2_id.clone(),
         event: ReadingEvent::Finished,
-        metadata: ReadingMetadata { current_page: None },
+        metadata: ReadingMetadata::default(),
+        edition_id: None,
     };
     storage.add_reading(reading2);
 
@@ -1515,7 +1536,8 @@ fn test_get_read_books_by_time_period() {
         created_on: base_time + Duration::days(15),
         book_id: book3_id.clone(),
         event: ReadingEvent::Finished,
-        metadata: ReadingMetadata { current_page: None },
+        metadata: ReadingMetadata::default(),
+        edition_id: None,
     };
     storage.add_reading(reading3);
 
@@ -1570,7 +1592,8 @@ fn test_get_earliest_finished_year() {
         created_on: Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap(),
         book_id: book_id.clone(),
         event: ReadingEvent::Finished,
-        metadata: ReadingMetadata { current_page: None },
+        metadata: ReadingMetadata::default(),
+        edition_id: None,
     };
     storage.add_reading(reading1);
 
@@ -1579,12 +1602,16 @@ fn test_get_earliest_finished_year() {
         created_on: Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
         book_id: book_id.clone(),
         event: ReadingEvent::Finished,
-        metadata: ReadingMetadata { current_page: None },
+        metadata: ReadingMetadata::default(),
+        edition_id: None,
     };
     storage.add_reading(reading2);
 
     // Test getting earliest year
-    assert_eq!(storage.get_earliest_finished_year(), Some(2022));
+    assert_eq!(
+        storage.get_earliest_finished_year(&Timezone::Utc),
+        Some(2022)
+    );
 }
 
 #[test]
@@ -1637,7 +1664,8 @@ fn test_get_books_finished_in_year() {
         created_on: Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap(),
         book_id: book1_id.clone(),
         event: ReadingEvent::Finished,
-        metadata: ReadingMetadata { current_page: None },
+        metadata: ReadingMetadata::default(),
+        edition_id: None,
     };
     storage.add_reading(reading1);
 
@@ -1646,7 +1674,8 @@ fn test_get_books_finished_in_year() {
         created_on: Utc.with_ymd_and_hms(2022, 6, 1, 0, 0, 0).unwrap(),
         book_id: book2_id.clone(),
         event: ReadingEvent::Finished,
-        metadata: ReadingMetadata { current_page: None },
+        metadata: ReadingMetadata::default(),
+        edition_id: None,
     };
     storage.add_reading(reading2);
 
@@ -1655,23 +1684,24 @@ fn test_get_books_finished_in_year() {
         created_on: Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
         book_id: book3_id.clone(),
         event: ReadingEvent::Finished,
-        metadata: ReadingMetadata { current_page: None },
+        metadata: ReadingMetadata::default(),
+        edition_id: None,
     };
     storage.add_reading(reading3);
 
     // Test getting books finished in 2022
-    let books_2022 = storage.get_books_finished_in_year(2022);
+    let books_2022 = storage.get_books_finished_in_year(2022, &Timezone::Utc);
     assert_eq!(books_2022.len(), 2);
     assert!(books_2022.iter().any(|b| b.id == book1_id));
     assert!(books_2022.iter().any(|b| b.id == book2_id));
 
     // Test getting books finished in 2023
-    let books_2023 = storage.get_books_finished_in_year(2023);
+    let books_2023 = storage.get_books_finished_in_year(2023, &Timezone::Utc);
     assert_eq!(books_2023.len(), 1);
     assert!(books_2023.iter().any(|b| b.id == book3_id));
 
     // Test getting books finished in 2024 (should be empty)
-    let books_2024 = storage.get_books_finished_in_year(2024);
+    let books_2024 = storage.get_books_finished_in_year(2024, &Timezone::Utc);
     assert!(books_2024.is_empty());
 }
 
@@ -1696,6 +1726,13 @@ fn test_handle_missing_fields_updates_book_author_reference() {
         total_pages: 100,
         series_id: None,
         position_in_series: None,
+        description: None,
+        cover_url: None,
+        subtitle: None,
+        source: None,
+        publisher: None,
+        publish_year: None,
+        editions: Vec::new(),
     };
     let book_id = book.id.clone();
     storage.add_book(book);
@@ -1741,6 +1778,13 @@ fn test_handle_missing_fields_updates_book_category_reference() {
         total_pages: 100,
         series_id: None,
         position_in_series: None,
+        description: None,
+        cover_url: None,
+        subtitle: None,
+        source: None,
+        publisher: None,
+        publish_year: None,
+        editions: Vec::new(),
     };
     let book_id = book.id.clone();
     storage.add_book(book);
@@ -1991,7 +2035,7 @@ fn test_sort_books_all_same_status() {
 #[test]
 fn test_get_earliest_finished_year_empty() {
     let storage = Storage::new();
-    assert_eq!(storage.get_earliest_finished_year(), None);
+    assert_eq!(storage.get_earliest_finished_year(&Timezone::Utc), None);
 }
 
 #[test]
@@ -2006,6 +2050,7 @@ fn test_handle_missing_fields_updates_reading_book_id() {
         event: ReadingEvent::Started,
         created_on: Utc::now(),
         metadata: ReadingMetadata::default(),
+        edition_id: None,
     };
     let reading_id = reading.id.clone();
     storage.readings.insert(reading.id.clone(), reading);
@@ -2321,6 +2366,13 @@ fn test_handle_missing_fields_clears_orphaned_series_id() {
         total_pages: 200,
         series_id: Some("nonexistent-series-id".to_string()),
         position_in_series: Some("3".to_string()),
+        description: None,
+        cover_url: None,
+        subtitle: None,
+        source: None,
+        publisher: None,
+        publish_year: None,
+        editions: Vec::new(),
     };
     let book_id = book.id.clone();
     storage.add_book(book);
@@ -2371,6 +2423,13 @@ fn test_handle_missing_fields_preserves_valid_series_id() {
         total_pages: 300,
         series_id: Some(series_id.clone()),
         position_in_series: Some("1".to_string()),
+        description: None,
+        cover_url: None,
+        subtitle: None,
+        source: None,
+        publisher: None,
+        publish_year: None,
+        editions: Vec::new(),
     };
     let book_id = book.id.clone();
     storage.add_book(book);
@@ -2393,3 +2452,1734 @@ fn test_handle_missing_fields_preserves_valid_series_id() {
         "position_in_series should be preserved for valid series_id"
     );
 }
+
+#[test]
+fn test_projected_finish_date_from_known_velocity() {
+    let mut storage = Storage::new();
+
+    let author = Author::new("Test Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    let book = Book::new(
+        "Test Book".to_string(),
+        "123".to_string(),
+        category_id,
+        author_id,
+        300,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    let start = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+
+    let mut started = Reading::new(book_id.clone(), ReadingEvent::Started);
+    started.created_on = start;
+    storage.add_reading(started);
+
+    // 100 pages in 10 days = 10 pages/day
+    let mut update = Reading::with_metadata(book_id.clone(), ReadingEvent::Update, 100);
+    update.created_on = start + Duration::days(10);
+    storage.add_reading(update);
+
+    let velocity = storage.reading_velocity(&book_id).unwrap();
+    assert!((velocity - 10.0).abs() < f64::EPSILON);
+
+    // 200 pages remaining at 10 pages/day = 20 days from now
+    let now = start + Duration::days(10);
+    let projected = storage.projected_finish_date(&book_id, now);
+    assert_eq!(projected, Some(start + Duration::days(30)));
+}
+
+#[test]
+fn test_projected_finish_date_none_without_velocity() {
+    let mut storage = Storage::new();
+
+    let book = Book::new(
+        "Test Book".to_string(),
+        "123".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        300,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    let start = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+    let mut started = Reading::new(book_id.clone(), ReadingEvent::Started);
+    started.created_on = start;
+    storage.add_reading(started);
+
+    // No page updates yet — velocity can't be computed
+    assert!(storage.reading_velocity(&book_id).is_none());
+    assert!(storage
+        .projected_finish_date(&book_id, start + Duration::days(3))
+        .is_none());
+
+    // An update on page 0 gives zero velocity — still no projection
+    let mut update = Reading::with_metadata(book_id.clone(), ReadingEvent::Update, 0);
+    update.created_on = start + Duration::days(2);
+    storage.add_reading(update);
+    assert!(storage
+        .projected_finish_date(&book_id, start + Duration::days(3))
+        .is_none());
+}
+
+#[test]
+fn test_projected_finish_date_ignores_pages_from_an_earlier_read() {
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Test Book".to_string(),
+        "123".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        300,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    let start = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+    let add = |storage: &mut Storage, mut reading: Reading, days: i64| {
+        reading.created_on = start + Duration::days(days);
+        storage.add_reading(reading);
+    };
+    add(
+        &mut storage,
+        Reading::new(book_id.clone(), ReadingEvent::Started),
+        0,
+    );
+    add(
+        &mut storage,
+        Reading::with_metadata(book_id.clone(), ReadingEvent::Update, 250),
+        5,
+    );
+    add(
+        &mut storage,
+        Reading::new(book_id.clone(), ReadingEvent::Finished),
+        6,
+    );
+
+    // Re-reading without a new page update: the first read's page doesn't count
+    add(
+        &mut storage,
+        Reading::new(book_id.clone(), ReadingEvent::Started),
+        20,
+    );
+    assert!(storage
+        .projected_finish_date(&book_id, start + Duration::days(21))
+        .is_none());
+
+    // 30 pages in 3 days = 10 pages/day, 270 pages left = 27 days
+    add(
+        &mut storage,
+        Reading::with_metadata(book_id.clone(), ReadingEvent::Update, 30),
+        23,
+    );
+    assert_eq!(
+        storage.projected_finish_date(&book_id, start + Duration::days(23)),
+        Some(start + Duration::days(50))
+    );
+}
+
+#[test]
+fn test_books_added_in_year_across_year_boundary() {
+    let mut storage = Storage::new();
+
+    let mut late_book = Book::new(
+        "New Year's Eve".to_string(),
+        "111".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        100,
+    );
+    late_book.added_on = Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap();
+    storage.add_book(late_book);
+
+    let mut early_book = Book::new(
+        "New Year's Day".to_string(),
+        "222".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        100,
+    );
+    early_book.added_on = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    storage.add_book(early_book);
+
+    let added_2024 = storage.books_added_in_year(2024, &Timezone::Utc);
+    assert_eq!(added_2024.len(), 1);
+    assert_eq!(added_2024[0].title, "New Year's Eve");
+
+    let added_2025 = storage.books_added_in_year(2025, &Timezone::Utc);
+    assert_eq!(added_2025.len(), 1);
+    assert_eq!(added_2025[0].title, "New Year's Day");
+
+    assert!(storage.books_added_in_year(2023, &Timezone::Utc).is_empty());
+    assert_eq!(storage.get_earliest_added_year(&Timezone::Utc), Some(2024));
+}
+
+#[test]
+fn test_recent_readings_sorted_newest_first_and_limited() {
+    let mut storage = Storage::new();
+    let base = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+
+    for (days, event) in [
+        (0, ReadingEvent::Bought),
+        (5, ReadingEvent::Finished),
+        (2, ReadingEvent::Started),
+        (3, ReadingEvent::Update),
+    ] {
+        let mut reading = Reading::new("book".to_string(), event);
+        reading.created_on = base + Duration::days(days);
+        storage.add_reading(reading);
+    }
+
+    let recent = storage.recent_readings(3);
+    let events: Vec<ReadingEvent> = recent.iter().map(|r| r.event).collect();
+    assert_eq!(
+        events,
+        vec![
+            ReadingEvent::Finished,
+            ReadingEvent::Update,
+            ReadingEvent::Started
+        ]
+    );
+
+    assert_eq!(storage.recent_readings(10).len(), 4);
+    assert!(storage.recent_readings(0).is_empty());
+}
+
+#[test]
+fn test_finished_counts_by_category() {
+    let mut storage = Storage::new();
+
+    let fiction = Category::new("Fiction".to_string(), None);
+    let fiction_id = fiction.id.clone();
+    storage.add_category(fiction);
+    let history = Category::new("History".to_string(), None);
+    let history_id = history.id.clone();
+    storage.add_category(history);
+
+    let in_2024 = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+    let in_2025 = Utc.with_ymd_and_hms(2025, 5, 1, 12, 0, 0).unwrap();
+
+    let mut finish = |category_id: &str, finished_on| {
+        let book = Book::new(
+            "Book".to_string(),
+            "isbn".to_string(),
+            category_id.to_string(),
+            "author".to_string(),
+            100,
+        );
+        let mut reading = Reading::new(book.id.clone(), ReadingEvent::Finished);
+        reading.created_on = finished_on;
+        storage.add_book(book);
+        storage.add_reading(reading);
+    };
+
+    finish(&fiction_id, in_2025);
+    finish(&fiction_id, in_2025);
+    finish(&history_id, in_2025);
+    finish(&history_id, in_2024);
+    // Category was deleted
+    finish("deleted-category", in_2025);
+
+    assert_eq!(
+        storage.finished_counts_by_category(Some(2025), &Timezone::Utc),
+        vec![
+            ("Fiction".to_string(), 2),
+            ("History".to_string(), 1),
+            ("Uncategorized".to_string(), 1),
+        ]
+    );
+    assert_eq!(
+        storage.finished_counts_by_category(None, &Timezone::Utc),
+        vec![
+            ("Fiction".to_string(), 2),
+            ("History".to_string(), 2),
+            ("Uncategorized".to_string(), 1),
+        ]
+    );
+    assert!(storage
+        .finished_counts_by_category(Some(2023), &Timezone::Utc)
+        .is_empty());
+}
+
+#[test]
+fn test_compare_positions_suffixes() {
+    use bookmon::storage::compare_positions;
+    use std::cmp::Ordering;
+
+    assert_eq!(compare_positions(Some("10"), Some("9")), Ordering::Greater);
+    assert_eq!(compare_positions(Some("2"), Some("2a")), Ordering::Less);
+    assert_eq!(compare_positions(Some("2A"), Some("2b")), Ordering::Less);
+    // Unrecognised positions sort after recognised ones
+    assert_eq!(
+        compare_positions(Some("Prequel"), Some("99")),
+        Ordering::Greater
+    );
+    assert_eq!(compare_positions(Some("c"), Some("1")), Ordering::Greater);
+    assert_eq!(compare_positions(Some("Book X"), Some("c")), Ordering::Less);
+    assert_eq!(compare_positions(Some("Prequel"), None), Ordering::Less);
+}
+
+/// Adds a finished reading at noon UTC on the given date.
+fn add_finished_on(storage: &mut Storage, book_id: &str, year: i32, month: u32, day: u32) {
+    storage.add_reading(Reading {
+        id: Uuid::new_v4().to_string(),
+        created_on: Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap(),
+        book_id: book_id.to_string(),
+        event: ReadingEvent::Finished,
+        metadata: ReadingMetadata::default(),
+        edition_id: None,
+    });
+}
+
+#[test]
+fn test_finished_count_through_day_of_year_compares_years() {
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Book".to_string(),
+        "123".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        100,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+
+    // 2023: two books by early March, one later in the year
+    add_finished_on(&mut storage, &book_id, 2023, 1, 15);
+    add_finished_on(&mut storage, &book_id, 2023, 3, 1);
+    add_finished_on(&mut storage, &book_id, 2023, 10, 1);
+    // 2024 (leap year): three books by Feb 29, one on Mar 1
+    add_finished_on(&mut storage, &book_id, 2024, 1, 10);
+    add_finished_on(&mut storage, &book_id, 2024, 2, 1);
+    add_finished_on(&mut storage, &book_id, 2024, 2, 29);
+    add_finished_on(&mut storage, &book_id, 2024, 3, 1);
+
+    // Mar 1 is day 60 in 2023 but day 61 in 2024
+    assert_eq!(
+        storage.finished_count_through_day_of_year(2023, 60, &Timezone::Utc),
+        2
+    );
+    assert_eq!(
+        storage.finished_count_through_day_of_year(2024, 61, &Timezone::Utc),
+        4
+    );
+    // Day 60 of 2024 is Feb 29
+    assert_eq!(
+        storage.finished_count_through_day_of_year(2024, 60, &Timezone::Utc),
+        3
+    );
+    // Counting past the end of a non-leap year covers the whole year
+    assert_eq!(
+        storage.finished_count_through_day_of_year(2023, 366, &Timezone::Utc),
+        3
+    );
+    assert_eq!(
+        storage.finished_count_through_day_of_year(2022, 366, &Timezone::Utc),
+        0
+    );
+}
+
+#[test]
+fn test_year_grouping_follows_timezone() {
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Late Night".to_string(),
+        "123".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        100,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+    // 23:30 UTC on New Year's Eve is already 2025 in Oslo
+    storage.add_reading(Reading {
+        id: Uuid::new_v4().to_string(),
+        created_on: Utc.with_ymd_and_hms(2024, 12, 31, 23, 30, 0).unwrap(),
+        book_id,
+        event: ReadingEvent::Finished,
+        metadata: ReadingMetadata::default(),
+        edition_id: None,
+    });
+
+    let oslo = bookmon::time::parse_timezone("Europe/Oslo").unwrap();
+    assert_eq!(
+        storage
+            .get_books_finished_in_year(2024, &Timezone::Utc)
+            .len(),
+        1
+    );
+    assert!(storage.get_books_finished_in_year(2024, &oslo).is_empty());
+    assert_eq!(storage.get_books_finished_in_year(2025, &oslo).len(), 1);
+    assert_eq!(storage.get_earliest_finished_year(&oslo), Some(2025));
+    assert_eq!(
+        storage.finished_count_through_day_of_year(2025, 1, &oslo),
+        1
+    );
+}
+
+#[test]
+fn test_books_missing_pages() {
+    let mut storage = Storage::new();
+    for (title, pages) in [
+        ("Zero", 0),
+        ("Complete", 320),
+        ("Negative", -1),
+        ("Another Zero", 0),
+    ] {
+        storage.add_book(Book::new(
+            title.to_string(),
+            title.to_lowercase(),
+            "category".to_string(),
+            "author".to_string(),
+            pages,
+        ));
+    }
+
+    let titles: Vec<&str> = storage
+        .books_missing_pages()
+        .iter()
+        .map(|b| b.title.as_str())
+        .collect();
+    assert_eq!(titles, vec!["Another Zero", "Negative", "Zero"]);
+}
+
+#[test]
+fn test_books_missing_pages_empty_when_all_have_pages() {
+    let mut storage = Storage::new();
+    storage.add_book(Book::new(
+        "Complete".to_string(),
+        "123".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        200,
+    ));
+    assert!(storage.books_missing_pages().is_empty());
+}
+
+#[test]
+fn test_reading_event_display_from_str_round_trip() {
+    for event in ReadingEvent::ALL {
+        let name = event.to_string();
+        assert_eq!(name.parse::<ReadingEvent>(), Ok(event), "{}", name);
+    }
+    assert_eq!(ReadingEvent::WantToRead.to_string(), "want-to-read");
+    assert_eq!(
+        ReadingEvent::UnmarkedAsWantToRead.to_string(),
+        "unmarked-as-want-to-read"
+    );
+}
+
+#[test]
+fn test_reading_event_from_str_is_lenient_about_case_and_separators() {
+    assert_eq!(
+        "Finished".parse::<ReadingEvent>(),
+        Ok(ReadingEvent::Finished)
+    );
+    assert_eq!(
+        "WantToRead".parse::<ReadingEvent>(),
+        Ok(ReadingEvent::WantToRead)
+    );
+    assert_eq!(
+        "want to read".parse::<ReadingEvent>(),
+        Ok(ReadingEvent::WantToRead)
+    );
+}
+
+#[test]
+fn test_reading_event_from_str_rejects_unknown() {
+    let err = "reading".parse::<ReadingEvent>().unwrap_err();
+    assert!(err.starts_with("Unknown event type 'reading'"));
+    assert!(err.contains("want-to-read"));
+}
+
+/// Adds a book with a fixed ID and returns the storage.
+fn add_book_with_id(storage: &mut Storage, id: &str, title: &str, isbn: &str) {
+    let mut book = Book::new(
+        title.to_string(),
+        isbn.to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        100,
+    );
+    book.id = id.to_string();
+    storage.add_book(book);
+}
+
+fn create_ref_storage() -> Storage {
+    let mut storage = Storage::new();
+    add_book_with_id(
+        &mut storage,
+        "a1b2c3d4-0000-4000-8000-000000000001",
+        "Dune",
+        "978-0-441-17271-9",
+    );
+    add_book_with_id(
+        &mut storage,
+        "a1b2ffff-0000-4000-8000-000000000002",
+        "Emma",
+        "9780141439587",
+    );
+    add_book_with_id(
+        &mut storage,
+        "b7e8f9a0-0000-4000-8000-000000000003",
+        "Emma",
+        "9780199535521",
+    );
+    storage
+}
+
+#[test]
+fn test_resolve_book_ref_by_full_id_and_unique_prefix() {
+    let storage = create_ref_storage();
+
+    let book = storage
+        .resolve_book_ref("a1b2c3d4-0000-4000-8000-000000000001")
+        .unwrap();
+    assert_eq!(book.title, "Dune");
+    assert_eq!(storage.resolve_book_ref("a1b2c").unwrap().title, "Dune");
+    assert_eq!(
+        storage.resolve_book_ref("b7e8").unwrap().isbn,
+        "9780199535521"
+    );
+}
+
+#[test]
+fn test_resolve_book_ref_by_isbn_and_title() {
+    let storage = create_ref_storage();
+
+    assert_eq!(
+        storage.resolve_book_ref("9780441172719").unwrap().title,
+        "Dune"
+    );
+    assert_eq!(
+        storage.resolve_book_ref("978-0-14-143958-7").unwrap().id,
+        "a1b2ffff-0000-4000-8000-000000000002"
+    );
+    assert_eq!(
+        storage.resolve_book_ref("dune").unwrap().isbn,
+        "978-0-441-17271-9"
+    );
+}
+
+#[test]
+fn test_resolve_book_ref_ambiguous_lists_candidates() {
+    let storage = create_ref_storage();
+
+    let err = storage.resolve_book_ref("a1b2").unwrap_err();
+    assert!(err.contains("matches 2 books"), "{}", err);
+    assert!(err.contains("a1b2c3d4 \"Dune\""), "{}", err);
+    assert!(err.contains("a1b2ffff \"Emma\""), "{}", err);
+
+    let err = storage.resolve_book_ref("Emma").unwrap_err();
+    assert!(err.contains("9780141439587") && err.contains("9780199535521"));
+}
+
+#[test]
+fn test_resolve_book_ref_no_match() {
+    let storage = create_ref_storage();
+    assert_eq!(
+        storage.resolve_book_ref("Middlemarch").unwrap_err(),
+        "No book matches 'Middlemarch'"
+    );
+}
+
+#[test]
+fn test_finished_page_buckets() {
+    let mut storage = Storage::new();
+    let pages = [0, 120, 199, 200, 399, 400, 599, 600, 1200];
+    for (i, total_pages) in pages.iter().enumerate() {
+        let book = Book::new(
+            format!("Book {}", i),
+            format!("isbn-{}", i),
+            "category".to_string(),
+            "author".to_string(),
+            *total_pages,
+        );
+        storage.add_reading(Reading::new(book.id.clone(), ReadingEvent::Finished));
+        storage.add_book(book);
+    }
+    // An unfinished long book doesn't count
+    storage.add_book(Book::new(
+        "Unread".to_string(),
+        "isbn-unread".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        900,
+    ));
+
+    assert_eq!(
+        storage.finished_page_buckets(),
+        vec![
+            ("< 200".to_string(), 2),
+            ("200\u{2013}399".to_string(), 2),
+            ("400\u{2013}599".to_string(), 2),
+            ("600+".to_string(), 2),
+        ]
+    );
+}
+
+#[test]
+fn test_finished_page_buckets_empty_library() {
+    let storage = Storage::new();
+    let buckets = storage.finished_page_buckets();
+    assert_eq!(buckets.len(), 4);
+    assert!(buckets.iter().all(|(_, count)| *count == 0));
+}
+
+fn add_event(storage: &mut Storage, book_id: &str, event: ReadingEvent, days_ago: i64) -> String {
+    let mut reading = Reading::new(book_id.to_string(), event);
+    reading.created_on = Utc::now() - Duration::days(days_ago);
+    let id = reading.id.clone();
+    storage.add_reading(reading);
+    id
+}
+
+#[test]
+fn test_remove_finished_reading_restores_started_status() {
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Dune".to_string(),
+        "9780441172719".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        412,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+    add_event(&mut storage, &book_id, ReadingEvent::Started, 10);
+    let finished_id = add_event(&mut storage, &book_id, ReadingEvent::Finished, 2);
+    assert!(storage.is_book_finished(&book_id));
+
+    let removed = storage.remove_reading(&finished_id).unwrap();
+    assert_eq!(removed.event, ReadingEvent::Finished);
+    assert!(!storage.is_book_finished(&book_id));
+    assert!(storage.is_book_started(&book_id));
+    assert!(storage.get_finished_books().is_empty());
+    assert_eq!(storage.get_started_books().len(), 1);
+
+    assert!(storage.remove_reading(&finished_id).is_none());
+}
+
+#[test]
+fn test_remove_only_finished_reading_returns_book_to_backlog() {
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Emma".to_string(),
+        "9780141439587".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        474,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+    let finished_id = add_event(&mut storage, &book_id, ReadingEvent::Finished, 1);
+    assert!(storage.get_unstarted_books().is_empty());
+
+    storage.remove_reading(&finished_id);
+    assert_eq!(storage.get_unstarted_books().len(), 1);
+}
+
+#[test]
+fn test_resolve_reading_ref_by_prefix() {
+    let mut storage = Storage::new();
+    let mut first = Reading::new("book".to_string(), ReadingEvent::Started);
+    first.id = "c0ffee00-0000-4000-8000-000000000001".to_string();
+    let mut second = Reading::new("book".to_string(), ReadingEvent::Finished);
+    second.id = "c0ffee99-0000-4000-8000-000000000002".to_string();
+    storage.add_reading(first);
+    storage.add_reading(second);
+
+    assert_eq!(
+        storage.resolve_reading_ref("c0ffee9").unwrap().event,
+        ReadingEvent::Finished
+    );
+    assert_eq!(
+        storage
+            .resolve_reading_ref("c0ffee00-0000-4000-8000-000000000001")
+            .unwrap()
+            .event,
+        ReadingEvent::Started
+    );
+
+    let err = storage.resolve_reading_ref("c0ffee").unwrap_err();
+    assert!(err.contains("matches 2 events"), "{}", err);
+    assert!(
+        err.contains("c0ffee00") && err.contains("c0ffee99"),
+        "{}",
+        err
+    );
+    assert_eq!(
+        storage.resolve_reading_ref("dead").unwrap_err(),
+        "No reading event matches 'dead'"
+    );
+}
+
+#[test]
+fn test_finished_on_day_matches_month_and_day_across_years() {
+    let mut storage = Storage::new();
+    let finishes = [
+        ("Dune", Utc.with_ymd_and_hms(2021, 3, 14, 12, 0, 0).unwrap()),
+        ("Emma", Utc.with_ymd_and_hms(2023, 3, 14, 8, 0, 0).unwrap()),
+        (
+            "Beloved",
+            Utc.with_ymd_and_hms(2023, 3, 14, 20, 0, 0).unwrap(),
+        ),
+        (
+            "Kindred",
+            Utc.with_ymd_and_hms(2022, 3, 15, 12, 0, 0).unwrap(),
+        ),
+        (
+            "Solaris",
+            Utc.with_ymd_and_hms(2022, 4, 14, 12, 0, 0).unwrap(),
+        ),
+    ];
+    for (title, finished_on) in finishes {
+        let book = Book::new(
+            title.to_string(),
+            format!("isbn-{}", title),
+            "category".to_string(),
+            "author".to_string(),
+            100,
+        );
+        let mut reading = Reading::new(book.id.clone(), ReadingEvent::Finished);
+        reading.created_on = finished_on;
+        storage.add_reading(reading);
+        storage.add_book(book);
+    }
+
+    let found: Vec<(&str, i32)> = storage
+        .finished_on_day(3, 14, &Timezone::Utc)
+        .into_iter()
+        .map(|(book, year)| (book.title.as_str(), year))
+        .collect();
+    assert_eq!(
+        found,
+        vec![("Beloved", 2023), ("Emma", 2023), ("Dune", 2021)]
+    );
+    assert!(storage.finished_on_day(12, 24, &Timezone::Utc).is_empty());
+}
+
+#[test]
+fn test_finished_on_day_uses_timezone() {
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Late Night".to_string(),
+        "123".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        100,
+    );
+    // 23:30 UTC on March 14 is already March 15 in Oslo
+    let mut reading = Reading::new(book.id.clone(), ReadingEvent::Finished);
+    reading.created_on = Utc.with_ymd_and_hms(2022, 3, 14, 23, 30, 0).unwrap();
+    storage.add_reading(reading);
+    storage.add_book(book);
+
+    let oslo = bookmon::time::parse_timezone("Europe/Oslo").unwrap();
+    assert_eq!(storage.finished_on_day(3, 14, &Timezone::Utc).len(), 1);
+    assert!(storage.finished_on_day(3, 14, &oslo).is_empty());
+    assert_eq!(storage.finished_on_day(3, 15, &oslo)[0].1, 2022);
+}
+
+#[test]
+fn test_mark_books_finished_marks_each_book_once() {
+    let mut storage = Storage::new();
+    let mut ids = Vec::new();
+    for title in ["Dune", "Emma", "Beloved"] {
+        let book = Book::new(
+            title.to_string(),
+            format!("isbn-{}", title),
+            "category".to_string(),
+            "author".to_string(),
+            100,
+        );
+        add_event(&mut storage, &book.id, ReadingEvent::Started, 5);
+        ids.push(book.id.clone());
+        storage.add_book(book);
+    }
+    // Beloved was already finished
+    add_event(&mut storage, &ids[2], ReadingEvent::Finished, 1);
+
+    let selected = vec![
+        ids[0].clone(),
+        ids[1].clone(),
+        ids[0].clone(),
+        ids[2].clone(),
+        "no-such-book".to_string(),
+    ];
+    assert_eq!(storage.mark_books_finished(&selected), 2);
+
+    assert!(ids.iter().all(|id| storage.is_book_finished(id)));
+    assert!(storage.get_started_books().is_empty());
+    let finished_events = storage
+        .readings
+        .values()
+        .filter(|r| r.event == ReadingEvent::Finished)
+        .count();
+    assert_eq!(finished_events, 3);
+}
+
+#[test]
+fn test_mark_books_finished_with_no_ids() {
+    let mut storage = Storage::new();
+    assert_eq!(storage.mark_books_finished(&[]), 0);
+    assert!(storage.readings.is_empty());
+}
+
+/// A book logged after the fact: its only event is a `Finished` dated years ago.
+fn storage_with_backdated_finish() -> (Storage, String) {
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "The Left Hand of Darkness".to_string(),
+        "9780441478125".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        304,
+    );
+    let book_id = book.id.clone();
+    storage.add_book(book);
+    let mut finished = Reading::new(book_id.clone(), ReadingEvent::Finished);
+    finished.created_on = Utc.with_ymd_and_hms(2019, 8, 20, 18, 0, 0).unwrap();
+    storage.add_reading(finished);
+    (storage, book_id)
+}
+
+#[test]
+fn test_backdated_finish_without_start_counts_as_finished() {
+    let (storage, book_id) = storage_with_backdated_finish();
+
+    assert!(storage.is_book_finished(&book_id));
+    assert_eq!(storage.get_finished_books().len(), 1);
+    assert_eq!(
+        storage
+            .get_books_finished_in_year(2019, &Timezone::Utc)
+            .len(),
+        1
+    );
+    assert!(storage
+        .get_books_finished_in_year(Utc::now().year(), &Timezone::Utc)
+        .is_empty());
+    assert_eq!(
+        storage.get_earliest_finished_year(&Timezone::Utc),
+        Some(2019)
+    );
+    assert_eq!(
+        storage.finished_counts_by_category(Some(2019), &Timezone::Utc)[0].1,
+        1
+    );
+    let from = Utc.with_ymd_and_hms(2019, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2019, 12, 31, 23, 59, 59).unwrap();
+    assert_eq!(storage.get_read_books_by_time_period(from, to).len(), 1);
+}
+
+#[test]
+fn test_backdated_finish_without_start_is_never_in_progress() {
+    let (storage, book_id) = storage_with_backdated_finish();
+
+    assert!(!storage.is_book_started(&book_id));
+    assert!(storage.get_started_books().is_empty());
+    assert!(storage
+        .get_currently_reading_and_want_to_read_books()
+        .is_empty());
+    assert!(storage.get_unstarted_books().is_empty());
+    assert_eq!(storage.reading_velocity(&book_id), None);
+    assert_eq!(storage.projected_finish_date(&book_id, Utc::now()), None);
+    assert!(bookmon::reading::build_started_books_table(&storage)
+        .unwrap()
+        .is_empty());
+}
+
+fn add_book_by(storage: &mut Storage, title: &str, author_id: &str, category_id: &str) -> String {
+    let book = Book::new(
+        title.to_string(),
+        format!("isbn-{}", title),
+        category_id.to_string(),
+        author_id.to_string(),
+        300,
+    );
+    let id = book.id.clone();
+    storage.add_book(book);
+    id
+}
+
+#[test]
+fn test_recommendations_rank_author_match_above_category_match() {
+    let mut storage = Storage::new();
+    let read = add_book_by(&mut storage, "Read", "author-1", "fantasy");
+    add_event(&mut storage, &read, ReadingEvent::Finished, 1);
+    add_book_by(&mut storage, "A Same Category", "author-2", "fantasy");
+    add_book_by(&mut storage, "Z Same Author", "author-1", "poetry");
+    add_book_by(&mut storage, "Same Both", "author-1", "fantasy");
+    add_book_by(&mut storage, "Unrelated", "author-3", "poetry");
+
+    let titles: Vec<&str> = storage
+        .recommendations_for(&read, 10)
+        .iter()
+        .map(|b| b.title.as_str())
+        .collect();
+    assert_eq!(
+        titles,
+        vec!["Same Both", "Z Same Author", "A Same Category"]
+    );
+
+    let titles: Vec<&str> = storage
+        .recommendations_for(&read, 1)
+        .iter()
+        .map(|b| b.title.as_str())
+        .collect();
+    assert_eq!(titles, vec!["Same Both"]);
+}
+
+#[test]
+fn test_recommendations_exclude_started_and_finished_books() {
+    let mut storage = Storage::new();
+    let read = add_book_by(&mut storage, "Read", "author-1", "fantasy");
+    add_event(&mut storage, &read, ReadingEvent::Finished, 1);
+    let started = add_book_by(&mut storage, "Started", "author-1", "fantasy");
+    add_event(&mut storage, &started, ReadingEvent::Started, 2);
+    let finished = add_book_by(&mut storage, "Finished", "author-1", "fantasy");
+    add_event(&mut storage, &finished, ReadingEvent::Finished, 3);
+    let bought = add_book_by(&mut storage, "Bought", "author-1", "fantasy");
+    add_event(&mut storage, &bought, ReadingEvent::Bought, 4);
+
+    let titles: Vec<&str> = storage
+        .recommendations_for(&read, 10)
+        .iter()
+        .map(|b| b.title.as_str())
+        .collect();
+    assert_eq!(titles, vec!["Bought"]);
+    assert!(storage.recommendations_for("missing", 10).is_empty());
+}
+
+#[test]
+fn test_book_source_round_trips_and_defaults_to_none() {
+    let mut book = Book::new(
+        "Matilda".to_string(),
+        "9780142410370".to_string(),
+        "cat".to_string(),
+        "author".to_string(),
+        240,
+    );
+    assert_eq!(book.source, None);
+    // Unset sources are left out of the file
+    assert!(serde_json::to_value(&book).unwrap().get("source").is_none());
+
+    book.source = Some("openlibrary".to_string());
+    let json = serde_json::to_string(&book).unwrap();
+    let loaded: Book = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.source.as_deref(), Some("openlibrary"));
+
+    // Books saved before the field existed load without a source
+    let mut value = serde_json::to_value(&book).unwrap();
+    value.as_object_mut().unwrap().remove("source");
+    let old: Book = serde_json::from_value(value).unwrap();
+    assert_eq!(old.source, None);
+}
+
+#[test]
+fn test_total_pages_remaining_mixes_books_with_and_without_progress() {
+    let mut storage = Storage::new();
+    let with_progress = add_book_by(&mut storage, "With progress", "a", "c");
+    let without_progress = add_book_by(&mut storage, "Without progress", "a", "c");
+    let no_page_count = add_book_by(&mut storage, "No page count", "a", "c");
+    storage.books.get_mut(&no_page_count).unwrap().total_pages = 0;
+    let finished = add_book_by(&mut storage, "Finished", "a", "c");
+    add_book_by(&mut storage, "Unstarted", "a", "c");
+
+    add_event(&mut storage, &with_progress, ReadingEvent::Started, 10);
+    let mut update = Reading::with_metadata(with_progress.clone(), ReadingEvent::Update, 120);
+    update.created_on = Utc::now() - Duration::days(2);
+    storage.add_reading(update);
+    add_event(&mut storage, &without_progress, ReadingEvent::Started, 5);
+    add_event(&mut storage, &no_page_count, ReadingEvent::Started, 5);
+    add_event(&mut storage, &finished, ReadingEvent::Started, 20);
+    add_event(&mut storage, &finished, ReadingEvent::Finished, 15);
+
+    // All books have 300 pages: 180 left in the first, all 300 in the second
+    assert_eq!(storage.total_pages_remaining(), 180 + 300);
+}
+
+#[test]
+fn test_total_pages_remaining_ignores_progress_from_an_earlier_read() {
+    let mut storage = Storage::new();
+    let book = add_book_by(&mut storage, "Reread", "a", "c");
+    add_event(&mut storage, &book, ReadingEvent::Started, 30);
+    let mut update = Reading::with_metadata(book.clone(), ReadingEvent::Update, 250);
+    update.created_on = Utc::now() - Duration::days(25);
+    storage.add_reading(update);
+    add_event(&mut storage, &book, ReadingEvent::Finished, 20);
+    add_event(&mut storage, &book, ReadingEvent::Started, 1);
+
+    assert_eq!(storage.total_pages_remaining(), 300);
+    assert_eq!(Storage::new().total_pages_remaining(), 0);
+}
+
+#[test]
+fn test_series_overfull_flags_series_with_more_books_than_total() {
+    let mut storage = Storage::new();
+    let author = Author::new("Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    let mut overfull = Series::new("Overfull".to_string());
+    overfull.total_books = Some(1);
+    let overfull_id = overfull.id.clone();
+    storage.add_series(overfull);
+    let mut normal = Series::new("Normal".to_string());
+    normal.total_books = Some(3);
+    let normal_id = normal.id.clone();
+    storage.add_series(normal);
+
+    for series_id in [&overfull_id, &overfull_id, &normal_id, &normal_id] {
+        let book_id = add_book_by(&mut storage, "Book", &author_id, &category_id);
+        storage.books.get_mut(&book_id).unwrap().series_id = Some(series_id.clone());
+    }
+
+    let names: Vec<&str> = storage
+        .series_overfull()
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["Overfull"]);
+    let issues = storage.integrity_issues();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, IntegrityIssueKind::OverfullSeries);
+    assert_eq!(
+        issues[0].detail,
+        "Series 'Overfull' has 2 books but total_books is 1"
+    );
+}
+
+#[test]
+fn test_series_overfull_ignores_full_and_open_ended_series() {
+    let mut storage = Storage::new();
+    let author = Author::new("Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    let mut complete = Series::new("Complete".to_string());
+    complete.total_books = Some(1);
+    let complete_id = complete.id.clone();
+    storage.add_series(complete);
+    let open_ended = Series::new("Open ended".to_string());
+    let open_ended_id = open_ended.id.clone();
+    storage.add_series(open_ended);
+
+    for series_id in [&complete_id, &open_ended_id, &open_ended_id] {
+        let book_id = add_book_by(&mut storage, "Book", &author_id, &category_id);
+        storage.books.get_mut(&book_id).unwrap().series_id = Some(series_id.clone());
+    }
+
+    assert!(storage.series_overfull().is_empty());
+    assert!(storage.integrity_issues().is_empty());
+}
+
+#[test]
+fn test_reading_price_metadata_round_trips_and_stays_optional() {
+    let reading = Reading::with_price("book".to_string(), 249.0, Some("NOK".to_string()));
+    let json = serde_json::to_string(&reading).unwrap();
+    let parsed: Reading = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.metadata.price, Some(249.0));
+    assert_eq!(parsed.metadata.currency.as_deref(), Some("NOK"));
+
+    // Readings without a price don't gain new keys, and old files still load
+    let plain = serde_json::to_string(&Reading::new("book".to_string(), ReadingEvent::Bought));
+    assert!(!plain.unwrap().contains("price"));
+    let old: Reading = serde_json::from_str(
+        r#"{"id":"r1","created_on":"2024-01-01T00:00:00Z","book_id":"b1","event":"Bought","metadata":{"current_page":null}}"#,
+    )
+    .unwrap();
+    assert_eq!(old.metadata.price, None);
+    assert_eq!(old.metadata.currency, None);
+}
+
+#[test]
+fn test_spending_is_summed_per_currency() {
+    let mut storage = Storage::new();
+    for (price, currency, year) in [
+        (200.0, Some("NOK"), 2024),
+        (49.5, Some("NOK"), 2024),
+        (12.5, Some("USD"), 2024),
+        (10.0, None, 2024),
+        (100.0, Some("NOK"), 2023),
+    ] {
+        let mut reading =
+            Reading::with_price("book".to_string(), price, currency.map(str::to_string));
+        reading.created_on = Utc.with_ymd_and_hms(year, 6, 1, 12, 0, 0).unwrap();
+        storage.add_reading(reading);
+    }
+    // Other events and Bought events without a price don't count
+    add_event(&mut storage, "book", ReadingEvent::Bought, 0);
+    add_event(&mut storage, "book", ReadingEvent::Finished, 0);
+
+    let spent_2024 = storage.spending_by_currency(Some(2024), &Timezone::Utc);
+    assert_eq!(
+        spent_2024.into_iter().collect::<Vec<_>>(),
+        vec![
+            ("".to_string(), 10.0),
+            ("NOK".to_string(), 249.5),
+            ("USD".to_string(), 12.5),
+        ]
+    );
+    assert_eq!(
+        storage.spending_by_currency(None, &Timezone::Utc)["NOK"],
+        349.5
+    );
+    assert_eq!(storage.total_spent(Some(2023), &Timezone::Utc), 100.0);
+    assert_eq!(storage.total_spent(None, &Timezone::Utc), 372.0);
+    assert_eq!(storage.total_spent(Some(2020), &Timezone::Utc), 0.0);
+}
+
+#[test]
+fn test_integrity_issue_json_for_dangling_reading() {
+    let mut storage = Storage::new();
+    let reading_id = add_event(&mut storage, "missing-book", ReadingEvent::Started, 0);
+
+    let json = serde_json::to_value(storage.integrity_issues()).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "kind": "dangling_reading",
+            "entity_id": reading_id,
+            "detail": "Reading event 'started' refers to missing book missing-book",
+        }])
+    );
+}
+
+#[test]
+fn test_repair_keys_rekeys_records_stored_under_the_wrong_key() {
+    let mut storage = Storage::new();
+    let book = Book::new(
+        "Piranesi".to_string(),
+        "9781635575637".to_string(),
+        "category".to_string(),
+        "author".to_string(),
+        272,
+    );
+    let book_id = book.id.clone();
+    // Keyed by ISBN, as a bad import would do.
+    storage.books.insert(book.isbn.clone(), book);
+    let reading = Reading::new(book_id.clone(), ReadingEvent::Started);
+    let reading_id = reading.id.clone();
+    storage.readings.insert("wrong".to_string(), reading);
+    add_event(&mut storage, &book_id, ReadingEvent::Update, 0);
+
+    assert!(storage.get_book(&book_id).is_none());
+    // Until the book is re-keyed its readings also look dangling.
+    let issues: Vec<_> = storage
+        .integrity_issues()
+        .into_iter()
+        .filter(|i| i.kind == IntegrityIssueKind::MismatchedKey)
+        .collect();
+    assert_eq!(issues.len(), 2);
+    assert!(issues.iter().any(|i| i.entity_id == book_id
+        && i.detail == format!("Book {} is stored under key 9781635575637", book_id)));
+
+    assert_eq!(storage.repair_keys(), 2);
+
+    assert_eq!(storage.get_book(&book_id).unwrap().title, "Piranesi");
+    assert!(storage.readings.contains_key(&reading_id));
+    assert_eq!(storage.readings.len(), 2);
+    assert!(storage.integrity_issues().is_empty());
+    assert_eq!(storage.repair_keys(), 0);
+}
+
+#[test]
+fn test_load_and_repair_storage_rekeys_books_without_prompting() {
+    let mut storage = Storage::new();
+    let author = Author::new("Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+    let book_id = add_book_by(&mut storage, "Piranesi", &author_id, &category_id);
+    add_event(&mut storage, &book_id, ReadingEvent::Started, 1);
+    // Keyed by ISBN, as a bad import would do.
+    let book = storage.books.remove(&book_id).unwrap();
+    storage.books.insert(book.isbn.clone(), book);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir
+        .path()
+        .join("storage.json")
+        .to_str()
+        .unwrap()
+        .to_string();
+    write_storage(&path, &storage).unwrap();
+
+    let prompter = SummaryPrompter::new(false);
+    let loaded =
+        load_and_repair_storage_with_mode(&path, &prompter, RepairMode::SummaryFirst).unwrap();
+
+    assert!(prompter.summary.borrow().is_empty());
+    assert_eq!(loaded.books.len(), 1);
+    assert_eq!(loaded.get_book(&book_id).unwrap().title, "Piranesi");
+    assert!(load_storage(&path).unwrap().books.contains_key(&book_id));
+}
+
+/// Adds an `Update` event with a page number `days_ago` days in the past.
+fn add_page_update(storage: &mut Storage, book_id: &str, page: i32, days_ago: i64) -> String {
+    let mut reading = Reading::with_metadata(book_id.to_string(), ReadingEvent::Update, page);
+    reading.created_on = Utc::now() - Duration::days(days_ago);
+    let id = reading.id.clone();
+    storage.add_reading(reading);
+    id
+}
+
+#[test]
+fn test_page_update_without_started_event_counts_as_started() {
+    let mut storage = Storage::new();
+    let book_id = add_book_by(&mut storage, "Piranesi", "author", "category");
+    add_page_update(&mut storage, &book_id, 40, 3);
+    add_page_update(&mut storage, &book_id, 90, 1);
+
+    assert!(storage.is_book_started(&book_id));
+    let started: Vec<&str> = storage
+        .get_started_books()
+        .iter()
+        .map(|b| b.title.as_str())
+        .collect();
+    assert_eq!(started, vec!["Piranesi"]);
+    assert!(storage.get_unstarted_books().is_empty());
+    assert_eq!(
+        storage.started_on(&book_id).unwrap().date_naive(),
+        (Utc::now() - Duration::days(3)).date_naive()
+    );
+}
+
+#[test]
+fn test_update_without_page_or_before_finishing_does_not_start_book() {
+    let mut storage = Storage::new();
+    let untouched = add_book_by(&mut storage, "Untouched", "author", "category");
+    add_event(&mut storage, &untouched, ReadingEvent::Update, 1);
+
+    let finished = add_book_by(&mut storage, "Finished", "author", "category");
+    add_page_update(&mut storage, &finished, 100, 5);
+    add_event(&mut storage, &finished, ReadingEvent::Finished, 2);
+
+    assert!(!storage.is_book_started(&untouched));
+    assert!(!storage.is_book_started(&finished));
+    assert!(storage.get_started_books().is_empty());
+    assert_eq!(storage.started_on(&finished), None);
+}
+
+#[test]
+fn test_backup_storage_copies_file_byte_for_byte() {
+    let dir = tempfile::tempdir().unwrap();
+    let storage_path = dir.path().join("storage.json");
+    let storage_path = storage_path.to_str().unwrap();
+    let mut storage = Storage::new();
+    add_book_by(&mut storage, "Piranesi", "author", "category");
+    write_storage(storage_path, &storage).unwrap();
+
+    let destination = dir.path().join("snapshots").join("before-merge.json");
+    bookmon::storage::backup_storage(storage_path, &destination).unwrap();
+
+    assert_eq!(
+        std::fs::read(&destination).unwrap(),
+        std::fs::read(storage_path).unwrap()
+    );
+    assert!(bookmon::storage::backup_storage(storage_path, Path::new(storage_path)).is_err());
+}
+
+#[test]
+fn test_default_backup_path_is_timestamped_next_to_storage() {
+    let now = chrono::NaiveDate::from_ymd_opt(2025, 3, 1)
+        .unwrap()
+        .and_hms_opt(9, 15, 0)
+        .unwrap();
+    assert_eq!(
+        bookmon::storage::default_backup_path("/data/books/storage.json", now),
+        Path::new("/data/books/storage-20250301-091500.json")
+    );
+}
+
+/// Creates a storage with one problem of each kind `detect_missing_fields` looks for.
+/// Returns the storage and the IDs of the book missing its author and the book
+/// missing its page count.
+fn storage_with_missing_fields() -> (Storage, String, String) {
+    let mut storage = Storage::new();
+    let author = Author::new("Ursula K. Le Guin".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    let no_author = add_book_by(&mut storage, "No Author", "missing-author", &category_id);
+    add_book_by(&mut storage, "No Category", &author_id, "missing-category");
+    let no_series = add_book_by(&mut storage, "No Series", &author_id, &category_id);
+    storage.books.get_mut(&no_series).unwrap().series_id = Some("missing-series".to_string());
+    let no_pages = add_book_by(&mut storage, "No Pages", &author_id, &category_id);
+    storage.books.get_mut(&no_pages).unwrap().total_pages = 0;
+
+    let mut reading = Reading::new("missing-book".to_string(), ReadingEvent::Started);
+    reading.id = "orphaned-reading".to_string();
+    storage.add_reading(reading);
+    (storage, no_author, no_pages)
+}
+
+/// Answers like `TestPrompter`, remembering the summary it was shown and
+/// optionally failing when asked for a page count.
+struct SummaryPrompter {
+    inner: TestPrompter,
+    fail_on_pages: bool,
+    summary: RefCell<Vec<MissingField>>,
+}
+
+impl SummaryPrompter {
+    fn new(fail_on_pages: bool) -> Self {
+        Self {
+            inner: TestPrompter::new("Repaired Author", "Repaired Category", 250),
+            fail_on_pages,
+            summary: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl RepairPrompter for SummaryPrompter {
+    fn prompt_author_name(&self, book_title: &str) -> Result<String, Box<dyn std::error::Error>> {
+        self.inner.prompt_author_name(book_title)
+    }
+
+    fn prompt_category_name(&self, book_title: &str) -> Result<String, Box<dyn std::error::Error>> {
+        self.inner.prompt_category_name(book_title)
+    }
+
+    fn prompt_total_pages(&self, book_title: &str) -> Result<i32, Box<dyn std::error::Error>> {
+        if self.fail_on_pages {
+            return Err("prompt cancelled".into());
+        }
+        self.inner.prompt_total_pages(book_title)
+    }
+
+    fn prompt_book_details(
+        &self,
+        reading_id: &str,
+    ) -> Result<BookRepairInput, Box<dyn std::error::Error>> {
+        self.inner.prompt_book_details(reading_id)
+    }
+
+    fn show_summary(&self, issues: &[MissingField]) {
+        self.summary.replace(issues.to_vec());
+    }
+}
+
+#[test]
+fn test_detect_missing_fields_finds_each_kind_of_problem() {
+    let (storage, _, _) = storage_with_missing_fields();
+
+    let described: Vec<String> = detect_missing_fields(&storage)
+        .iter()
+        .map(|issue| issue.describe())
+        .collect();
+    assert_eq!(
+        described,
+        vec![
+            "Book 'No Author' references a missing author",
+            "Book 'No Category' references a missing category",
+            "Book 'No Series' references a missing series (the link will be removed)",
+            "Book 'No Pages' is missing total pages",
+            "Reading orphaned-reading references missing book missing-book",
+        ]
+    );
+}
+
+#[test]
+fn test_detect_missing_fields_is_empty_for_consistent_storage() {
+    let mut storage = Storage::new();
+    let author = Author::new("Author".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+    let category = Category::new("Fiction".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+    let book_id = add_book_by(&mut storage, "Fine", &author_id, &category_id);
+    add_event(&mut storage, &book_id, ReadingEvent::Started, 1);
+
+    assert!(detect_missing_fields(&storage).is_empty());
+}
+
+#[test]
+fn test_summary_first_repair_shows_issues_then_fixes_and_saves_them() {
+    let tmp = tempfile::NamedTempFile::new().unwrap();
+    let path = tmp.path().to_str().unwrap().to_string();
+    let (mut storage, _, _) = storage_with_missing_fields();
+    let expected = detect_missing_fields(&storage);
+
+    let prompter = SummaryPrompter::new(false);
+    handle_missing_fields_with_mode(&mut storage, &path, &prompter, RepairMode::SummaryFirst)
+        .unwrap();
+
+    assert_eq!(*prompter.summary.borrow(), expected);
+    assert!(detect_missing_fields(&storage).is_empty());
+    let saved = bookmon::storage::load_storage(&path).unwrap();
+    assert!(detect_missing_fields(&saved).is_empty());
+}
+
+#[test]
+fn test_summary_first_repair_saves_earlier_fixes_when_a_prompt_fails() {
+    let tmp = tempfile::NamedTempFile::new().unwrap();
+    let path = tmp.path().to_str().unwrap().to_string();
+    let (mut storage, no_author, no_pages) = storage_with_missing_fields();
+
+    let prompter = SummaryPrompter::new(true);
+    let result =
+        handle_missing_fields_with_mode(&mut storage, &path, &prompter, RepairMode::SummaryFirst);
+    assert!(result.is_err());
+
+    let saved = bookmon::storage::load_storage(&path).unwrap();
+    let author_id = &saved.books[&no_author].author_id;
+    assert_eq!(saved.authors[author_id].name, "Repaired Author");
+    assert_eq!(saved.books[&no_pages].total_pages, 0);
+}
+
+#[test]
+fn test_incremental_repair_does_not_show_a_summary() {
+    let tmp = tempfile::NamedTempFile::new().unwrap();
+    let path = tmp.path().to_str().unwrap().to_string();
+    let (mut storage, _, _) = storage_with_missing_fields();
+
+    let prompter = SummaryPrompter::new(false);
+    handle_missing_fields_with_mode(&mut storage, &path, &prompter, RepairMode::Incremental)
+        .unwrap();
+
+    assert!(prompter.summary.borrow().is_empty());
+    assert!(detect_missing_fields(&storage).is_empty());
+}
+
+#[test]
+fn test_finished_book_is_not_want_to_read_or_bought_when_events_are_out_of_order() {
+    let mut storage = Storage::new();
+    let book_id = add_book_by(&mut storage, "Finished Twice Over", "author", "category");
+    add_event(&mut storage, &book_id, ReadingEvent::Started, 10);
+    add_event(&mut storage, &book_id, ReadingEvent::Finished, 5);
+    add_event(&mut storage, &book_id, ReadingEvent::Bought, 3);
+    add_event(&mut storage, &book_id, ReadingEvent::WantToRead, 1);
+
+    assert!(storage.get_want_to_read_books().is_empty());
+    assert!(storage.get_bought_books().is_empty());
+    assert!(storage.get_unstarted_books().is_empty());
+    assert!(storage.is_book_finished(&book_id));
+    assert_eq!(storage.get_finished_books().len(), 1);
+}
+
+#[test]
+fn test_reread_after_finishing_is_not_finished() {
+    let mut storage = Storage::new();
+    let book_id = add_book_by(&mut storage, "Reread", "author", "category");
+    add_event(&mut storage, &book_id, ReadingEvent::Finished, 10);
+    add_event(&mut storage, &book_id, ReadingEvent::WantToRead, 5);
+    add_event(&mut storage, &book_id, ReadingEvent::Started, 1);
+
+    assert!(!storage.is_book_finished(&book_id));
+    assert!(storage.is_book_started(&book_id));
+}
+
+#[test]
+fn test_finished_wins_over_started_at_the_same_time() {
+    let mut storage = Storage::new();
+    let book_id = add_book_by(&mut storage, "Same Instant", "author", "category");
+    let at = Utc::now() - Duration::days(1);
+    for event in [ReadingEvent::Finished, ReadingEvent::Started] {
+        let mut reading = Reading::new(book_id.clone(), event);
+        reading.created_on = at;
+        storage.add_reading(reading);
+    }
+
+    assert!(storage.is_book_finished(&book_id));
+    assert!(!storage.is_book_started(&book_id));
+}
+
+#[test]
+fn test_bought_only_book_is_in_the_backlog() {
+    let mut storage = Storage::new();
+    let bought = add_book_by(&mut storage, "Bought", "author", "category");
+    add_event(&mut storage, &bought, ReadingEvent::Bought, 2);
+    let wanted = add_book_by(&mut storage, "Wanted", "author", "category");
+    add_event(&mut storage, &wanted, ReadingEvent::WantToRead, 1);
+
+    let mut backlog: Vec<&str> = storage
+        .get_unstarted_books()
+        .iter()
+        .map(|b| b.title.as_str())
+        .collect();
+    backlog.sort();
+    assert_eq!(backlog, vec!["Bought", "Wanted"]);
+    assert_eq!(storage.get_bought_books()[0].id, bought);
+    assert_eq!(storage.get_want_to_read_books()[0].id, wanted);
+}
+
+#[test]
+fn test_dnf_books_are_tallied_in_the_year_they_were_abandoned() {
+    let mut storage = Storage::new();
+    let abandoned = add_book_by(&mut storage, "Ulysses", "author", "category");
+    let started_on = Utc.with_ymd_and_hms(2024, 11, 20, 12, 0, 0).unwrap();
+    let abandoned_on = Utc.with_ymd_and_hms(2025, 2, 3, 12, 0, 0).unwrap();
+    for (event, at) in [
+        (ReadingEvent::Started, started_on),
+        (ReadingEvent::DidNotFinish, abandoned_on),
+    ] {
+        let mut reading = Reading::new(abandoned.clone(), event);
+        reading.created_on = at;
+        storage.add_reading(reading);
+    }
+    let finished = add_book_by(&mut storage, "Finished", "author", "category");
+    let mut reading = Reading::new(finished, ReadingEvent::Finished);
+    reading.created_on = abandoned_on;
+    storage.add_reading(reading);
+
+    let dnf: Vec<&str> = storage
+        .get_dnf_books_in_year(2025, &Timezone::Utc)
+        .iter()
+        .map(|b| b.title.as_str())
+        .collect();
+    assert_eq!(dnf, vec!["Ulysses"]);
+    assert!(storage
+        .get_dnf_books_in_year(2024, &Timezone::Utc)
+        .is_empty());
+    assert_eq!(
+        storage
+            .get_books_finished_in_year(2025, &Timezone::Utc)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_dnf_ends_the_read_without_finishing_the_book() {
+    let mut storage = Storage::new();
+    let book_id = add_book_by(&mut storage, "Abandoned", "author", "category");
+    add_event(&mut storage, &book_id, ReadingEvent::Started, 10);
+    add_event(&mut storage, &book_id, ReadingEvent::DidNotFinish, 2);
+
+    assert!(!storage.is_book_started(&book_id));
+    assert!(!storage.is_book_finished(&book_id));
+    assert!(storage.get_unstarted_books().is_empty());
+    assert_eq!(storage.started_on(&book_id), None);
+}
+
+#[test]
+fn test_dnf_parses_from_its_short_name() {
+    assert_eq!(
+        "dnf".parse::<ReadingEvent>(),
+        Ok(ReadingEvent::DidNotFinish)
+    );
+    assert_eq!(
+        "did-not-finish".parse::<ReadingEvent>(),
+        Ok(ReadingEvent::DidNotFinish)
+    );
+}
+
+/// Adds a Finished and a Started reading with the same timestamp, in the given
+/// order, with IDs that sort Finished first.
+fn storage_with_same_time_events(finished_first: bool) -> (Storage, String) {
+    let mut storage = Storage::new();
+    let book_id = add_book_by(&mut storage, "Bulk Imported", "author", "category");
+    let at = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+    let mut readings = vec![
+        ("reading-a", ReadingEvent::Finished),
+        ("reading-b", ReadingEvent::Started),
+    ];
+    if !finished_first {
+        readings.reverse();
+    }
+    for (id, event) in readings {
+        let mut reading = Reading::new(book_id.clone(), event);
+        reading.id = id.to_string();
+        reading.created_on = at;
+        storage.add_reading(reading);
+    }
+    (storage, book_id)
+}
+
+#[test]
+fn test_same_timestamp_events_are_ordered_by_id() {
+    for finished_first in [true, false] {
+        let (storage, book_id) = storage_with_same_time_events(finished_first);
+
+        // The greater ID counts as the most recent event...
+        let latest = storage.most_recent_event_for_book(&book_id).unwrap();
+        assert_eq!(latest.id, "reading-b");
+        assert_eq!(
+            storage.most_recent_reading_event(&book_id),
+            Some(ReadingEvent::Started)
+        );
+        let recent: Vec<&str> = storage
+            .readings_in_period(None, None)
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect();
+        assert_eq!(recent, vec!["reading-b", "reading-a"]);
+
+        // ...but for the reading status, finishing wins a tie
+        assert!(storage.is_book_finished(&book_id));
+        assert!(!storage.is_book_started(&book_id));
+    }
+}
+
+/// Adds a series with one book per title, in order, and returns the book IDs.
+fn add_series_books(storage: &mut Storage, name: &str, titles: &[&str]) -> Vec<String> {
+    let series = Series::new(name.to_string());
+    let series_id = series.id.clone();
+    storage.add_series(series);
+    titles
+        .iter()
+        .enumerate()
+        .map(|(i, title)| {
+            let id = add_book_by(storage, title, "author", "category");
+            let book = storage.books.get_mut(&id).unwrap();
+            book.series_id = Some(series_id.clone());
+            book.position_in_series = Some((i + 1).to_string());
+            id
+        })
+        .collect()
+}
+
+fn next_up_titles(storage: &Storage, limit: usize) -> Vec<&str> {
+    storage
+        .next_up(limit)
+        .iter()
+        .map(|b| b.title.as_str())
+        .collect()
+}
+
+#[test]
+fn test_next_up_blends_nearly_done_series_and_want_to_read() {
+    let mut storage = Storage::new();
+    // Books being read: 280/300 and 240/300 are nearly done, 30/300 isn't
+    for (title, page) in [("Almost", 280), ("Close", 240), ("Barely", 30)] {
+        let id = add_book_by(&mut storage, title, "author", "category");
+        add_event(&mut storage, &id, ReadingEvent::Started, 10);
+        add_page_update(&mut storage, &id, page, 1);
+    }
+    let discworld = add_series_books(&mut storage, "Discworld", &["Colour", "Light", "Sourcery"]);
+    add_event(&mut storage, &discworld[0], ReadingEvent::Finished, 20);
+    // A series nobody has started suggests nothing
+    add_series_books(&mut storage, "Untouched", &["Untouched 1"]);
+    let waited_longest = add_book_by(&mut storage, "Waited Longest", "author", "category");
+    add_event(&mut storage, &waited_longest, ReadingEvent::WantToRead, 30);
+    let recent = add_book_by(&mut storage, "Recently Wanted", "author", "category");
+    add_event(&mut storage, &recent, ReadingEvent::WantToRead, 2);
+
+    assert_eq!(
+        next_up_titles(&storage, 10),
+        vec![
+            "Almost",
+            "Close",
+            "Light",
+            "Waited Longest",
+            "Recently Wanted"
+        ]
+    );
+    assert_eq!(next_up_titles(&storage, 2), vec!["Almost", "Close"]);
+}
+
+#[test]
+fn test_next_up_lists_a_book_from_two_sources_once() {
+    let mut storage = Storage::new();
+    let books = add_series_books(&mut storage, "Earthsea", &["Wizard", "Tombs"]);
+    add_event(&mut storage, &books[0], ReadingEvent::Finished, 20);
+    add_event(&mut storage, &books[1], ReadingEvent::WantToRead, 5);
+    let other = add_book_by(&mut storage, "Other", "author", "category");
+    add_event(&mut storage, &other, ReadingEvent::WantToRead, 1);
+
+    assert_eq!(next_up_titles(&storage, 10), vec!["Tombs", "Other"]);
+}
+
+#[test]
+fn test_next_up_counts_page_updates_as_starting_series_books() {
+    let mut storage = Storage::new();
+    // Started only by logging a page, which still picks up the series
+    let dune = add_series_books(&mut storage, "Dune", &["Dune", "Messiah"]);
+    add_page_update(&mut storage, &dune[0], 30, 5);
+    // The next book is already being read through page updates
+    let foundation = add_series_books(&mut storage, "Foundation", &["Foundation", "Empire"]);
+    add_event(&mut storage, &foundation[0], ReadingEvent::Finished, 20);
+    add_page_update(&mut storage, &foundation[1], 30, 5);
+
+    assert_eq!(next_up_titles(&storage, 10), vec!["Messiah"]);
+}
+
+#[test]
+fn test_next_up_skips_abandoned_series_books() {
+    let mut storage = Storage::new();
+    let books = add_series_books(&mut storage, "Wheel", &["Eye", "Hunt", "Dragon"]);
+    add_event(&mut storage, &books[0], ReadingEvent::Finished, 20);
+    add_event(&mut storage, &books[1], ReadingEvent::DidNotFinish, 10);
+
+    assert_eq!(next_up_titles(&storage, 10), vec!["Dragon"]);
+}
+
+#[test]
+fn test_next_up_measures_wait_from_marking_as_want_to_read() {
+    let mut storage = Storage::new();
+    // Bought long ago, but only recently marked as want-to-read
+    let bought_first = add_book_by(&mut storage, "Bought First", "author", "category");
+    add_event(&mut storage, &bought_first, ReadingEvent::Bought, 60);
+    add_event(&mut storage, &bought_first, ReadingEvent::WantToRead, 5);
+    let wanted = add_book_by(&mut storage, "Wanted", "author", "category");
+    add_event(&mut storage, &wanted, ReadingEvent::WantToRead, 10);
+
+    assert_eq!(next_up_titles(&storage, 10), vec!["Wanted", "Bought First"]);
+}
+
+#[test]
+fn test_next_up_is_empty_without_candidates() {
+    let mut storage = Storage::new();
+    add_book_by(&mut storage, "Unread", "author", "category");
+    assert!(storage.next_up(5).is_empty());
+}
+
+#[test]
+fn test_reading_event_serializes_as_snake_case() {
+    let names: Vec<String> = ReadingEvent::ALL
+        .iter()
+        .map(|event| serde_json::to_string(event).unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "\"finished\"",
+            "\"started\"",
+            "\"update\"",
+            "\"bought\"",
+            "\"want_to_read\"",
+            "\"unmarked_as_want_to_read\"",
+            "\"did_not_finish\"",
+        ]
+    );
+}
+
+#[test]
+fn test_reading_event_deserializes_new_and_old_spellings() {
+    for event in ReadingEvent::ALL {
+        let new = serde_json::to_string(&event).unwrap();
+        let old = format!("\"{:?}\"", event);
+        assert_eq!(serde_json::from_str::<ReadingEvent>(&new).unwrap(), event);
+        assert_eq!(serde_json::from_str::<ReadingEvent>(&old).unwrap(), event);
+    }
+
+    let reading: Reading = serde_json::from_str(
+        r#"{"id":"r1","created_on":"2024-01-01T00:00:00Z","book_id":"b1","event":"WantToRead"}"#,
+    )
+    .unwrap();
+    assert_eq!(reading.event, ReadingEvent::WantToRead);
+    assert!(serde_json::to_string(&reading)
+        .unwrap()
+        .contains(r#""event":"want_to_read""#));
+}
@@ -0,0 +1,92 @@
+use bookmon::storage::{Author, Book, Category, Reading, ReadingEvent, Review, Series, Storage};
+use bookmon::summary::LibrarySummary;
+use chrono::{Duration, Utc};
+
+fn fixture() -> Storage {
+    let mut storage = Storage::new();
+
+    let author = Author::new("Ursula K. Le Guin".to_string());
+    let author_id = author.id.clone();
+    storage.add_author(author);
+
+    let category = Category::new("Fantasy".to_string(), None);
+    let category_id = category.id.clone();
+    storage.add_category(category);
+
+    storage.add_series(Series::new("Earthsea".to_string()));
+
+    let mut ids = Vec::new();
+    for (i, title) in [
+        "A Wizard of Earthsea",
+        "The Tombs of Atuan",
+        "The Farthest Shore",
+        "Tehanu",
+    ]
+    .iter()
+    .enumerate()
+    {
+        let book = Book::new(
+            title.to_string(),
+            format!("isbn-{}", i),
+            category_id.clone(),
+            author_id.clone(),
+            200,
+        );
+        ids.push(book.id.clone());
+        storage.add_book(book);
+    }
+
+    let now = Utc::now();
+    // Book 0: finished
+    let mut started = Reading::new(ids[0].clone(), ReadingEvent::Started);
+    started.created_on = now - Duration::days(10);
+    storage.add_reading(started);
+    let mut finished = Reading::new(ids[0].clone(), ReadingEvent::Finished);
+    finished.created_on = now - Duration::days(2);
+    storage.add_reading(finished);
+    // Book 1: reading
+    storage.add_reading(Reading::new(ids[1].clone(), ReadingEvent::Started));
+    // Book 2: want to read
+    storage.add_reading(Reading::new(ids[2].clone(), ReadingEvent::WantToRead));
+    // Book 3: no events
+
+    storage.add_review(Review::new(ids[0].clone(), "Wonderful".to_string()));
+
+    storage
+}
+
+#[test]
+fn test_summary_counts() {
+    let summary = LibrarySummary::from_storage(&fixture());
+
+    assert_eq!(summary.total, 4);
+    assert_eq!(summary.finished, 1);
+    assert_eq!(summary.reading, 1);
+    assert_eq!(summary.backlog, 2);
+    assert_eq!(summary.want_to_read, 1);
+    assert_eq!(summary.authors, 1);
+    assert_eq!(summary.series, 1);
+    assert_eq!(summary.reviews, 1);
+    // Book 1 is being read with no page recorded
+    assert_eq!(summary.pages_remaining, 200);
+}
+
+#[test]
+fn test_summary_porcelain_output() {
+    let summary = LibrarySummary::from_storage(&fixture());
+
+    assert_eq!(
+        summary.format_porcelain(),
+        "total=4\nfinished=1\nreading=1\nbacklog=2\nwant_to_read=1\nauthors=1\nseries=1\nreviews=1\npages_remaining=200"
+    );
+}
+
+#[test]
+fn test_summary_pretty_output() {
+    let summary = LibrarySummary::from_storage(&Storage::new());
+    let pretty = summary.format_pretty();
+
+    assert!(pretty.starts_with("Library summary\n"));
+    assert!(pretty.contains("  Books:         0"));
+    assert!(pretty.contains("  Want to read:  0"));
+}
@@ -1,4 +1,8 @@
-use bookmon::table::{format_structured_table, format_table, Alignment, TableRow};
+use bookmon::table::{
+    fit_to_width, format_bar_chart, format_structured_table, format_structured_table_fitted,
+    format_structured_table_styled, format_table, format_table_fitted, format_table_styled,
+    reverse_table_rows, Alignment, TableRow, TableStyle,
+};
 
 #[test]
 fn test_table_ascii_rows_are_aligned() {
@@ -624,3 +628,271 @@ fn test_format_table_with_alignments() {
         lines[3]
     );
 }
+
+// --- Fitting tables to the terminal width ---
+
+#[test]
+fn test_fit_to_width_leaves_fitting_tables_alone() {
+    // 10 + 20 + 3 borders = 33
+    assert_eq!(fit_to_width(&[10, 20], 33), vec![10, 20]);
+    assert_eq!(fit_to_width(&[10, 20], 80), vec![10, 20]);
+}
+
+#[test]
+fn test_fit_to_width_shrinks_widest_column_first() {
+    // Needs to lose 10 columns; only the widest one shrinks
+    assert_eq!(fit_to_width(&[10, 40, 12], 56), vec![10, 30, 12]);
+}
+
+#[test]
+fn test_fit_to_width_evens_out_wide_columns() {
+    // 30 + 26 + 8 + 4 borders = 68, target 50 => 18 to lose
+    let widths = fit_to_width(&[30, 26, 8], 50);
+    assert_eq!(widths.iter().sum::<usize>() + 4, 50);
+    assert_eq!(widths, vec![19, 19, 8]);
+}
+
+#[test]
+fn test_fit_to_width_respects_minimum_column_width() {
+    let widths = fit_to_width(&[20, 20, 3], 10);
+    assert_eq!(widths, vec![5, 5, 3]);
+}
+
+#[test]
+fn test_fitted_table_truncates_cells_with_ellipsis() {
+    let rows = vec![
+        vec!["Title".to_string(), "Author".to_string()],
+        vec![
+            "A Very Long Book Title That Goes On".to_string(),
+            "Someone".to_string(),
+        ],
+    ];
+    let output = format_table_fitted(&rows, &[], Some(30));
+
+    for line in output.lines() {
+        assert_eq!(
+            unicode_width::UnicodeWidthStr::width(line),
+            30,
+            "line: {}",
+            line
+        );
+    }
+    assert!(output.contains('\u{2026}'));
+    assert!(output.contains("Someone"));
+}
+
+#[test]
+fn test_fitted_structured_table_lines_share_width() {
+    let rows = vec![
+        TableRow::Header(vec!["Title".to_string(), "Author".to_string()]),
+        TableRow::GroupHeader("An Extremely Long Series Name Indeed".to_string(), 1),
+        TableRow::Data(vec![
+            "A Rather Long Title For A Book".to_string(),
+            "An Author".to_string(),
+        ]),
+    ];
+    let output = format_structured_table_fitted(&rows, &[], Some(32));
+
+    for line in output.lines() {
+        assert_eq!(
+            unicode_width::UnicodeWidthStr::width(line),
+            32,
+            "line: {}",
+            line
+        );
+    }
+}
+
+#[test]
+fn test_unfitted_table_is_unchanged() {
+    let rows = vec![
+        vec!["Title".to_string()],
+        vec!["A Very Long Book Title That Goes On".to_string()],
+    ];
+    assert_eq!(
+        format_table_fitted(&rows, &[], None),
+        format_table(&rows, &[])
+    );
+}
+
+#[test]
+fn test_format_bar_chart_scales_to_largest_count() {
+    let entries = vec![
+        ("< 200".to_string(), 2),
+        ("200\u{2013}399".to_string(), 4),
+        ("600+".to_string(), 0),
+    ];
+    let chart = format_bar_chart(&entries, 10);
+    let lines: Vec<&str> = chart.lines().collect();
+
+    assert_eq!(
+        lines[0],
+        "    < 200 | \u{2588}\u{2588}\u{2588}\u{2588}\u{2588} 2"
+    );
+    assert_eq!(
+        lines[1],
+        format!("  200\u{2013}399 | {} 4", "\u{2588}".repeat(10))
+    );
+    assert_eq!(lines[2], "     600+ | 0");
+}
+
+fn style_rows() -> Vec<Vec<String>> {
+    vec![
+        vec!["Title".to_string(), "Pages".to_string()],
+        vec!["Dune".to_string(), "412".to_string()],
+        vec!["Ørkenen | II".to_string(), "98".to_string()],
+    ]
+}
+
+#[test]
+fn test_boxed_style_is_the_default_table() {
+    let rows = style_rows();
+    assert_eq!(TableStyle::default(), TableStyle::Boxed);
+    assert_eq!(
+        format_table_styled(&rows, &[], TableStyle::Boxed),
+        format_table(&rows, &[])
+    );
+}
+
+#[test]
+fn test_plain_style_has_aligned_columns_without_borders() {
+    let alignments = [Alignment::Left, Alignment::Right];
+    let output = format_table_styled(&style_rows(), &alignments, TableStyle::Plain);
+    assert_eq!(
+        output,
+        "Title         Pages\nDune            412\n\u{d8}rkenen | II     98\n"
+    );
+    assert!(!output.contains('+') && !output.contains('='));
+}
+
+#[test]
+fn test_plain_style_puts_group_headers_on_their_own_line() {
+    let rows = vec![
+        TableRow::Header(vec!["Title".to_string(), "Author".to_string()]),
+        TableRow::GroupHeader("Dune".to_string(), 1),
+        TableRow::Data(vec!["  #1 Dune".to_string(), "Herbert".to_string()]),
+    ];
+    assert_eq!(
+        format_structured_table_styled(&rows, &[], TableStyle::Plain),
+        "Title      Author\nDune\n  #1 Dune  Herbert\n"
+    );
+}
+
+#[test]
+fn test_markdown_style_is_a_github_table() {
+    let alignments = [Alignment::Left, Alignment::Right];
+    let output = format_table_styled(&style_rows(), &alignments, TableStyle::Markdown);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "| Title | Pages |");
+    assert_eq!(lines[1], "| --- | ---: |");
+    assert_eq!(lines[2], "| Dune | 412 |");
+    // Pipes in cells are escaped so they don't add a column
+    assert_eq!(lines[3], "| \u{d8}rkenen \\| II | 98 |");
+    assert_eq!(lines.len(), 4);
+}
+
+#[test]
+fn test_markdown_style_group_header_row() {
+    let rows = vec![
+        TableRow::Header(vec!["Title".to_string(), "Author".to_string()]),
+        TableRow::GroupHeader("Dune".to_string(), 1),
+        TableRow::Data(vec!["#1 Dune".to_string(), "Herbert".to_string()]),
+    ];
+    let output = format_structured_table_styled(&rows, &[Alignment::Center], TableStyle::Markdown);
+    assert_eq!(
+        output,
+        "| Title | Author |\n| :---: | --- |\n| **Dune** |  |\n| #1 Dune | Herbert |\n"
+    );
+}
+
+#[test]
+fn test_markdown_alignment_row_for_mixed_columns() {
+    let rows = vec![
+        vec![
+            "Title".to_string(),
+            "Rating".to_string(),
+            "Read".to_string(),
+            "Notes".to_string(),
+        ],
+        vec![
+            "Dune".to_string(),
+            "5".to_string(),
+            "x".to_string(),
+            "".to_string(),
+        ],
+    ];
+    // The fourth column has no alignment and defaults to left
+    let alignments = [Alignment::Left, Alignment::Right, Alignment::Center];
+    let output = format_table_styled(&rows, &alignments, TableStyle::Markdown);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "| Title | Rating | Read | Notes |");
+    assert_eq!(lines[1], "| --- | ---: | :---: | --- |");
+    assert_eq!(lines[2], "| Dune | 5 | x |  |");
+}
+
+#[test]
+fn test_markdown_cells_escaped_beforehand_are_not_escaped_twice() {
+    let rows = vec![
+        vec!["Title".to_string()],
+        vec!["Pipes \\| Tables".to_string()],
+        vec!["Back\\\\|slash".to_string()],
+    ];
+    let output = format_table_styled(&rows, &[], TableStyle::Markdown);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[2], "| Pipes \\| Tables |");
+    // An escaped backslash before a pipe doesn't escape the pipe
+    assert_eq!(lines[3], "| Back\\\\\\|slash |");
+}
+
+fn row_labels(rows: &[TableRow]) -> Vec<String> {
+    rows.iter()
+        .map(|row| match row {
+            TableRow::Header(cells) => format!("header:{}", cells[0]),
+            TableRow::GroupHeader(name, count) => format!("group:{} ({})", name, count),
+            TableRow::Data(cells) => cells[0].clone(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_reverse_table_rows_keeps_groups_together() {
+    let rows = vec![
+        TableRow::Header(vec!["Title".to_string()]),
+        TableRow::GroupHeader("Series A".to_string(), 2),
+        TableRow::Data(vec!["A1".to_string()]),
+        TableRow::Data(vec!["A2".to_string()]),
+        TableRow::Data(vec!["Standalone".to_string()]),
+        TableRow::GroupHeader("Series B".to_string(), 1),
+        TableRow::Data(vec!["B1".to_string()]),
+    ];
+
+    assert_eq!(
+        row_labels(&reverse_table_rows(rows)),
+        vec![
+            "header:Title",
+            "group:Series B (1)",
+            "B1",
+            "Standalone",
+            "group:Series A (2)",
+            "A2",
+            "A1",
+        ]
+    );
+}
+
+#[test]
+fn test_reverse_table_rows_twice_restores_order() {
+    let rows = vec![
+        TableRow::Header(vec!["Title".to_string()]),
+        TableRow::Data(vec!["First".to_string()]),
+        TableRow::GroupHeader("Series".to_string(), 2),
+        TableRow::Data(vec!["One".to_string()]),
+        TableRow::Data(vec!["Two".to_string()]),
+    ];
+    let expected = row_labels(&rows);
+
+    assert_eq!(
+        row_labels(&reverse_table_rows(reverse_table_rows(rows))),
+        expected
+    );
+}
@@ -0,0 +1,51 @@
+use bookmon::time::{parse_timezone, Timezone};
+use chrono::TimeZone;
+
+#[test]
+fn test_parse_timezone_utc() {
+    assert_eq!(parse_timezone("UTC").unwrap(), Timezone::Utc);
+    assert_eq!(parse_timezone("utc").unwrap(), Timezone::Utc);
+    assert_eq!(parse_timezone("Z").unwrap(), Timezone::Utc);
+}
+
+#[test]
+fn test_parse_timezone_fixed_offset() {
+    let offset = |secs| Timezone::Fixed(chrono::FixedOffset::east_opt(secs).unwrap());
+    assert_eq!(parse_timezone("+02:00").unwrap(), offset(7200));
+    assert_eq!(parse_timezone("-05:30").unwrap(), offset(-19800));
+}
+
+#[test]
+fn test_parse_timezone_iana_name() {
+    assert_eq!(
+        parse_timezone("Europe/Oslo").unwrap(),
+        Timezone::Named(chrono_tz::Europe::Oslo)
+    );
+    assert_eq!(
+        parse_timezone(" America/New_York ").unwrap(),
+        Timezone::Named(chrono_tz::America::New_York)
+    );
+}
+
+#[test]
+fn test_parse_timezone_rejects_garbage() {
+    for value in ["garbage", "Mars/Olympus", "+25:00", ""] {
+        let err = parse_timezone(value).unwrap_err();
+        assert!(err.contains("Invalid timezone"), "{}: {}", value, err);
+    }
+}
+
+#[test]
+fn test_timezone_year_of_crosses_new_year() {
+    // 23:30 UTC on Dec 31 is already Jan 1 in Oslo, but still Dec 31 in New York
+    let time = chrono::Utc
+        .with_ymd_and_hms(2024, 12, 31, 23, 30, 0)
+        .unwrap();
+    assert_eq!(parse_timezone("UTC").unwrap().year_of(&time), 2024);
+    assert_eq!(parse_timezone("Europe/Oslo").unwrap().year_of(&time), 2025);
+    assert_eq!(parse_timezone("+01:00").unwrap().year_of(&time), 2025);
+    assert_eq!(
+        parse_timezone("America/New_York").unwrap().year_of(&time),
+        2024
+    );
+}